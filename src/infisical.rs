@@ -1,7 +1,26 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
+pub mod access_approvals;
+pub mod audit_logs;
 pub mod auth_methods;
+pub mod dynamic_secrets;
+pub mod environments;
+pub mod groups;
+pub mod identities;
+pub mod kms;
+pub mod organizations;
+pub mod pki;
+pub mod project_memberships;
+pub mod project_roles;
+pub mod projects;
+pub mod secret_approvals;
+pub mod secret_rotations;
+pub mod secret_sharing;
+pub mod secrets;
+pub mod ssh;
+pub mod tags;
 pub mod utils;
+pub mod webhooks;
 
 pub const INFISICAL_DEFAULT_TIME_TO_LIVE: u128 = 2592000;
 pub const INFISICAL_DEFAULT_IPV4_ADDRESS: IpAddr = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
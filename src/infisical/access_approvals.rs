@@ -0,0 +1,396 @@
+/// Access Approvals:
+///
+/// Access approval policies, and the temporary access requests made under them: requesting access to an
+/// environment/path for a limited time, reviewing requests, and waiting until access is granted.
+use std::{collections::HashMap, time::Instant};
+
+use reqwest::StatusCode;
+
+use error_handling::AccessApprovalError;
+use utils::{
+    access_approval_util_functions::{
+        access_request_permissions, construct_access_approval_endpoint_url,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    environments::utils::environment_util_functions::validate_environment_slug,
+    utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the access approvals module, such as AccessApprovalPolicy and AccessRequest.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Access approval policies for a given project
+impl UniversalAuthAccessToken {
+    /// list_access_approval_policies()
+    ///
+    /// Lists every access approval policy in a given project.
+    pub async fn list_access_approval_policies(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+    ) -> Result<Vec<AccessApprovalPolicy>, AccessApprovalError> {
+        let endpoint_url = construct_access_approval_endpoint_url(host, "policies");
+
+        let response = client
+            .get(&endpoint_url)
+            .query(&[("projectSlug", project_slug)])
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(AccessApprovalError::PolicyRequestError {
+                operation: "list_access_approval_policies".to_string(),
+                project_slug: project_slug.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<AccessApprovalPoliciesResponse>(&bytes)?.approvals)
+    }
+
+    /// create_access_approval_policy()
+    ///
+    /// Requires access to an environment (or a secret path glob within it) to be requested and approved.
+    pub async fn create_access_approval_policy(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateAccessApprovalPolicyOptions,
+    ) -> Result<AccessApprovalPolicy, AccessApprovalError> {
+        validate_environment_slug(&options.environment)?;
+
+        let endpoint_url = construct_access_approval_endpoint_url(host, "policies");
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(AccessApprovalError::PolicyRequestError {
+                operation: "create_access_approval_policy".to_string(),
+                project_slug: options.project_slug.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<AccessApprovalPolicyResponse>(&bytes)?.approval)
+    }
+
+    /// update_access_approval_policy()
+    ///
+    /// Updates an access approval policy. Any field left as None in `options` is left untouched.
+    pub async fn update_access_approval_policy(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        policy_id: &str,
+        options: &UpdateAccessApprovalPolicyOptions,
+    ) -> Result<AccessApprovalPolicy, AccessApprovalError> {
+        let endpoint_url =
+            construct_access_approval_endpoint_url(host, &format!("policies/{policy_id}"));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(AccessApprovalError::PolicyError {
+                operation: "update_access_approval_policy".to_string(),
+                policy_id: policy_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<AccessApprovalPolicyResponse>(&bytes)?.approval)
+    }
+
+    /// delete_access_approval_policy()
+    ///
+    /// Deletes an access approval policy. Access already granted under it is left in place until it expires.
+    pub async fn delete_access_approval_policy(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        policy_id: &str,
+    ) -> Result<AccessApprovalPolicy, AccessApprovalError> {
+        let endpoint_url =
+            construct_access_approval_endpoint_url(host, &format!("policies/{policy_id}"));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(AccessApprovalError::PolicyError {
+                operation: "delete_access_approval_policy".to_string(),
+                policy_id: policy_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<AccessApprovalPolicyResponse>(&bytes)?.approval)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Temporary access requests for a given project
+impl UniversalAuthAccessToken {
+    /// request_access()
+    ///
+    /// Requests temporary access to an environment/path, for `options.duration` once approved.
+    /// Use wait_for_access() to block until the request has been reviewed.
+    pub async fn request_access(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+        options: &RequestAccessOptions,
+    ) -> Result<AccessRequest, AccessApprovalError> {
+        validate_environment_slug(&options.environment)?;
+
+        let endpoint_url = construct_access_approval_endpoint_url(host, "requests");
+
+        let response = client
+            .post(&endpoint_url)
+            .query(&[("projectSlug", project_slug)])
+            .bearer_auth(self.access_token())
+            .json(&AccessRequestBody {
+                permissions: access_request_permissions(options),
+                is_temporary: true,
+                temporary_range: &options.duration,
+                note: options.justification.as_deref(),
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(AccessApprovalError::RequestAccessError {
+                project_slug: project_slug.to_string(),
+                environment: options.environment.clone(),
+                secret_path: options.secret_path.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<AccessRequestResponse>(&bytes)?.approval)
+    }
+
+    /// list_access_requests()
+    ///
+    /// Lists the access requests in a given project, optionally filtered by environment and requester.
+    pub async fn list_access_requests(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+        options: &ListAccessRequestsOptions,
+    ) -> Result<Vec<AccessRequest>, AccessApprovalError> {
+        let endpoint_url = construct_access_approval_endpoint_url(host, "requests");
+
+        let response = client
+            .get(&endpoint_url)
+            .query(&[("projectSlug", project_slug)])
+            .query(options)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(AccessApprovalError::ListAccessRequestsError {
+                project_slug: project_slug.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<AccessRequestsResponse>(&bytes)?.requests)
+    }
+
+    /// list_pending_access_requests()
+    ///
+    /// Convenience wrapper around list_access_requests(), keeping only the requests still awaiting review.
+    pub async fn list_pending_access_requests(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+    ) -> Result<Vec<AccessRequest>, AccessApprovalError> {
+        Ok(self
+            .list_access_requests(host, client, project_slug, &Default::default())
+            .await?
+            .into_iter()
+            .filter(AccessRequest::is_pending)
+            .collect())
+    }
+
+    /// get_access_request()
+    ///
+    /// Retrieves a single access request by its id. Infisical has no endpoint for this, so the project's
+    /// requests are listed and filtered.
+    pub async fn get_access_request(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+        request_id: &str,
+    ) -> Result<AccessRequest, AccessApprovalError> {
+        self.list_access_requests(host, client, project_slug, &Default::default())
+            .await?
+            .into_iter()
+            .find(|request| request.id == request_id)
+            .ok_or_else(|| AccessApprovalError::AccessRequestNotFound {
+                project_slug: project_slug.to_string(),
+                request_id: request_id.to_string(),
+            })
+    }
+
+    /// review_access_request()
+    ///
+    /// Approves or denies an access request as the current identity.
+    pub async fn review_access_request(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        request_id: &str,
+        decision: AccessRequestDecision,
+    ) -> Result<AccessRequestReviewer, AccessApprovalError> {
+        let endpoint_url =
+            construct_access_approval_endpoint_url(host, &format!("requests/{request_id}/review"));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("status", decision);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&form_data)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(AccessApprovalError::ReviewAccessRequestError {
+                request_id: request_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<AccessRequestReviewResponse>(&bytes)?.review)
+    }
+
+    /// wait_for_access()
+    ///
+    /// Polls an access request until it's granted, returning the approved request. Fails with AccessRequestDenied
+    /// if it gets rejected, and AccessRequestTimedOut if it's still pending after `options.timeout`.
+    pub async fn wait_for_access(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+        request_id: &str,
+        options: &AccessPollOptions,
+    ) -> Result<AccessRequest, AccessApprovalError> {
+        let started_at = Instant::now();
+
+        loop {
+            let request = self
+                .get_access_request(host, client, project_slug, request_id)
+                .await?;
+
+            if request.is_granted() {
+                return Ok(request);
+            }
+
+            if request.is_denied() {
+                return Err(AccessApprovalError::AccessRequestDenied {
+                    request_id: request_id.to_string(),
+                });
+            }
+
+            if started_at.elapsed() >= options.timeout {
+                return Err(AccessApprovalError::AccessRequestTimedOut {
+                    request_id: request_id.to_string(),
+                    waited_secs: started_at.elapsed().as_secs(),
+                });
+            }
+
+            tokio::time::sleep(
+                options
+                    .poll_interval()
+                    .min(options.timeout.saturating_sub(started_at.elapsed())),
+            )
+            .await;
+        }
+    }
+}
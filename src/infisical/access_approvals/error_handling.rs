@@ -0,0 +1,86 @@
+use crate::infisical::{
+    environments::error_handling::EnvironmentSlugError, utils::api_utils::ApiResponse,
+};
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum AccessApprovalError {
+    #[error(
+        "AccessApprovals::{operation}(): 
+        Project: {project_slug}
+        Err: {error}"
+    )]
+    PolicyRequestError {
+        operation: String,
+        project_slug: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "AccessApprovals::{operation}(): 
+        Policy ID: {policy_id}
+        Err: {error}"
+    )]
+    PolicyError {
+        operation: String,
+        policy_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "AccessApprovals::request_access(): 
+        Project: {project_slug}
+        Environment: {environment}
+        Secret path: {secret_path}
+        Err: {error}"
+    )]
+    RequestAccessError {
+        project_slug: String,
+        environment: String,
+        secret_path: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "AccessApprovals::list_access_requests(): 
+        Project: {project_slug}
+        Err: {error}"
+    )]
+    ListAccessRequestsError {
+        project_slug: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "AccessApprovals::review_access_request(): 
+        Request ID: {request_id}
+        Err: {error}"
+    )]
+    ReviewAccessRequestError {
+        request_id: String,
+        error: ApiResponse,
+    },
+
+    #[error("AccessApprovals: access request {request_id} not found in project {project_slug}")]
+    AccessRequestNotFound {
+        project_slug: String,
+        request_id: String,
+    },
+
+    #[error("AccessApprovals: access request {request_id} was denied")]
+    AccessRequestDenied { request_id: String },
+
+    #[error("AccessApprovals: access request {request_id} still pending after {waited_secs}s")]
+    AccessRequestTimedOut {
+        request_id: String,
+        waited_secs: u64,
+    },
+
+    #[error(transparent)]
+    InvalidEnvironmentSlug(#[from] EnvironmentSlugError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
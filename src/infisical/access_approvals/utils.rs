@@ -0,0 +1,280 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::infisical::{
+    environments::utils::Environment,
+    secret_approvals::utils::{ApprovalEnforcementLevel, SecretApprover},
+};
+
+/// An access approval policy: access to the environment (and optionally secret path glob) it covers has to be
+/// requested, and approved by its approvers, before it is granted.
+///
+/// Approvers are described the same way as for secret approval policies, see SecretApprover.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct AccessApprovalPolicy {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    pub approvals: u64,
+    #[serde(default)]
+    pub secret_path: Option<String>,
+    #[serde(default)]
+    pub enforcement_level: ApprovalEnforcementLevel,
+    #[serde(default)]
+    pub environment: Option<Environment>,
+    #[serde(default)]
+    pub approvers: Vec<SecretApprover>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// Request body for create_access_approval_policy().
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateAccessApprovalPolicyOptions {
+    pub project_slug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub environment: String,
+    pub secret_path: String,
+    pub approvers: Vec<SecretApprover>,
+    pub approvals: u64,
+    pub enforcement_level: ApprovalEnforcementLevel,
+}
+
+/// Request body for update_access_approval_policy(). Any field left as None is left untouched by Infisical.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct UpdateAccessApprovalPolicyOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approvers: Option<Vec<SecretApprover>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approvals: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforcement_level: Option<ApprovalEnforcementLevel>,
+}
+
+/// What a temporary access request asks to be allowed to do with the secrets in its scope.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum AccessRequestAction {
+    Read,
+    Create,
+    Edit,
+    Delete,
+}
+
+/// Input for request_access().
+///
+/// - environment/secret_path: the scope access is requested to (secret_path may be a glob, e.g. "/payments/**")
+/// - duration: how long access is granted for once approved, in Infisical's duration format (e.g. "30m", "4h")
+/// - justification: shown to approvers alongside the request
+#[derive(Clone, Debug)]
+pub struct RequestAccessOptions {
+    pub environment: String,
+    pub secret_path: String,
+    pub actions: Vec<AccessRequestAction>,
+    pub duration: String,
+    pub justification: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum AccessRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+    /// anything newer Infisical versions might report
+    #[serde(other)]
+    Unknown,
+}
+
+/// An approver's decision on an access request, see review_access_request().
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum AccessRequestDecision {
+    Approved,
+    Rejected,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct AccessRequestReviewer {
+    #[serde(alias = "userId")]
+    pub member: String,
+    pub status: AccessRequestStatus,
+}
+
+/// A temporary access request.
+///
+/// Fields:
+/// - privilege_id: the additional privilege created once the request is approved, None until then
+/// - is_temporary/temporary_range: how long access is granted for once approved
+/// - permissions: the permission rules requested, in Infisical's permission format
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct AccessRequest {
+    pub id: String,
+    pub policy_id: String,
+    #[serde(default)]
+    pub privilege_id: Option<String>,
+    #[serde(default)]
+    pub requested_by_user_id: Option<String>,
+    #[serde(default)]
+    pub is_temporary: bool,
+    #[serde(default)]
+    pub temporary_range: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub status: Option<AccessRequestStatus>,
+    #[serde(default)]
+    pub is_approved: bool,
+    #[serde(default)]
+    pub permissions: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub reviewers: Vec<AccessRequestReviewer>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+impl AccessRequest {
+    /// Whether access has been granted, i.e. the request was approved and its privilege created.
+    pub fn is_granted(&self) -> bool {
+        self.privilege_id.is_some()
+            || self.is_approved
+            || self.status == Some(AccessRequestStatus::Approved)
+    }
+
+    pub fn is_denied(&self) -> bool {
+        self.status == Some(AccessRequestStatus::Rejected)
+            || self
+                .reviewers
+                .iter()
+                .any(|reviewer| reviewer.status == AccessRequestStatus::Rejected)
+    }
+
+    pub fn is_pending(&self) -> bool {
+        !self.is_granted() && !self.is_denied()
+    }
+}
+
+/// Input for wait_for_access(). Defaults to checking every 10 seconds, for up to 15 minutes.
+///
+/// Intervals shorter than `AccessPollOptions::MIN_INTERVAL` (including zero) are raised to it, so a misconfigured
+/// interval can't hammer the API.
+#[derive(Clone, Debug)]
+pub struct AccessPollOptions {
+    pub interval: Duration,
+    pub timeout: Duration,
+}
+
+impl AccessPollOptions {
+    pub const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// The interval wait_for_access() actually waits between polls.
+    pub fn poll_interval(&self) -> Duration {
+        self.interval.max(Self::MIN_INTERVAL)
+    }
+}
+
+impl Default for AccessPollOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(10),
+            timeout: Duration::from_secs(15 * 60),
+        }
+    }
+}
+
+/// Query options for list_access_requests(). Any field left as None is not filtered on.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct ListAccessRequestsOptions {
+    #[serde(rename = "envSlug", skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_project_membership_id: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AccessRequestBody<'a> {
+    pub permissions: Vec<serde_json::Value>,
+    pub is_temporary: bool,
+    pub temporary_range: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AccessApprovalPolicyResponse {
+    pub approval: AccessApprovalPolicy,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AccessApprovalPoliciesResponse {
+    pub approvals: Vec<AccessApprovalPolicy>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AccessRequestResponse {
+    pub approval: AccessRequest,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AccessRequestReviewResponse {
+    pub review: AccessRequestReviewer,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AccessRequestsResponse {
+    pub requests: Vec<AccessRequest>,
+}
+
+pub mod access_approval_util_functions {
+    use serde_json::json;
+
+    use super::RequestAccessOptions;
+
+    pub fn construct_access_approval_endpoint_url(host: &str, path: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/access-approvals/{path}",
+            host_url = host,
+            version = "v1",
+        )
+    }
+
+    /// access_request_permissions()
+    ///
+    /// Builds the permission rules a temporary access request asks for, in Infisical's permission format.
+    pub fn access_request_permissions(options: &RequestAccessOptions) -> Vec<serde_json::Value> {
+        options
+            .actions
+            .iter()
+            .map(|action| {
+                json!({
+                    "action": action,
+                    "subject": "secrets",
+                    "conditions": {
+                        "environment": options.environment,
+                        "secretPath": { "$glob": options.secret_path },
+                    },
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,106 @@
+/// Audit Logs:
+///
+/// Querying a project's (or a whole organization's) audit logs, either a page at a time, or as a Stream that
+/// pages through results transparently.
+use futures_util::{Stream, TryStreamExt, stream};
+use reqwest::StatusCode;
+
+use error_handling::AuditLogError;
+use utils::{
+    audit_log_util_functions::{audit_log_query_parameters, construct_audit_log_endpoint_url},
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the audit logs module, such as AuditLog and AuditLogQuery.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Audit log queries
+impl UniversalAuthAccessToken {
+    /// list_audit_logs()
+    ///
+    /// Fetches a single page of audit logs (up to `query.page_size` entries, newest first), starting at `offset`.
+    pub async fn list_audit_logs(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        query: &AuditLogQuery,
+        offset: u64,
+    ) -> Result<Vec<AuditLog>, AuditLogError> {
+        let endpoint_url = construct_audit_log_endpoint_url(host);
+
+        let response = client
+            .get(&endpoint_url)
+            .query(&audit_log_query_parameters(query, offset))
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(AuditLogError::ListAuditLogsError {
+                project_id: query.project_id.clone(),
+                offset,
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<AuditLogsResponse>(&bytes)?.audit_logs)
+    }
+
+    /// stream_audit_logs()
+    ///
+    /// Streams every audit log matching `query`, fetching the next page only once the current one has been consumed.
+    /// The stream ends after the first short page, or after the first error.
+    ///
+    /// # Example
+    /// ```ignore
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let query = AuditLogQuery {
+    ///     project_id: Some(project_id.to_string()),
+    ///     actor_type: Some(AuditLogActorType::Identity),
+    ///     event_types: vec!["get-secret".to_string(), "get-secrets".to_string()],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut audit_logs = std::pin::pin!(access_token.stream_audit_logs(host, &client, &query));
+    /// while let Some(audit_log) = audit_logs.try_next().await? {
+    ///     println!("{:?} {}", audit_log.actor.actor_id(), audit_log.event.event_type());
+    /// }
+    /// ```
+    pub fn stream_audit_logs<'a>(
+        &'a self,
+        host: &'a str,
+        client: &'a reqwest::Client,
+        query: &'a AuditLogQuery,
+    ) -> impl Stream<Item = Result<AuditLog, AuditLogError>> + 'a {
+        // state: the next page's offset, or None once the last page has been fetched
+        stream::try_unfold(Some(0), move |offset| async move {
+            let Some(offset) = offset else {
+                return Ok::<_, AuditLogError>(None);
+            };
+
+            let page = self.list_audit_logs(host, client, query, offset).await?;
+
+            let next_offset =
+                (page.len() as u64 >= query.page_size.max(1)).then_some(offset + page.len() as u64);
+
+            Ok(Some((stream::iter(page.into_iter().map(Ok)), next_offset)))
+        })
+        .try_flatten()
+    }
+}
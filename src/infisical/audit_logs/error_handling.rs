@@ -0,0 +1,23 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum AuditLogError {
+    #[error(
+        "AuditLogs::list_audit_logs(): 
+        Project ID: {project_id:?}
+        Offset: {offset}
+        Err: {error}"
+    )]
+    ListAuditLogsError {
+        project_id: Option<String>,
+        offset: u64,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
@@ -0,0 +1,492 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+/// Who performed an audited action.
+///
+/// Identity is a machine identity, e.g. one authenticated through universal auth. Actors this crate doesn't
+/// model yet (e.g. the platform itself) deserialize as Other, keeping their raw type and metadata.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "RawAuditLogEntry", into = "RawAuditLogEntry")]
+pub enum AuditLogActor {
+    Identity {
+        identity_id: String,
+        name: String,
+    },
+    User {
+        user_id: String,
+        email: Option<String>,
+        username: Option<String>,
+    },
+    Service {
+        service_id: String,
+        name: String,
+    },
+    Other {
+        actor_type: String,
+        metadata: serde_json::Value,
+    },
+}
+
+impl AuditLogActor {
+    /// The identity, user, or service token id behind the actor.
+    pub fn actor_id(&self) -> Option<&str> {
+        match self {
+            AuditLogActor::Identity { identity_id, .. } => Some(identity_id),
+            AuditLogActor::User { user_id, .. } => Some(user_id),
+            AuditLogActor::Service { service_id, .. } => Some(service_id),
+            AuditLogActor::Other { .. } => None,
+        }
+    }
+
+    pub fn actor_type(&self) -> &str {
+        match self {
+            AuditLogActor::Identity { .. } => "identity",
+            AuditLogActor::User { .. } => "user",
+            AuditLogActor::Service { .. } => "service",
+            AuditLogActor::Other { actor_type, .. } => actor_type,
+        }
+    }
+}
+
+/// The secret an event acted on.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct AuditedSecret {
+    pub secret_id: String,
+    pub secret_key: String,
+    #[serde(default)]
+    pub secret_version: u64,
+}
+
+/// Metadata shared by every single-secret event.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SecretEventMetadata {
+    pub environment: String,
+    pub secret_path: String,
+    #[serde(flatten)]
+    pub secret: AuditedSecret,
+}
+
+/// Metadata shared by every batch secret event.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SecretsEventMetadata {
+    pub environment: String,
+    pub secret_path: String,
+    pub secrets: Vec<AuditedSecret>,
+}
+
+/// What happened. Events this crate doesn't model yet deserialize as Other, keeping their raw type and metadata.
+///
+/// Use AuditLogEvent::event_type() for the event's Infisical name (e.g. "get-secret"), as used to filter queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "RawAuditLogEntry", into = "RawAuditLogEntry")]
+pub enum AuditLogEvent {
+    GetSecrets {
+        environment: String,
+        secret_path: String,
+        number_of_secrets: u64,
+    },
+    GetSecret(SecretEventMetadata),
+    CreateSecret(SecretEventMetadata),
+    UpdateSecret(SecretEventMetadata),
+    DeleteSecret(SecretEventMetadata),
+    CreateSecrets(SecretsEventMetadata),
+    UpdateSecrets(SecretsEventMetadata),
+    DeleteSecrets(SecretsEventMetadata),
+    LoginIdentityUniversalAuth {
+        identity_id: String,
+        client_secret_id: Option<String>,
+    },
+    Other {
+        event_type: String,
+        metadata: serde_json::Value,
+    },
+}
+
+impl AuditLogEvent {
+    pub fn event_type(&self) -> &str {
+        match self {
+            AuditLogEvent::GetSecrets { .. } => "get-secrets",
+            AuditLogEvent::GetSecret(_) => "get-secret",
+            AuditLogEvent::CreateSecret(_) => "create-secret",
+            AuditLogEvent::UpdateSecret(_) => "update-secret",
+            AuditLogEvent::DeleteSecret(_) => "delete-secret",
+            AuditLogEvent::CreateSecrets(_) => "create-secrets",
+            AuditLogEvent::UpdateSecrets(_) => "update-secrets",
+            AuditLogEvent::DeleteSecrets(_) => "delete-secrets",
+            AuditLogEvent::LoginIdentityUniversalAuth { .. } => "login-identity-universal-auth",
+            AuditLogEvent::Other { event_type, .. } => event_type,
+        }
+    }
+
+    /// The secrets a secret event read or wrote, with the environment and path they live in.
+    /// Empty for get-secrets (which only records a count) and for non-secret events.
+    pub fn secrets(&self) -> Vec<(&str, &str, &AuditedSecret)> {
+        match self {
+            AuditLogEvent::GetSecret(metadata)
+            | AuditLogEvent::CreateSecret(metadata)
+            | AuditLogEvent::UpdateSecret(metadata)
+            | AuditLogEvent::DeleteSecret(metadata) => vec![(
+                metadata.environment.as_str(),
+                metadata.secret_path.as_str(),
+                &metadata.secret,
+            )],
+            AuditLogEvent::CreateSecrets(metadata)
+            | AuditLogEvent::UpdateSecrets(metadata)
+            | AuditLogEvent::DeleteSecrets(metadata) => metadata
+                .secrets
+                .iter()
+                .map(|secret| {
+                    (
+                        metadata.environment.as_str(),
+                        metadata.secret_path.as_str(),
+                        secret,
+                    )
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+// the wire format of both actors and events: a type, and type-specific metadata
+#[derive(Serialize, Deserialize)]
+struct RawAuditLogEntry {
+    #[serde(rename = "type")]
+    entry_type: String,
+    #[serde(default)]
+    metadata: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IdentityActorMetadata {
+    identity_id: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserActorMetadata {
+    user_id: String,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    username: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServiceActorMetadata {
+    service_id: String,
+    name: String,
+}
+
+impl From<RawAuditLogEntry> for AuditLogActor {
+    fn from(raw: RawAuditLogEntry) -> Self {
+        let metadata = raw.metadata.clone();
+
+        let actor = match raw.entry_type.as_str() {
+            "identity" => {
+                serde_json::from_value::<IdentityActorMetadata>(metadata).map(|metadata| {
+                    AuditLogActor::Identity {
+                        identity_id: metadata.identity_id,
+                        name: metadata.name,
+                    }
+                })
+            }
+            "user" => serde_json::from_value::<UserActorMetadata>(metadata).map(|metadata| {
+                AuditLogActor::User {
+                    user_id: metadata.user_id,
+                    email: metadata.email,
+                    username: metadata.username,
+                }
+            }),
+            "service" => serde_json::from_value::<ServiceActorMetadata>(metadata).map(|metadata| {
+                AuditLogActor::Service {
+                    service_id: metadata.service_id,
+                    name: metadata.name,
+                }
+            }),
+            _ => Ok(AuditLogActor::Other {
+                actor_type: raw.entry_type.clone(),
+                metadata: raw.metadata.clone(),
+            }),
+        };
+
+        actor.unwrap_or(AuditLogActor::Other {
+            actor_type: raw.entry_type,
+            metadata: raw.metadata,
+        })
+    }
+}
+
+impl From<AuditLogActor> for RawAuditLogEntry {
+    fn from(actor: AuditLogActor) -> Self {
+        let actor_type = actor.actor_type().to_string();
+
+        let metadata = match actor {
+            AuditLogActor::Identity { identity_id, name } => serde_json::json!({
+                "identityId": identity_id,
+                "name": name,
+            }),
+            AuditLogActor::User {
+                user_id,
+                email,
+                username,
+            } => serde_json::json!({
+                "userId": user_id,
+                "email": email,
+                "username": username,
+            }),
+            AuditLogActor::Service { service_id, name } => serde_json::json!({
+                "serviceId": service_id,
+                "name": name,
+            }),
+            AuditLogActor::Other { metadata, .. } => metadata,
+        };
+
+        RawAuditLogEntry {
+            entry_type: actor_type,
+            metadata,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GetSecretsMetadata {
+    environment: String,
+    secret_path: String,
+    #[serde(default)]
+    number_of_secrets: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UniversalAuthLoginMetadata {
+    identity_id: String,
+    #[serde(default)]
+    client_secret_id: Option<String>,
+}
+
+impl From<RawAuditLogEntry> for AuditLogEvent {
+    fn from(raw: RawAuditLogEntry) -> Self {
+        let metadata = raw.metadata.clone();
+
+        let event = match raw.entry_type.as_str() {
+            "get-secrets" => {
+                serde_json::from_value::<GetSecretsMetadata>(metadata).map(|metadata| {
+                    AuditLogEvent::GetSecrets {
+                        environment: metadata.environment,
+                        secret_path: metadata.secret_path,
+                        number_of_secrets: metadata.number_of_secrets,
+                    }
+                })
+            }
+            "get-secret" => serde_json::from_value(metadata).map(AuditLogEvent::GetSecret),
+            "create-secret" => serde_json::from_value(metadata).map(AuditLogEvent::CreateSecret),
+            "update-secret" => serde_json::from_value(metadata).map(AuditLogEvent::UpdateSecret),
+            "delete-secret" => serde_json::from_value(metadata).map(AuditLogEvent::DeleteSecret),
+            "create-secrets" => serde_json::from_value(metadata).map(AuditLogEvent::CreateSecrets),
+            "update-secrets" => serde_json::from_value(metadata).map(AuditLogEvent::UpdateSecrets),
+            "delete-secrets" => serde_json::from_value(metadata).map(AuditLogEvent::DeleteSecrets),
+            "login-identity-universal-auth" => {
+                serde_json::from_value::<UniversalAuthLoginMetadata>(metadata).map(|metadata| {
+                    AuditLogEvent::LoginIdentityUniversalAuth {
+                        identity_id: metadata.identity_id,
+                        client_secret_id: metadata.client_secret_id,
+                    }
+                })
+            }
+            _ => Ok(AuditLogEvent::Other {
+                event_type: raw.entry_type.clone(),
+                metadata: raw.metadata.clone(),
+            }),
+        };
+
+        // metadata shapes do drift between Infisical versions, an unexpected one shouldn't fail the whole page
+        event.unwrap_or(AuditLogEvent::Other {
+            event_type: raw.entry_type,
+            metadata: raw.metadata,
+        })
+    }
+}
+
+impl From<AuditLogEvent> for RawAuditLogEntry {
+    fn from(event: AuditLogEvent) -> Self {
+        let event_type = event.event_type().to_string();
+
+        let metadata = match event {
+            AuditLogEvent::GetSecrets {
+                environment,
+                secret_path,
+                number_of_secrets,
+            } => serde_json::json!({
+                "environment": environment,
+                "secretPath": secret_path,
+                "numberOfSecrets": number_of_secrets,
+            }),
+            AuditLogEvent::GetSecret(metadata)
+            | AuditLogEvent::CreateSecret(metadata)
+            | AuditLogEvent::UpdateSecret(metadata)
+            | AuditLogEvent::DeleteSecret(metadata) => serde_json::json!({
+                "environment": metadata.environment,
+                "secretPath": metadata.secret_path,
+                "secretId": metadata.secret.secret_id,
+                "secretKey": metadata.secret.secret_key,
+                "secretVersion": metadata.secret.secret_version,
+            }),
+            AuditLogEvent::CreateSecrets(metadata)
+            | AuditLogEvent::UpdateSecrets(metadata)
+            | AuditLogEvent::DeleteSecrets(metadata) => serde_json::json!({
+                "environment": metadata.environment,
+                "secretPath": metadata.secret_path,
+                "secrets": metadata.secrets.iter().map(|secret| serde_json::json!({
+                    "secretId": secret.secret_id,
+                    "secretKey": secret.secret_key,
+                    "secretVersion": secret.secret_version,
+                })).collect::<Vec<_>>(),
+            }),
+            AuditLogEvent::LoginIdentityUniversalAuth {
+                identity_id,
+                client_secret_id,
+            } => serde_json::json!({
+                "identityId": identity_id,
+                "clientSecretId": client_secret_id,
+            }),
+            AuditLogEvent::Other { metadata, .. } => metadata,
+        };
+
+        RawAuditLogEntry {
+            entry_type: event_type,
+            metadata,
+        }
+    }
+}
+
+/// A single audit log entry.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct AuditLog {
+    pub id: String,
+    pub actor: AuditLogActor,
+    pub event: AuditLogEvent,
+    #[serde(default)]
+    pub ip_address: Option<String>,
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub user_agent_type: Option<String>,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    #[serde(default)]
+    pub project_name: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum AuditLogActorType {
+    Identity,
+    User,
+    Service,
+    Platform,
+}
+
+/// Query options for list_audit_logs() and stream_audit_logs(). Any field left as None is not filtered on.
+///
+/// - project_id: None queries the whole organization, which requires organization-level permissions
+/// - event_types: Infisical event names, e.g. "get-secret" (see AuditLogEvent::event_type())
+/// - start/end: the time range to query
+/// - page_size: how many entries are fetched per request
+#[derive(Clone, Debug)]
+pub struct AuditLogQuery {
+    pub project_id: Option<String>,
+    pub environment: Option<String>,
+    pub secret_path: Option<String>,
+    pub actor_type: Option<AuditLogActorType>,
+    pub actor_id: Option<String>,
+    pub event_types: Vec<String>,
+    pub start: Option<SystemTime>,
+    pub end: Option<SystemTime>,
+    pub page_size: u64,
+}
+
+impl Default for AuditLogQuery {
+    fn default() -> Self {
+        Self {
+            project_id: None,
+            environment: None,
+            secret_path: None,
+            actor_type: None,
+            actor_id: None,
+            event_types: Vec::new(),
+            start: None,
+            end: None,
+            page_size: 100,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AuditLogsResponse {
+    pub audit_logs: Vec<AuditLog>,
+}
+
+pub mod audit_log_util_functions {
+    use crate::infisical::utils::time_utils::format_rfc3339_utc;
+
+    use super::AuditLogQuery;
+
+    pub fn construct_audit_log_endpoint_url(host: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/organization/audit-logs",
+            host_url = host,
+            version = "v1",
+        )
+    }
+
+    /// audit_log_query_parameters()
+    ///
+    /// Builds the query string for one page of an audit log query. Event types are repeated, once per type.
+    pub fn audit_log_query_parameters(
+        query: &AuditLogQuery,
+        offset: u64,
+    ) -> Vec<(&'static str, String)> {
+        let mut parameters = vec![
+            ("offset", offset.to_string()),
+            ("limit", query.page_size.to_string()),
+        ];
+
+        let optional_parameters = [
+            ("projectId", query.project_id.clone()),
+            ("environment", query.environment.clone()),
+            ("secretPath", query.secret_path.clone()),
+            ("actorType", query.actor_type.map(|actor| actor.to_string())),
+            ("actorId", query.actor_id.clone()),
+            ("startDate", query.start.map(format_rfc3339_utc)),
+            ("endDate", query.end.map(format_rfc3339_utc)),
+        ];
+
+        parameters.extend(
+            optional_parameters
+                .into_iter()
+                .filter_map(|(name, value)| value.map(|value| (name, value))),
+        );
+
+        parameters.extend(
+            query
+                .event_types
+                .iter()
+                .map(|event_type| ("eventType", event_type.clone())),
+        );
+
+        parameters
+    }
+}
@@ -0,0 +1,523 @@
+/// Dynamic Secrets:
+///
+/// Management of dynamic secret configurations (database users, AWS IAM credentials, etc.), and of the
+/// leases used to generate short-lived credentials from them.
+use std::collections::HashMap;
+
+use reqwest::StatusCode;
+use secrecy::SecretBox;
+
+use error_handling::DynamicSecretError;
+use utils::{dynamic_secret_util_functions::construct_dynamic_secret_endpoint_url, *};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    environments::utils::environment_util_functions::validate_environment_slug,
+    utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Keeps a dynamic secret lease alive in the background for long-lived processes.
+pub mod lease_keeper;
+
+/// Structs and utility functions used by the dynamic secrets module, such as DynamicSecret and DynamicSecretLease.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Dynamic secret and lease management
+impl UniversalAuthAccessToken {
+    /// list_dynamic_secrets()
+    ///
+    /// Lists the dynamic secrets configured in a given scope.
+    pub async fn list_dynamic_secrets(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+    ) -> Result<Vec<DynamicSecret>, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_dynamic_secret_endpoint_url(host, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(scope)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(dynamic_secret_error(
+                "list_dynamic_secrets",
+                scope,
+                "",
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<DynamicSecretsResponse>(&bytes)?.dynamic_secrets)
+    }
+
+    /// get_dynamic_secret()
+    ///
+    /// Retrieves a single dynamic secret configuration by name.
+    pub async fn get_dynamic_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+        name: &str,
+    ) -> Result<DynamicSecret, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_dynamic_secret_endpoint_url(host, Some(name));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(scope)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(dynamic_secret_error(
+                "get_dynamic_secret",
+                scope,
+                name,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<DynamicSecretResponse>(&bytes)?.dynamic_secret)
+    }
+
+    /// create_dynamic_secret()
+    ///
+    /// Creates a new dynamic secret configuration in a given scope.
+    pub async fn create_dynamic_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+        options: &CreateDynamicSecretOptions,
+    ) -> Result<DynamicSecret, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_dynamic_secret_endpoint_url(host, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&DynamicSecretRequestBody { scope, options })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(dynamic_secret_error(
+                "create_dynamic_secret",
+                scope,
+                &options.name,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<DynamicSecretResponse>(&bytes)?.dynamic_secret)
+    }
+
+    /// update_dynamic_secret()
+    ///
+    /// Updates a dynamic secret's name, provider inputs, and/or lease durations.
+    pub async fn update_dynamic_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+        name: &str,
+        options: &UpdateDynamicSecretOptions,
+    ) -> Result<DynamicSecret, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_dynamic_secret_endpoint_url(host, Some(name));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("data", options);
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&DynamicSecretRequestBody {
+                scope,
+                options: &form_data,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(dynamic_secret_error(
+                "update_dynamic_secret",
+                scope,
+                name,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<DynamicSecretResponse>(&bytes)?.dynamic_secret)
+    }
+
+    /// delete_dynamic_secret()
+    ///
+    /// Deletes a dynamic secret configuration, revoking all of its leases first. With `force` set, the dynamic secret
+    /// is deleted even if revoking its leases with the provider fails.
+    pub async fn delete_dynamic_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+        name: &str,
+        force: bool,
+    ) -> Result<DynamicSecret, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_dynamic_secret_endpoint_url(host, Some(name));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("isForced", force);
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&DynamicSecretRequestBody {
+                scope,
+                options: &form_data,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(dynamic_secret_error(
+                "delete_dynamic_secret",
+                scope,
+                name,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<DynamicSecretResponse>(&bytes)?.dynamic_secret)
+    }
+
+    /// list_dynamic_secret_leases()
+    ///
+    /// Lists the active leases of a given dynamic secret.
+    pub async fn list_dynamic_secret_leases(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+        name: &str,
+    ) -> Result<Vec<DynamicSecretLease>, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url =
+            construct_dynamic_secret_endpoint_url(host, Some(&format!("{name}/leases")));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(scope)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(dynamic_secret_error(
+                "list_dynamic_secret_leases",
+                scope,
+                name,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<DynamicSecretLeasesResponse>(&bytes)?.leases)
+    }
+
+    /// create_dynamic_secret_lease()
+    ///
+    /// Creates a new lease on a dynamic secret, generating a fresh set of credentials with the provider.
+    /// `ttl` (e.g. "1h") defaults to the dynamic secret's default TTL.
+    pub async fn create_dynamic_secret_lease(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+        name: &str,
+        ttl: Option<&str>,
+    ) -> Result<DynamicSecretLeaseCredentials, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_dynamic_secret_endpoint_url(host, Some("leases"));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("dynamicSecretName", name);
+        if let Some(ttl) = ttl {
+            form_data.insert("ttl", ttl);
+        }
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&DynamicSecretRequestBody {
+                scope,
+                options: &form_data,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(dynamic_secret_error(
+                "create_dynamic_secret_lease",
+                scope,
+                name,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        let created_lease = serde_json::from_slice::<CreateDynamicSecretLeaseResponse>(&bytes)?;
+
+        Ok(DynamicSecretLeaseCredentials {
+            lease: created_lease.lease,
+            credentials: SecretBox::new(Box::new(DynamicSecretCredentials::from(
+                created_lease.data,
+            ))),
+        })
+    }
+
+    /// get_dynamic_secret_lease()
+    ///
+    /// Retrieves a single lease by its id. The lease's credentials are only ever returned on creation.
+    pub async fn get_dynamic_secret_lease(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+        lease_id: &str,
+    ) -> Result<DynamicSecretLease, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url =
+            construct_dynamic_secret_endpoint_url(host, Some(&format!("leases/{lease_id}")));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(scope)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(lease_error(
+                "get_dynamic_secret_lease",
+                scope,
+                lease_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<DynamicSecretLeaseResponse>(&bytes)?.lease)
+    }
+
+    /// renew_dynamic_secret_lease()
+    ///
+    /// Extends a lease by `ttl` (e.g. "1h", defaults to the dynamic secret's default TTL), up to the
+    /// dynamic secret's max TTL. Returns the renewed lease with its new expiry.
+    pub async fn renew_dynamic_secret_lease(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+        lease_id: &str,
+        ttl: Option<&str>,
+    ) -> Result<DynamicSecretLease, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url =
+            construct_dynamic_secret_endpoint_url(host, Some(&format!("leases/{lease_id}/renew")));
+
+        let mut form_data = HashMap::new();
+        if let Some(ttl) = ttl {
+            form_data.insert("ttl", ttl);
+        }
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&DynamicSecretRequestBody {
+                scope,
+                options: &form_data,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(lease_error(
+                "renew_dynamic_secret_lease",
+                scope,
+                lease_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<DynamicSecretLeaseResponse>(&bytes)?.lease)
+    }
+
+    /// revoke_dynamic_secret_lease()
+    ///
+    /// Revokes a lease, deleting its credentials with the provider. With `force` set, the lease is removed from
+    /// Infisical even if the provider fails to delete the credentials.
+    pub async fn revoke_dynamic_secret_lease(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &DynamicSecretScope,
+        lease_id: &str,
+        force: bool,
+    ) -> Result<DynamicSecretLease, DynamicSecretError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url =
+            construct_dynamic_secret_endpoint_url(host, Some(&format!("leases/{lease_id}")));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("isForced", force);
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&DynamicSecretRequestBody {
+                scope,
+                options: &form_data,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(lease_error(
+                "revoke_dynamic_secret_lease",
+                scope,
+                lease_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<DynamicSecretLeaseResponse>(&bytes)?.lease)
+    }
+}
+
+fn dynamic_secret_error(
+    operation: &str,
+    scope: &DynamicSecretScope,
+    dynamic_secret: &str,
+    error: ApiResponse,
+) -> DynamicSecretError {
+    DynamicSecretError::DynamicSecretRequestError {
+        operation: operation.to_string(),
+        project_slug: scope.project_slug.clone(),
+        environment: scope.environment.clone(),
+        secret_path: scope.secret_path.clone(),
+        dynamic_secret: dynamic_secret.to_string(),
+        error,
+    }
+}
+
+fn lease_error(
+    operation: &str,
+    scope: &DynamicSecretScope,
+    lease_id: &str,
+    error: ApiResponse,
+) -> DynamicSecretError {
+    DynamicSecretError::LeaseRequestError {
+        operation: operation.to_string(),
+        project_slug: scope.project_slug.clone(),
+        lease_id: lease_id.to_string(),
+        error,
+    }
+}
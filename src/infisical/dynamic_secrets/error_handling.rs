@@ -0,0 +1,55 @@
+use crate::infisical::{
+    environments::error_handling::EnvironmentSlugError, utils::api_utils::ApiResponse,
+};
+
+// ---------------------------------------------------------------------------------------------------------
+/// Kept separate from DynamicSecretError so synchronous callers like DynamicSecretLeaseKeeper::spawn() can return it
+/// without carrying the full (and rather large) error enum around.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("DynamicSecrets: couldn't parse lease expiry timestamp '{expire_at}'")]
+pub struct InvalidLeaseExpiry {
+    pub expire_at: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum DynamicSecretError {
+    #[error(
+        "DynamicSecrets::{operation}(): 
+        Project: {project_slug}
+        Environment: {environment}
+        Secret path: {secret_path}
+        Dynamic secret: {dynamic_secret}
+        Err: {error}"
+    )]
+    DynamicSecretRequestError {
+        operation: String,
+        project_slug: String,
+        environment: String,
+        secret_path: String,
+        dynamic_secret: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "DynamicSecrets::{operation}(): 
+        Project: {project_slug}
+        Lease ID: {lease_id}
+        Err: {error}"
+    )]
+    LeaseRequestError {
+        operation: String,
+        project_slug: String,
+        lease_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    InvalidLeaseExpiry(#[from] InvalidLeaseExpiry),
+
+    #[error(transparent)]
+    InvalidEnvironmentSlug(#[from] EnvironmentSlugError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
@@ -0,0 +1,233 @@
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use tokio::{sync::watch, task::JoinHandle};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    dynamic_secrets::{
+        error_handling::InvalidLeaseExpiry,
+        utils::{DynamicSecretLease, DynamicSecretScope},
+    },
+};
+
+/// Input for DynamicSecretLeaseKeeper::spawn().
+///
+/// - renew_ttl: the TTL requested on each renewal (e.g. "1h"). None uses the dynamic secret's default TTL.
+/// - retry_interval: how long to wait before retrying a failed renewal. Retries never go past the lease's expiry.
+///   Raised to `LeaseKeeperOptions::MIN_RETRY_INTERVAL` if shorter (including zero)
+#[derive(Clone, Debug)]
+pub struct LeaseKeeperOptions {
+    pub renew_ttl: Option<String>,
+    pub retry_interval: Duration,
+}
+
+impl LeaseKeeperOptions {
+    pub const MIN_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// The interval the lease keeper actually waits before retrying a failed renewal.
+    pub fn retry_interval(&self) -> Duration {
+        self.retry_interval.max(Self::MIN_RETRY_INTERVAL)
+    }
+}
+
+impl Default for LeaseKeeperOptions {
+    fn default() -> Self {
+        Self {
+            renew_ttl: None,
+            retry_interval: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The state of the lease a DynamicSecretLeaseKeeper is looking after.
+///
+/// - Active: the last renewal (if any) succeeded, holds the lease as last returned by Infisical
+/// - RenewalFailed: the last renewal failed, but the lease hasn't expired yet and will be retried
+/// - Expired: the lease could not be renewed before its expiry (e.g. it hit its max TTL), the keeper has stopped
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LeaseKeeperStatus {
+    Active(DynamicSecretLease),
+    RenewalFailed {
+        lease: DynamicSecretLease,
+        error: String,
+    },
+    Expired(DynamicSecretLease),
+}
+
+impl LeaseKeeperStatus {
+    pub fn lease(&self) -> &DynamicSecretLease {
+        match self {
+            Self::Active(lease) | Self::Expired(lease) => lease,
+            Self::RenewalFailed { lease, .. } => lease,
+        }
+    }
+}
+
+/// DynamicSecretLeaseKeeper
+///
+/// Renews a dynamic secret lease in the background for as long as it's kept alive, for processes that outlive a
+/// single lease TTL. Renewals happen once two thirds of the lease's remaining lifetime has passed.
+///
+/// The keeper stops renewing when dropped, or when stop() is called. The lease itself is left alone either way,
+/// revoke it with revoke_dynamic_secret_lease() if it should not outlive the process.
+///
+/// # Example
+/// ```ignore
+/// let created = access_token
+///     .create_dynamic_secret_lease(host, &client, &scope, "postgres-app", Some("1h"))
+///     .await?;
+///
+/// let keeper = DynamicSecretLeaseKeeper::spawn(
+///     access_token.clone(), // Arc<UniversalAuthAccessToken>
+///     host,
+///     client.clone(),
+///     scope,
+///     created.lease.clone(),
+///     LeaseKeeperOptions::default(),
+/// )?;
+///
+/// let mut status = keeper.subscribe();
+/// while status.changed().await.is_ok() {
+///     if let LeaseKeeperStatus::Expired(_) = *status.borrow() {
+///         // time to create a new lease
+///     }
+/// }
+/// ```
+pub struct DynamicSecretLeaseKeeper {
+    status: watch::Receiver<LeaseKeeperStatus>,
+    task: JoinHandle<()>,
+}
+
+impl DynamicSecretLeaseKeeper {
+    /// spawn()
+    ///
+    /// Starts renewing `lease` on the current tokio runtime. Fails if the lease's expiry can't be parsed.
+    pub fn spawn(
+        access_token: Arc<UniversalAuthAccessToken>,
+        host: &str,
+        client: reqwest::Client,
+        scope: DynamicSecretScope,
+        lease: DynamicSecretLease,
+        options: LeaseKeeperOptions,
+    ) -> Result<Self, InvalidLeaseExpiry> {
+        let expires_at = lease.expires_at().ok_or_else(|| InvalidLeaseExpiry {
+            expire_at: lease.expire_at.clone(),
+        })?;
+
+        let (sender, status) = watch::channel(LeaseKeeperStatus::Active(lease.clone()));
+        let host = host.to_string();
+
+        let task = tokio::spawn(async move {
+            let mut lease = lease;
+            let mut expires_at = expires_at;
+            let mut next_renewal = renewal_delay(expires_at);
+
+            loop {
+                tokio::time::sleep(next_renewal).await;
+
+                let renewal = access_token
+                    .renew_dynamic_secret_lease(
+                        &host,
+                        &client,
+                        &scope,
+                        &lease.id,
+                        options.renew_ttl.as_deref(),
+                    )
+                    .await;
+
+                let status = match renewal {
+                    Ok(renewed_lease) => match renewed_lease.expires_at() {
+                        Some(renewed_expiry) => {
+                            lease = renewed_lease;
+                            expires_at = renewed_expiry;
+                            next_renewal = renewal_delay(expires_at);
+                            LeaseKeeperStatus::Active(lease.clone())
+                        }
+                        None => {
+                            let error = InvalidLeaseExpiry {
+                                expire_at: renewed_lease.expire_at,
+                            };
+                            retry_or_expire(&lease, expires_at, error.to_string())
+                        }
+                    },
+                    Err(error) => retry_or_expire(&lease, expires_at, error.to_string()),
+                };
+
+                let expired = matches!(status, LeaseKeeperStatus::Expired(_));
+                if let LeaseKeeperStatus::RenewalFailed { .. } = status {
+                    next_renewal = options.retry_interval().min(time_until(expires_at));
+                }
+
+                // nobody is listening anymore, no point in keeping the lease alive
+                if sender.send(status).is_err() || expired {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self { status, task })
+    }
+
+    /// The current status, including the lease as last returned by Infisical.
+    pub fn status(&self) -> LeaseKeeperStatus {
+        self.status.borrow().clone()
+    }
+
+    /// A receiver notified on every renewal attempt.
+    pub fn subscribe(&self) -> watch::Receiver<LeaseKeeperStatus> {
+        self.status.clone()
+    }
+
+    /// Whether the keeper is still renewing the lease.
+    pub fn is_running(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    /// Stops renewing the lease, returning its last known status.
+    pub fn stop(self) -> LeaseKeeperStatus {
+        self.task.abort();
+        self.status()
+    }
+}
+
+impl Drop for DynamicSecretLeaseKeeper {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn time_until(instant: SystemTime) -> Duration {
+    instant
+        .duration_since(SystemTime::now())
+        .unwrap_or(Duration::ZERO)
+}
+
+// renew once two thirds of the remaining lifetime have passed
+fn renewal_delay(expires_at: SystemTime) -> Duration {
+    time_until(expires_at).mul_f64(2.0 / 3.0)
+}
+
+fn retry_or_expire(
+    lease: &DynamicSecretLease,
+    expires_at: SystemTime,
+    error: String,
+) -> LeaseKeeperStatus {
+    #[cfg(not(feature = "logging_silent"))]
+    println!(
+        "DynamicSecretLeaseKeeper: failed to renew lease {}: {error}",
+        lease.id
+    );
+
+    // a retry has to land before the lease expires to be of any use
+    if time_until(expires_at) < Duration::from_secs(1) {
+        LeaseKeeperStatus::Expired(lease.clone())
+    } else {
+        LeaseKeeperStatus::RenewalFailed {
+            lease: lease.clone(),
+            error,
+        }
+    }
+}
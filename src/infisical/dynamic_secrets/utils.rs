@@ -0,0 +1,228 @@
+use std::{collections::HashMap, time::SystemTime};
+
+use secrecy::{ExposeSecret, SecretBox, SerializableSecret, zeroize::Zeroize};
+use serde::{Deserialize, Serialize};
+
+use crate::infisical::utils::time_utils::parse_rfc3339_utc;
+
+/// Where a given dynamic secret lives. Unlike the secrets endpoints, the dynamic secrets endpoints
+/// identify projects by slug rather than by id.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct DynamicSecretScope {
+    pub project_slug: String,
+    #[serde(rename = "environmentSlug")]
+    pub environment: String,
+    #[serde(rename = "path")]
+    pub secret_path: String,
+}
+
+impl DynamicSecretScope {
+    /// secret_path defaults to the environment's root folder ("/") if left as None
+    pub fn new(project_slug: &str, environment: &str, secret_path: Option<&str>) -> Self {
+        Self {
+            project_slug: project_slug.to_string(),
+            environment: environment.to_string(),
+            secret_path: secret_path.unwrap_or("/").to_string(),
+        }
+    }
+}
+
+/// A dynamic secret configuration, i.e. the recipe Infisical uses to generate credentials on demand.
+///
+/// Fields:
+/// - id/name: the dynamic secret's id and name
+/// - type_: the provider type, e.g. "sql-database", "aws-iam"
+/// - default_ttl/max_ttl: lease durations, in Infisical's duration format (e.g. "1h", "30m")
+/// - status/status_details: set when Infisical fails to reach the provider
+///
+/// Note: the provider inputs (which usually contain root credentials) are deliberately not deserialized.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct DynamicSecret {
+    pub id: String,
+    pub name: String,
+    #[serde(rename(serialize = "type_", deserialize = "type"))]
+    pub type_: String,
+    #[serde(default)]
+    pub version: u64,
+    #[serde(rename(serialize = "default_ttl", deserialize = "defaultTTL"))]
+    pub default_ttl: String,
+    #[serde(rename(serialize = "max_ttl", deserialize = "maxTTL"), default)]
+    pub max_ttl: Option<String>,
+    #[serde(default)]
+    pub folder_id: String,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub status_details: Option<String>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// The provider a dynamic secret generates credentials with, e.g.
+/// `{ "type": "sql-database", "inputs": { "client": "postgres", "host": ..., ... } }`.
+///
+/// Inputs are provider-specific, see <https://infisical.com/docs/documentation/platform/dynamic-secrets/overview>
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamicSecretProvider {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub inputs: serde_json::Value,
+}
+
+/// Input for create_dynamic_secret().
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateDynamicSecretOptions {
+    pub name: String,
+    pub provider: DynamicSecretProvider,
+    #[serde(rename = "defaultTTL")]
+    pub default_ttl: String,
+    #[serde(rename = "maxTTL", skip_serializing_if = "Option::is_none")]
+    pub max_ttl: Option<String>,
+}
+
+/// Input for update_dynamic_secret(). Any field left as None is left untouched.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct UpdateDynamicSecretOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<serde_json::Value>,
+    #[serde(rename = "defaultTTL", skip_serializing_if = "Option::is_none")]
+    pub default_ttl: Option<String>,
+    #[serde(rename = "maxTTL", skip_serializing_if = "Option::is_none")]
+    pub max_ttl: Option<String>,
+}
+
+/// A lease on a dynamic secret, i.e. one set of generated credentials and how long they're valid for.
+///
+/// Fields:
+/// - id: the lease's id, used to renew or revoke it
+/// - expire_at: when the lease (and its credentials) expire, as an RFC 3339 timestamp
+/// - dynamic_secret_id: the dynamic secret the lease was created from
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct DynamicSecretLease {
+    pub id: String,
+    pub expire_at: String,
+    #[serde(default)]
+    pub version: u64,
+    #[serde(default)]
+    pub dynamic_secret_id: String,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+impl DynamicSecretLease {
+    /// expire_at, parsed. None if Infisical ever sends back a timestamp format we don't understand.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        parse_rfc3339_utc(&self.expire_at)
+    }
+}
+
+/// Returned by create_dynamic_secret_lease(): the new lease, and the credentials generated for it.
+pub struct DynamicSecretLeaseCredentials {
+    pub lease: DynamicSecretLease,
+    pub credentials: SecretBox<DynamicSecretCredentials>,
+}
+
+impl DynamicSecretLeaseCredentials {
+    /// convenience getter for a single generated credential, e.g. `credentials.get("DB_PASSWORD")`
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.credentials
+            .expose_secret()
+            .values
+            .get(key)
+            .map(|value| value.as_str())
+    }
+}
+
+/// The credentials generated for a lease, keyed by whatever names the provider uses
+/// (e.g. DB_USERNAME/DB_PASSWORD for SQL databases). Non-string values are stored in their JSON form.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct DynamicSecretCredentials {
+    pub values: HashMap<String, String>,
+}
+
+impl SerializableSecret for DynamicSecretCredentials {}
+
+impl Zeroize for DynamicSecretCredentials {
+    fn zeroize(&mut self) {
+        self.values.values_mut().for_each(|value| value.zeroize());
+        self.values.clear();
+    }
+}
+
+impl From<serde_json::Map<String, serde_json::Value>> for DynamicSecretCredentials {
+    fn from(data: serde_json::Map<String, serde_json::Value>) -> Self {
+        Self {
+            values: data
+                .into_iter()
+                .map(|(key, value)| match value {
+                    serde_json::Value::String(value) => (key, value),
+                    value => (key, value.to_string()),
+                })
+                .collect(),
+        }
+    }
+}
+
+// request/response envelopes for the dynamic secrets endpoints
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DynamicSecretRequestBody<'a, T: Serialize> {
+    #[serde(flatten)]
+    pub scope: &'a DynamicSecretScope,
+    #[serde(flatten)]
+    pub options: &'a T,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DynamicSecretResponse {
+    pub dynamic_secret: DynamicSecret,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DynamicSecretsResponse {
+    pub dynamic_secrets: Vec<DynamicSecret>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct DynamicSecretLeaseResponse {
+    pub lease: DynamicSecretLease,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct DynamicSecretLeasesResponse {
+    pub leases: Vec<DynamicSecretLease>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CreateDynamicSecretLeaseResponse {
+    pub lease: DynamicSecretLease,
+    pub data: serde_json::Map<String, serde_json::Value>,
+}
+
+pub mod dynamic_secret_util_functions {
+    pub fn construct_dynamic_secret_endpoint_url(host: &str, path: Option<&str>) -> String {
+        let is_path = path.map_or_else(|| "".to_string(), |path| format!("/{path}"));
+
+        format!(
+            "{host_url}/api/{version}/dynamic-secrets{path}",
+            host_url = host,
+            version = "v1",
+            path = is_path
+        )
+    }
+}
@@ -0,0 +1,293 @@
+/// Environments:
+///
+/// Listing and management of a project's (workspace's) environments.
+use reqwest::StatusCode;
+
+use error_handling::{EnvironmentError, EnvironmentOrderingError};
+use utils::{
+    environment_util_functions::{
+        construct_environment_endpoint_url, order_environments, validate_environment_slug,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the environments module, such as the Environment struct and slug validation.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Environment management for a given project (workspace)
+impl UniversalAuthAccessToken {
+    /// list_environments()
+    ///
+    /// Lists every environment in a given project, sorted by position.
+    ///
+    /// Arguments:
+    ///     - host: Infisical host url, e.g.: <https://us.infisical.com>
+    ///     - client: reqwest client to use. Defaults to async version
+    ///     - workspace_id: id of the project (workspace) to list environments from
+    pub async fn list_environments(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+    ) -> Result<Vec<Environment>, EnvironmentError> {
+        let endpoint_url = format!(
+            "{host_url}/api/{version}/workspace/{workspace_id}",
+            host_url = host,
+            version = "v1",
+            workspace_id = workspace_id
+        );
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(EnvironmentError::ListEnvironmentsError {
+                workspace_id: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        let mut environments = serde_json::from_slice::<WorkspaceEnvironmentsResponse>(&bytes)?
+            .workspace
+            .environments;
+        environments.sort_by_key(|environment| environment.position);
+
+        Ok(environments)
+    }
+
+    /// get_environment()
+    ///
+    /// Retrieves a single environment by its id.
+    pub async fn get_environment(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        environment_id: &str,
+    ) -> Result<Environment, EnvironmentError> {
+        let endpoint_url =
+            construct_environment_endpoint_url(host, workspace_id, Some(environment_id));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(EnvironmentError::GetEnvironmentError {
+                workspace_id: workspace_id.to_string(),
+                environment_id: environment_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<EnvironmentResponse>(&bytes)?.environment)
+    }
+
+    /// create_environment()
+    ///
+    /// Creates a new environment in the given project. The slug is validated before the request is sent.
+    pub async fn create_environment(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        options: &CreateEnvironmentOptions,
+    ) -> Result<Environment, EnvironmentError> {
+        validate_environment_slug(&options.slug)?;
+
+        let endpoint_url = construct_environment_endpoint_url(host, workspace_id, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(EnvironmentError::CreateEnvironmentError {
+                workspace_id: workspace_id.to_string(),
+                slug: options.slug.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<EnvironmentResponse>(&bytes)?.environment)
+    }
+
+    /// update_environment()
+    ///
+    /// Updates the name, slug, and/or position of a given environment. If a new slug is given,
+    /// it is validated before the request is sent.
+    pub async fn update_environment(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        environment_id: &str,
+        options: &UpdateEnvironmentOptions,
+    ) -> Result<Environment, EnvironmentError> {
+        if let Some(slug) = &options.slug {
+            validate_environment_slug(slug)?;
+        }
+
+        let endpoint_url =
+            construct_environment_endpoint_url(host, workspace_id, Some(environment_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(EnvironmentError::UpdateEnvironmentError {
+                workspace_id: workspace_id.to_string(),
+                environment_id: environment_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<EnvironmentResponse>(&bytes)?.environment)
+    }
+
+    /// delete_environment()
+    ///
+    /// Deletes a given environment, along with every secret stored in it. Returns the deleted environment.
+    pub async fn delete_environment(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        environment_id: &str,
+    ) -> Result<Environment, EnvironmentError> {
+        let endpoint_url =
+            construct_environment_endpoint_url(host, workspace_id, Some(environment_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(EnvironmentError::DeleteEnvironmentError {
+                workspace_id: workspace_id.to_string(),
+                environment_id: environment_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<EnvironmentResponse>(&bytes)?.environment)
+    }
+
+    /// reorder_environments()
+    ///
+    /// Reorders a project's environments to match the order of the given slugs, e.g. `["dev", "staging", "prod"]`.
+    /// Environments not mentioned in `ordered_slugs` keep their relative order after the ones that are.
+    ///
+    /// Returns the project's environments in their new order, as listed once every update went through.
+    ///
+    /// Note: Infisical has no bulk reorder endpoint, so this is one update_environment() call per environment.
+    pub async fn reorder_environments(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        ordered_slugs: &[&str],
+    ) -> Result<Vec<Environment>, EnvironmentError> {
+        for slug in ordered_slugs {
+            validate_environment_slug(slug)?;
+        }
+
+        let environments = self.list_environments(host, client, workspace_id).await?;
+
+        let reordered =
+            order_environments(environments, ordered_slugs).map_err(|error| match error {
+                EnvironmentOrderingError::UnknownSlug(slug) => {
+                    EnvironmentError::EnvironmentNotFound {
+                        workspace_id: workspace_id.to_string(),
+                        slug,
+                    }
+                }
+                EnvironmentOrderingError::DuplicateSlug(slug) => {
+                    EnvironmentError::DuplicateEnvironmentSlug {
+                        workspace_id: workspace_id.to_string(),
+                        slug,
+                    }
+                }
+            })?;
+
+        // Infisical shifts the other environments whenever one moves, so positions read up front go stale after the
+        // first update. Placing every environment in turn, from the first position on, never disturbs the ones
+        // already placed.
+        for (index, environment) in reordered.iter().enumerate() {
+            self.update_environment(
+                host,
+                client,
+                workspace_id,
+                &environment.id,
+                &UpdateEnvironmentOptions {
+                    position: Some(index as u64 + 1),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        }
+
+        self.list_environments(host, client, workspace_id).await
+    }
+}
@@ -0,0 +1,102 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+/// Returned by validate_environment_slug(). Kept separate from EnvironmentError
+/// so that slug validation can be reused outside of the environments module (e.g.: get_secret()).
+#[derive(thiserror::Error, Debug)]
+#[error("Invalid environment slug '{slug}': {reason}")]
+pub struct EnvironmentSlugError {
+    pub slug: String,
+    pub reason: String,
+}
+
+/// Returned by order_environments(). reorder_environments() turns these into EnvironmentError::EnvironmentNotFound
+/// and EnvironmentError::DuplicateEnvironmentSlug.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum EnvironmentOrderingError {
+    #[error("no environment with slug '{0}' exists")]
+    UnknownSlug(String),
+
+    #[error("environment slug '{0}' is listed more than once")]
+    DuplicateSlug(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum EnvironmentError {
+    #[error(transparent)]
+    InvalidEnvironmentSlug(#[from] EnvironmentSlugError),
+
+    #[error(
+        "Environments::list_environments(): 
+        Workspace ID: {workspace_id}
+        Err: {error}"
+    )]
+    ListEnvironmentsError {
+        workspace_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Environments::get_environment(): 
+        Workspace ID: {workspace_id}
+        Environment ID: {environment_id}
+        Err: {error}"
+    )]
+    GetEnvironmentError {
+        workspace_id: String,
+        environment_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Environments::create_environment(): 
+        Workspace ID: {workspace_id}
+        Environment slug: {slug}
+        Err: {error}"
+    )]
+    CreateEnvironmentError {
+        workspace_id: String,
+        slug: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Environments::update_environment(): 
+        Workspace ID: {workspace_id}
+        Environment ID: {environment_id}
+        Err: {error}"
+    )]
+    UpdateEnvironmentError {
+        workspace_id: String,
+        environment_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Environments::delete_environment(): 
+        Workspace ID: {workspace_id}
+        Environment ID: {environment_id}
+        Err: {error}"
+    )]
+    DeleteEnvironmentError {
+        workspace_id: String,
+        environment_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Environments::reorder_environments(): no environment with slug '{slug}' exists in workspace {workspace_id}"
+    )]
+    EnvironmentNotFound { workspace_id: String, slug: String },
+
+    #[error(
+        "Environments::reorder_environments(): environment slug '{slug}' is listed more than once for workspace {workspace_id}"
+    )]
+    DuplicateEnvironmentSlug { workspace_id: String, slug: String },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+
+/// A single project (workspace) environment, e.g. `dev`, `staging`, `prod`.
+///
+/// Fields:
+/// - id: the environment's id, used when updating or deleting an environment
+/// - name: the environment's display name
+/// - slug: the environment's slug, i.e. what you'd pass in as `environment` to get_secret()
+/// - position: the environment's position in the project's environment ordering (starting at 1)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct Environment {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    #[serde(default)]
+    pub position: u64,
+}
+
+/// Request body for create_environment(). Position is optional, and Infisical
+/// appends the new environment to the end of the project's environment list if left out.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateEnvironmentOptions {
+    pub name: String,
+    pub slug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
+}
+
+/// Request body for update_environment(). Any field left as None is left untouched by Infisical.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct UpdateEnvironmentOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
+}
+
+// wrapper structs for the JSON envelopes Infisical returns environments in
+#[derive(Deserialize)]
+pub(crate) struct EnvironmentResponse {
+    pub environment: Environment,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WorkspaceEnvironments {
+    pub environments: Vec<Environment>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WorkspaceEnvironmentsResponse {
+    pub workspace: WorkspaceEnvironments,
+}
+
+pub mod environment_util_functions {
+    use crate::infisical::environments::{
+        error_handling::{EnvironmentOrderingError, EnvironmentSlugError},
+        utils::Environment,
+    };
+
+    /// Infisical's own limit on slug length
+    pub const ENVIRONMENT_SLUG_MAX_LENGTH: usize = 64;
+
+    pub fn construct_environment_endpoint_url(
+        host: &str,
+        workspace_id: &str,
+        environment_id: Option<&str>,
+    ) -> String {
+        let is_environment_id = environment_id.map_or_else(
+            || "".to_string(),
+            |environment_id| format!("/{environment_id}"),
+        );
+
+        format!(
+            "{host_url}/api/{version}/workspace/{workspace_id}/environments{environment_id}",
+            host_url = host,
+            version = "v1",
+            workspace_id = workspace_id,
+            environment_id = is_environment_id
+        )
+    }
+
+    /// validate_environment_slug()
+    ///
+    /// Checks a given environment slug against the format Infisical accepts (lowercase letters, digits, and
+    /// single hyphens, not starting or ending with a hyphen), so malformed slugs fail before a request is ever sent.
+    pub fn validate_environment_slug(slug: &str) -> Result<(), EnvironmentSlugError> {
+        let invalid = |reason: &str| EnvironmentSlugError {
+            slug: slug.to_string(),
+            reason: reason.to_string(),
+        };
+
+        if slug.is_empty() {
+            return Err(invalid("slug cannot be empty"));
+        }
+
+        if slug.len() > ENVIRONMENT_SLUG_MAX_LENGTH {
+            return Err(invalid("slug cannot be longer than 64 characters"));
+        }
+
+        if !slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(invalid(
+                "slug can only contain lowercase letters, digits, and hyphens",
+            ));
+        }
+
+        if slug.starts_with('-') || slug.ends_with('-') || slug.contains("--") {
+            return Err(invalid(
+                "slug cannot start or end with a hyphen, or contain consecutive hyphens",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// order_environments()
+    ///
+    /// Orders environments by the given slugs, with the environments not mentioned keeping their relative order
+    /// after the ones that are. Fails on the first slug that's listed twice, or that none of the environments has.
+    pub fn order_environments(
+        mut environments: Vec<Environment>,
+        ordered_slugs: &[&str],
+    ) -> Result<Vec<Environment>, EnvironmentOrderingError> {
+        let mut ordered: Vec<Environment> = Vec::with_capacity(environments.len());
+
+        for slug in ordered_slugs {
+            if ordered.iter().any(|environment| environment.slug.eq(slug)) {
+                return Err(EnvironmentOrderingError::DuplicateSlug(slug.to_string()));
+            }
+
+            let index = environments
+                .iter()
+                .position(|environment| environment.slug.eq(slug))
+                .ok_or_else(|| EnvironmentOrderingError::UnknownSlug(slug.to_string()))?;
+            ordered.push(environments.remove(index));
+        }
+        ordered.append(&mut environments);
+
+        Ok(ordered)
+    }
+}
@@ -0,0 +1,522 @@
+/// Groups:
+///
+/// User groups: creating them within an organization, managing their members, and giving them access to projects
+/// with project roles, which every member of the group then holds.
+use reqwest::StatusCode;
+
+use error_handling::GroupError;
+use utils::{
+    group_util_functions::{
+        construct_group_endpoint_url, construct_organization_groups_endpoint_url,
+        construct_project_groups_endpoint_url,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    project_memberships::utils::{
+        ProjectMembershipRole, ProjectMembershipRolesResponse, ProjectRoleAssignment,
+        RoleAssignmentsBody,
+    },
+    utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the groups module, such as Group and ProjectGroupMembership.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Group management
+impl UniversalAuthAccessToken {
+    /// list_groups()
+    ///
+    /// Lists an organization's groups.
+    pub async fn list_groups(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+    ) -> Result<Vec<Group>, GroupError> {
+        let endpoint_url = construct_organization_groups_endpoint_url(host, organization_id);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(GroupError::OrganizationRequestError {
+                operation: "list_groups".to_string(),
+                organization_id: organization_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<GroupsResponse>(&bytes)?.groups)
+    }
+
+    /// get_group()
+    ///
+    /// Retrieves a group by its id.
+    pub async fn get_group(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        group_id: &str,
+    ) -> Result<Group, GroupError> {
+        let endpoint_url = construct_group_endpoint_url(host, Some(group_id));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(group_error("get_group", group_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<Group>(&bytes)?)
+    }
+
+    /// create_group()
+    ///
+    /// Creates a group in the organization the current identity belongs to.
+    pub async fn create_group(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateGroupOptions,
+    ) -> Result<Group, GroupError> {
+        let endpoint_url = construct_group_endpoint_url(host, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(group_error("create_group", &options.name, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<Group>(&bytes)?)
+    }
+
+    /// update_group()
+    ///
+    /// Updates a group's name, slug or organization role.
+    pub async fn update_group(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        group_id: &str,
+        options: &UpdateGroupOptions,
+    ) -> Result<Group, GroupError> {
+        let endpoint_url = construct_group_endpoint_url(host, Some(group_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(group_error("update_group", group_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<Group>(&bytes)?)
+    }
+
+    /// delete_group()
+    ///
+    /// Deletes a group. Its members lose the access they had through it, but stay in the organization.
+    pub async fn delete_group(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        group_id: &str,
+    ) -> Result<Group, GroupError> {
+        let endpoint_url = construct_group_endpoint_url(host, Some(group_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(group_error("delete_group", group_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<Group>(&bytes)?)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Group members
+impl UniversalAuthAccessToken {
+    /// list_group_users()
+    ///
+    /// Lists a page of a group's members, or of the organization's users, depending on the options' filter.
+    pub async fn list_group_users(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        group_id: &str,
+        options: &ListGroupUsersOptions,
+    ) -> Result<Vec<GroupUser>, GroupError> {
+        let endpoint_url = construct_group_endpoint_url(host, Some(&format!("{group_id}/users")));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(group_error("list_group_users", group_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<GroupUsersResponse>(&bytes)?.users)
+    }
+
+    /// add_user_to_group()
+    ///
+    /// Adds an organization user to a group by their username (usually their email).
+    pub async fn add_user_to_group(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        group_id: &str,
+        username: &str,
+    ) -> Result<GroupUser, GroupError> {
+        let endpoint_url =
+            construct_group_endpoint_url(host, Some(&format!("{group_id}/users/{username}")));
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(group_user_error(
+                "add_user_to_group",
+                group_id,
+                username,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<GroupUser>(&bytes)?)
+    }
+
+    /// remove_user_from_group()
+    ///
+    /// Removes a user from a group by their username. They stay members of the organization.
+    pub async fn remove_user_from_group(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        group_id: &str,
+        username: &str,
+    ) -> Result<GroupUser, GroupError> {
+        let endpoint_url =
+            construct_group_endpoint_url(host, Some(&format!("{group_id}/users/{username}")));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(group_user_error(
+                "remove_user_from_group",
+                group_id,
+                username,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<GroupUser>(&bytes)?)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Group project memberships
+impl UniversalAuthAccessToken {
+    /// list_project_group_memberships()
+    ///
+    /// Lists the groups that are members of a project, with their roles.
+    pub async fn list_project_group_memberships(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+    ) -> Result<Vec<ProjectGroupMembership>, GroupError> {
+        let endpoint_url = construct_project_groups_endpoint_url(host, project_id, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(GroupError::ProjectRequestError {
+                operation: "list_project_group_memberships".to_string(),
+                project_id: project_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectGroupMembershipsResponse>(&bytes)?.group_memberships)
+    }
+
+    /// add_group_to_project()
+    ///
+    /// Adds a group from the project's organization to the project, with the given roles.
+    ///
+    /// # Example
+    /// ```ignore
+    /// access_token
+    ///     .add_group_to_project(
+    ///         host,
+    ///         &client,
+    ///         project_id,
+    ///         &group.id,
+    ///         &[ProjectRoleAssignment::permanent("member")],
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn add_group_to_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        group_id: &str,
+        roles: &[ProjectRoleAssignment],
+    ) -> Result<ProjectGroupMembership, GroupError> {
+        let endpoint_url = construct_project_groups_endpoint_url(host, project_id, Some(group_id));
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&RoleAssignmentsBody::new(roles))
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(project_group_error(
+                "add_group_to_project",
+                project_id,
+                group_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectGroupMembershipResponse>(&bytes)?.group_membership)
+    }
+
+    /// update_project_group_roles()
+    ///
+    /// Replaces a group's roles in a project, returning its new roles.
+    pub async fn update_project_group_roles(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        group_id: &str,
+        roles: &[ProjectRoleAssignment],
+    ) -> Result<Vec<ProjectMembershipRole>, GroupError> {
+        let endpoint_url = construct_project_groups_endpoint_url(host, project_id, Some(group_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&RoleAssignmentsBody::new(roles))
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(project_group_error(
+                "update_project_group_roles",
+                project_id,
+                group_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectMembershipRolesResponse>(&bytes)?.roles)
+    }
+
+    /// remove_group_from_project()
+    ///
+    /// Removes a group from a project. Its members keep any access they have to the project of their own.
+    pub async fn remove_group_from_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        group_id: &str,
+    ) -> Result<ProjectGroupMembership, GroupError> {
+        let endpoint_url = construct_project_groups_endpoint_url(host, project_id, Some(group_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(project_group_error(
+                "remove_group_from_project",
+                project_id,
+                group_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectGroupMembershipResponse>(&bytes)?.group_membership)
+    }
+}
+
+fn group_error(operation: &str, group: &str, error: ApiResponse) -> GroupError {
+    GroupError::GroupRequestError {
+        operation: operation.to_string(),
+        group: group.to_string(),
+        error,
+    }
+}
+
+fn group_user_error(
+    operation: &str,
+    group_id: &str,
+    username: &str,
+    error: ApiResponse,
+) -> GroupError {
+    GroupError::GroupUserRequestError {
+        operation: operation.to_string(),
+        group_id: group_id.to_string(),
+        username: username.to_string(),
+        error,
+    }
+}
+
+fn project_group_error(
+    operation: &str,
+    project_id: &str,
+    group_id: &str,
+    error: ApiResponse,
+) -> GroupError {
+    GroupError::ProjectGroupRequestError {
+        operation: operation.to_string(),
+        project_id: project_id.to_string(),
+        group_id: group_id.to_string(),
+        error,
+    }
+}
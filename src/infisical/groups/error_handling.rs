@@ -0,0 +1,70 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum GroupError {
+    #[error(
+        "Groups::{operation}(): 
+        Organization ID: {organization_id}
+        Err: {error}"
+    )]
+    OrganizationRequestError {
+        operation: String,
+        organization_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Groups::{operation}(): 
+        Group: {group}
+        Err: {error}"
+    )]
+    GroupRequestError {
+        operation: String,
+        group: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Groups::{operation}(): 
+        Group ID: {group_id}
+        Username: {username}
+        Err: {error}"
+    )]
+    GroupUserRequestError {
+        operation: String,
+        group_id: String,
+        username: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Groups::{operation}(): 
+        Project ID: {project_id}
+        Err: {error}"
+    )]
+    ProjectRequestError {
+        operation: String,
+        project_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Groups::{operation}(): 
+        Project ID: {project_id}
+        Group ID: {group_id}
+        Err: {error}"
+    )]
+    ProjectGroupRequestError {
+        operation: String,
+        project_id: String,
+        group_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
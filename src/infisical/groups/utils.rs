@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+use crate::infisical::project_memberships::utils::ProjectMembershipRole;
+
+/// A user group: a set of organization users managed together, e.g. mirrored from a directory team.
+///
+/// Fields:
+/// - role: the organization role's slug the group's members get, "custom" for custom roles (see role_id)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct Group {
+    pub id: String,
+    pub org_id: String,
+    pub name: String,
+    pub slug: String,
+    pub role: String,
+    #[serde(default)]
+    pub role_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Input for create_group().
+///
+/// - slug: None derives one from the name
+/// - role: the organization role's slug to give the group's members, e.g. "member" or "no-access"
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateGroupOptions {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    pub role: String,
+}
+
+/// Input for update_group(). Any field left as None is left untouched.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGroupOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
+/// Which of the organization's users list_group_users() returns.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum GroupUsersFilter {
+    ExistingMembers,
+    NonMembers,
+}
+
+/// Options for list_group_users().
+///
+/// - username: only return users whose username contains this
+/// - filter: None returns every organization user, whether they're part of the group or not
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListGroupUsersOptions {
+    pub offset: u64,
+    pub limit: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<GroupUsersFilter>,
+}
+
+impl Default for ListGroupUsersOptions {
+    /// the first 100 members of the group
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            limit: 100,
+            username: None,
+            filter: Some(GroupUsersFilter::ExistingMembers),
+        }
+    }
+}
+
+/// An organization user, as listed for a group.
+///
+/// Fields:
+/// - joined_group_at: when the user was added to the group, as an RFC 3339 timestamp
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct GroupUser {
+    pub id: String,
+    pub username: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub is_part_of_group: bool,
+    #[serde(default)]
+    pub joined_group_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct ProjectMemberGroup {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+}
+
+/// A group's membership in a project. Its members get the membership's roles on top of their own.
+///
+/// Fields:
+/// - group/roles: left empty in the memberships returned by add_group_to_project() and remove_group_from_project()
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct ProjectGroupMembership {
+    pub id: String,
+    pub group_id: String,
+    #[serde(default)]
+    pub group: Option<ProjectMemberGroup>,
+    #[serde(default)]
+    pub roles: Vec<ProjectMembershipRole>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct GroupsResponse {
+    pub groups: Vec<Group>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct GroupUsersResponse {
+    pub users: Vec<GroupUser>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectGroupMembershipResponse {
+    pub group_membership: ProjectGroupMembership,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectGroupMembershipsResponse {
+    pub group_memberships: Vec<ProjectGroupMembership>,
+}
+
+pub mod group_util_functions {
+    pub fn construct_group_endpoint_url(host: &str, path: Option<&str>) -> String {
+        let is_path = path.map_or_else(|| "".to_string(), |path| format!("/{path}"));
+
+        format!(
+            "{host_url}/api/{version}/groups{path}",
+            host_url = host,
+            version = "v1",
+            path = is_path
+        )
+    }
+
+    pub fn construct_organization_groups_endpoint_url(host: &str, organization_id: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/organization/{organization_id}/groups",
+            host_url = host,
+            version = "v1",
+        )
+    }
+
+    pub fn construct_project_groups_endpoint_url(
+        host: &str,
+        project_id: &str,
+        group_id: Option<&str>,
+    ) -> String {
+        let is_group_id =
+            group_id.map_or_else(|| "".to_string(), |group_id| format!("/{group_id}"));
+
+        format!(
+            "{host_url}/api/{version}/workspace/{project_id}/groups{group_id}",
+            host_url = host,
+            version = "v2",
+            group_id = is_group_id
+        )
+    }
+}
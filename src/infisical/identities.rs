@@ -0,0 +1,244 @@
+/// Identities:
+///
+/// An organization's machine identities: creating them with an organization role and metadata, and managing them
+/// afterwards. Auth methods are attached separately, e.g. with universal auth's attach().
+use reqwest::StatusCode;
+
+use error_handling::IdentityError;
+use utils::{
+    identity_util_functions::{
+        construct_identity_endpoint_url, construct_organization_identities_endpoint_url,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the identities module, such as MachineIdentity and OrganizationIdentity.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Machine identity management
+impl UniversalAuthAccessToken {
+    /// list_identities()
+    ///
+    /// Lists a page of an organization's machine identities, with their organization roles and metadata.
+    pub async fn list_identities(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+        options: &ListIdentitiesOptions,
+    ) -> Result<Vec<OrganizationIdentity>, IdentityError> {
+        let endpoint_url = construct_organization_identities_endpoint_url(host, organization_id);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(IdentityError::OrganizationRequestError {
+                operation: "list_identities".to_string(),
+                organization_id: organization_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationIdentitiesResponse>(&bytes)?.identity_memberships)
+    }
+
+    /// get_identity()
+    ///
+    /// Retrieves a machine identity by its id, with its organization role and metadata.
+    pub async fn get_identity(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        identity_id: &str,
+    ) -> Result<OrganizationIdentity, IdentityError> {
+        let endpoint_url = construct_identity_endpoint_url(host, Some(identity_id));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(identity_error("get_identity", identity_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationIdentityResponse>(&bytes)?.identity)
+    }
+
+    /// create_identity()
+    ///
+    /// Creates a machine identity in an organization. It can't authenticate until an auth method is attached.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let identity = access_token
+    ///     .create_identity(
+    ///         host,
+    ///         &client,
+    ///         &CreateIdentityOptions {
+    ///             name: "payments-ci".to_string(),
+    ///             organization_id: organization_id.to_string(),
+    ///             role: "no-access".to_string(),
+    ///             has_delete_protection: false,
+    ///             metadata: vec![IdentityMetadata {
+    ///                 key: "team".to_string(),
+    ///                 value: "payments".to_string(),
+    ///             }],
+    ///         },
+    ///     )
+    ///     .await?;
+    ///
+    /// access_token
+    ///     .attach(host, &client, &identity.id, None, None, None, None, None)
+    ///     .await?;
+    ///
+    /// let client_secret = access_token
+    ///     .create_client_secret(host, &client, &identity.id, "provisioned by CI", 0, 0)
+    ///     .await?;
+    /// ```
+    pub async fn create_identity(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateIdentityOptions,
+    ) -> Result<MachineIdentity, IdentityError> {
+        let endpoint_url = construct_identity_endpoint_url(host, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(IdentityError::OrganizationRequestError {
+                operation: "create_identity".to_string(),
+                organization_id: options.organization_id.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<IdentityResponse>(&bytes)?.identity)
+    }
+
+    /// update_identity()
+    ///
+    /// Updates a machine identity's name, organization role, delete protection, and/or metadata.
+    pub async fn update_identity(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        identity_id: &str,
+        options: &UpdateIdentityOptions,
+    ) -> Result<MachineIdentity, IdentityError> {
+        let endpoint_url = construct_identity_endpoint_url(host, Some(identity_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(identity_error(
+                "update_identity",
+                identity_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<IdentityResponse>(&bytes)?.identity)
+    }
+
+    /// delete_identity()
+    ///
+    /// Deletes a machine identity, along with its auth methods and project memberships. Identities with delete
+    /// protection have to be unprotected with update_identity() first.
+    pub async fn delete_identity(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        identity_id: &str,
+    ) -> Result<MachineIdentity, IdentityError> {
+        let endpoint_url = construct_identity_endpoint_url(host, Some(identity_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(identity_error(
+                "delete_identity",
+                identity_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<IdentityResponse>(&bytes)?.identity)
+    }
+}
+
+fn identity_error(operation: &str, identity_id: &str, error: ApiResponse) -> IdentityError {
+    IdentityError::IdentityRequestError {
+        operation: operation.to_string(),
+        identity_id: identity_id.to_string(),
+        error,
+    }
+}
@@ -0,0 +1,33 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum IdentityError {
+    #[error(
+        "Identities::{operation}(): 
+        Organization ID: {organization_id}
+        Err: {error}"
+    )]
+    OrganizationRequestError {
+        operation: String,
+        organization_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Identities::{operation}(): 
+        Identity ID: {identity_id}
+        Err: {error}"
+    )]
+    IdentityRequestError {
+        operation: String,
+        identity_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
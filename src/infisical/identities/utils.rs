@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+/// A key/value pair attached to a machine identity, e.g. `team=payments`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IdentityMetadata {
+    pub key: String,
+    pub value: String,
+}
+
+/// A machine identity, on its own.
+///
+/// Fields:
+/// - auth_methods: the auth methods attached to the identity, e.g. "universal-auth"
+/// - has_delete_protection: whether the identity has to be unprotected before it can be deleted
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct MachineIdentity {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub auth_methods: Vec<String>,
+    #[serde(default)]
+    pub has_delete_protection: bool,
+}
+
+impl MachineIdentity {
+    pub fn has_auth_method(&self, auth_method: &str) -> bool {
+        self.auth_methods.iter().any(|method| method == auth_method)
+    }
+}
+
+/// A machine identity as a member of its organization: the identity, plus its organization role and metadata.
+///
+/// Fields:
+/// - id: the membership's id, identity.id is the identity's
+/// - role: the organization role's slug, "custom" for custom roles (see custom_role_id)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct OrganizationIdentity {
+    pub id: String,
+    pub role: String,
+    #[serde(default)]
+    pub custom_role_id: Option<String>,
+    pub org_id: String,
+    pub identity: MachineIdentity,
+    #[serde(default)]
+    pub metadata: Vec<IdentityMetadata>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl OrganizationIdentity {
+    /// The values of every metadata entry with the given key.
+    pub fn metadata_values(&self, key: &str) -> Vec<&str> {
+        self.metadata
+            .iter()
+            .filter(|metadata| metadata.key == key)
+            .map(|metadata| metadata.value.as_str())
+            .collect()
+    }
+}
+
+/// Input for create_identity().
+///
+/// - role: the organization role's slug, e.g. "member", "no-access", or a custom role's slug
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIdentityOptions {
+    pub name: String,
+    pub organization_id: String,
+    pub role: String,
+    pub has_delete_protection: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub metadata: Vec<IdentityMetadata>,
+}
+
+/// Input for update_identity(). Any field left as None is left untouched.
+///
+/// Note: metadata replaces the identity's metadata outright.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateIdentityOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_delete_protection: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Vec<IdentityMetadata>>,
+}
+
+/// Options for list_identities().
+///
+/// - search: only return identities whose name contains this
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListIdentitiesOptions {
+    pub offset: u64,
+    pub limit: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+}
+
+impl Default for ListIdentitiesOptions {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            limit: 100,
+            search: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct IdentityResponse {
+    pub identity: MachineIdentity,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OrganizationIdentityResponse {
+    pub identity: OrganizationIdentity,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OrganizationIdentitiesResponse {
+    pub identity_memberships: Vec<OrganizationIdentity>,
+}
+
+pub mod identity_util_functions {
+    pub fn construct_identity_endpoint_url(host: &str, identity_id: Option<&str>) -> String {
+        let is_identity_id =
+            identity_id.map_or_else(|| "".to_string(), |identity_id| format!("/{identity_id}"));
+
+        format!(
+            "{host_url}/api/{version}/identities{identity_id}",
+            host_url = host,
+            version = "v1",
+            identity_id = is_identity_id
+        )
+    }
+
+    pub fn construct_organization_identities_endpoint_url(
+        host: &str,
+        organization_id: &str,
+    ) -> String {
+        format!(
+            "{host_url}/api/{version}/organizations/{organization_id}/identity-memberships",
+            host_url = host,
+            version = "v2",
+        )
+    }
+}
@@ -0,0 +1,493 @@
+/// KMS:
+///
+/// Infisical's key management service: management of a project's KMS keys, and the data operations done with them
+/// (encrypt, decrypt, sign, verify). Key material never leaves Infisical, making the keys a good fit for envelope
+/// encryption.
+use std::collections::HashMap;
+
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use reqwest::StatusCode;
+use secrecy::{ExposeSecret, SecretBox, zeroize::Zeroizing};
+
+use error_handling::KmsError;
+use utils::{kms_util_functions::construct_kms_key_endpoint_url, *};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the KMS module, such as KmsKey and KmsSignature.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// KMS key management for a given project
+impl UniversalAuthAccessToken {
+    /// list_kms_keys()
+    ///
+    /// Lists the KMS keys in a given project, optionally searching by name.
+    pub async fn list_kms_keys(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        options: &ListKmsKeysOptions,
+    ) -> Result<Vec<KmsKey>, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .query(&[("projectId", project_id)])
+            .query(options)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(KmsError::ListKmsKeysError {
+                project_id: project_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsKeysResponse>(&bytes)?.keys)
+    }
+
+    /// get_kms_key()
+    ///
+    /// Retrieves a single KMS key by its id.
+    pub async fn get_kms_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        key_id: &str,
+    ) -> Result<KmsKey, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, Some(key_id));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error("get_kms_key", key_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsKeyResponse>(&bytes)?.key)
+    }
+
+    /// get_kms_key_by_name()
+    ///
+    /// Retrieves a single KMS key by its name within a given project.
+    pub async fn get_kms_key_by_name(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        name: &str,
+    ) -> Result<KmsKey, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, Some(&format!("key-name/{name}")));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(&[("projectId", project_id)])
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error("get_kms_key_by_name", name, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsKeyResponse>(&bytes)?.key)
+    }
+
+    /// create_kms_key()
+    ///
+    /// Creates a new KMS key in a given project.
+    pub async fn create_kms_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateKmsKeyOptions,
+    ) -> Result<KmsKey, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(KmsError::CreateKmsKeyError {
+                project_id: options.project_id.clone(),
+                name: options.name.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsKeyResponse>(&bytes)?.key)
+    }
+
+    /// update_kms_key()
+    ///
+    /// Renames, re-describes, or disables/enables a KMS key. Any field left as None in `options` is left untouched.
+    pub async fn update_kms_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        key_id: &str,
+        options: &UpdateKmsKeyOptions,
+    ) -> Result<KmsKey, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, Some(key_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error("update_kms_key", key_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsKeyResponse>(&bytes)?.key)
+    }
+
+    /// delete_kms_key()
+    ///
+    /// Deletes a KMS key. Anything encrypted with it can no longer be decrypted.
+    pub async fn delete_kms_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        key_id: &str,
+    ) -> Result<KmsKey, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, Some(key_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error("delete_kms_key", key_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsKeyResponse>(&bytes)?.key)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// KMS data operations
+impl UniversalAuthAccessToken {
+    /// encrypt_with_kms_key()
+    ///
+    /// Encrypts `plaintext` with an encrypt-decrypt key, returning Infisical's ciphertext blob as is. The blob embeds
+    /// what Infisical needs to decrypt it again, so store it alongside the data it protects.
+    pub async fn encrypt_with_kms_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        key_id: &str,
+        plaintext: &SecretBox<Vec<u8>>,
+    ) -> Result<String, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, Some(&format!("{key_id}/encrypt")));
+
+        // the encoded copy is as sensitive as the plaintext itself, so it gets wiped when dropped
+        let encoded_plaintext = Zeroizing::new(BASE64.encode(plaintext.expose_secret()));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("plaintext", encoded_plaintext.as_str());
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&form_data)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error(
+                "encrypt_with_kms_key",
+                key_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsEncryptResponse>(&bytes)?.ciphertext)
+    }
+
+    /// decrypt_with_kms_key()
+    ///
+    /// Decrypts a ciphertext blob returned by encrypt_with_kms_key() with the same key.
+    pub async fn decrypt_with_kms_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        key_id: &str,
+        ciphertext: &str,
+    ) -> Result<SecretBox<Vec<u8>>, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, Some(&format!("{key_id}/decrypt")));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("ciphertext", ciphertext);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&form_data)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error(
+                "decrypt_with_kms_key",
+                key_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        let encoded_plaintext = SecretBox::new(Box::new(
+            serde_json::from_slice::<KmsDecryptResponse>(&bytes)?.plaintext,
+        ));
+
+        BASE64
+            .decode(encoded_plaintext.expose_secret())
+            .map(|plaintext| SecretBox::new(Box::new(plaintext)))
+            .map_err(|error| KmsError::InvalidBase64 {
+                operation: "decrypt_with_kms_key".to_string(),
+                error,
+            })
+    }
+
+    /// sign_with_kms_key()
+    ///
+    /// Signs `data` (or its digest, see KmsSigningOptions) with a sign-verify key.
+    pub async fn sign_with_kms_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        key_id: &str,
+        data: &[u8],
+        options: &KmsSigningOptions,
+    ) -> Result<KmsSignature, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, Some(&format!("{key_id}/sign")));
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&KmsSignBody {
+                data: BASE64.encode(data),
+                signing_algorithm: options.signing_algorithm,
+                is_digest: options.is_digest,
+                signature: None,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error("sign_with_kms_key", key_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsSignature>(&bytes)?)
+    }
+
+    /// verify_with_kms_key()
+    ///
+    /// Checks a (base64 encoded) signature of `data` against a sign-verify key. An invalid signature is Ok(false),
+    /// not an error.
+    pub async fn verify_with_kms_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        key_id: &str,
+        data: &[u8],
+        signature: &str,
+        options: &KmsSigningOptions,
+    ) -> Result<bool, KmsError> {
+        let endpoint_url = construct_kms_key_endpoint_url(host, Some(&format!("{key_id}/verify")));
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&KmsSignBody {
+                data: BASE64.encode(data),
+                signing_algorithm: options.signing_algorithm,
+                is_digest: options.is_digest,
+                signature: Some(signature),
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error("verify_with_kms_key", key_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsVerifyResponse>(&bytes)?.signature_valid)
+    }
+
+    /// get_kms_public_key()
+    ///
+    /// Retrieves a sign-verify key's public key, for verifying signatures without calling Infisical.
+    pub async fn get_kms_public_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        key_id: &str,
+    ) -> Result<String, KmsError> {
+        let endpoint_url =
+            construct_kms_key_endpoint_url(host, Some(&format!("{key_id}/public-key")));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error("get_kms_public_key", key_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsPublicKeyResponse>(&bytes)?.public_key)
+    }
+
+    /// list_kms_signing_algorithms()
+    ///
+    /// Lists the signing algorithms a sign-verify key supports.
+    pub async fn list_kms_signing_algorithms(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        key_id: &str,
+    ) -> Result<Vec<KmsSigningAlgorithm>, KmsError> {
+        let endpoint_url =
+            construct_kms_key_endpoint_url(host, Some(&format!("{key_id}/signing-algorithms")));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(kms_key_error(
+                "list_kms_signing_algorithms",
+                key_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<KmsSigningAlgorithmsResponse>(&bytes)?.signing_algorithms)
+    }
+}
+
+fn kms_key_error(operation: &str, key: &str, error: ApiResponse) -> KmsError {
+    KmsError::KmsKeyError {
+        operation: operation.to_string(),
+        key: key.to_string(),
+        error,
+    }
+}
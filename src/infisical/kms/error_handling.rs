@@ -0,0 +1,50 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum KmsError {
+    #[error(
+        "Kms::list_kms_keys(): 
+        Project ID: {project_id}
+        Err: {error}"
+    )]
+    ListKmsKeysError {
+        project_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Kms::create_kms_key(): 
+        Project ID: {project_id}
+        Key name: {name}
+        Err: {error}"
+    )]
+    CreateKmsKeyError {
+        project_id: String,
+        name: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Kms::{operation}(): 
+        Key: {key}
+        Err: {error}"
+    )]
+    KmsKeyError {
+        operation: String,
+        key: String,
+        error: ApiResponse,
+    },
+
+    #[error("Kms::{operation}(): Infisical returned invalid base64 data: {error}")]
+    InvalidBase64 {
+        operation: String,
+        error: base64::DecodeError,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
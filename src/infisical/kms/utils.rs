@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+
+/// What a KMS key can be used for. Encryption keys are symmetric, signing keys asymmetric.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum KmsKeyUsage {
+    #[default]
+    EncryptDecrypt,
+    SignVerify,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+pub enum KmsKeyAlgorithm {
+    #[default]
+    #[serde(rename = "aes-256-gcm")]
+    #[strum(to_string = "aes-256-gcm")]
+    Aes256Gcm,
+    #[serde(rename = "aes-128-gcm")]
+    #[strum(to_string = "aes-128-gcm")]
+    Aes128Gcm,
+    #[serde(rename = "RSA_4096")]
+    #[strum(to_string = "RSA_4096")]
+    Rsa4096,
+    #[serde(rename = "ECC_NIST_P256")]
+    #[strum(to_string = "ECC_NIST_P256")]
+    EccNistP256,
+}
+
+/// The signing algorithms available to signing keys; RSA keys use the RSASSA ones, ECC keys the ECDSA ones.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+pub enum KmsSigningAlgorithm {
+    #[serde(rename = "RSASSA_PSS_SHA_256")]
+    #[strum(to_string = "RSASSA_PSS_SHA_256")]
+    RsassaPssSha256,
+    #[serde(rename = "RSASSA_PSS_SHA_384")]
+    #[strum(to_string = "RSASSA_PSS_SHA_384")]
+    RsassaPssSha384,
+    #[serde(rename = "RSASSA_PSS_SHA_512")]
+    #[strum(to_string = "RSASSA_PSS_SHA_512")]
+    RsassaPssSha512,
+    #[serde(rename = "RSASSA_PKCS1_V1_5_SHA_256")]
+    #[strum(to_string = "RSASSA_PKCS1_V1_5_SHA_256")]
+    RsassaPkcs1V15Sha256,
+    #[serde(rename = "RSASSA_PKCS1_V1_5_SHA_384")]
+    #[strum(to_string = "RSASSA_PKCS1_V1_5_SHA_384")]
+    RsassaPkcs1V15Sha384,
+    #[serde(rename = "RSASSA_PKCS1_V1_5_SHA_512")]
+    #[strum(to_string = "RSASSA_PKCS1_V1_5_SHA_512")]
+    RsassaPkcs1V15Sha512,
+    #[serde(rename = "ECDSA_SHA_256")]
+    #[strum(to_string = "ECDSA_SHA_256")]
+    EcdsaSha256,
+    #[serde(rename = "ECDSA_SHA_384")]
+    #[strum(to_string = "ECDSA_SHA_384")]
+    EcdsaSha384,
+    #[serde(rename = "ECDSA_SHA_512")]
+    #[strum(to_string = "ECDSA_SHA_512")]
+    EcdsaSha512,
+}
+
+/// A KMS key. The key material itself never leaves Infisical.
+///
+/// Fields:
+/// - id: the key's id, used for every data operation
+/// - is_disabled: disabled keys can't be used for data operations until re-enabled
+/// - key_usage/encryption_algorithm: what the key is for, and its algorithm; neither can be changed after creation
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct KmsKey {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub is_disabled: bool,
+    #[serde(default)]
+    pub project_id: String,
+    #[serde(default)]
+    pub key_usage: KmsKeyUsage,
+    pub encryption_algorithm: KmsKeyAlgorithm,
+    #[serde(default)]
+    pub version: u64,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// Request body for create_kms_key().
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateKmsKeyOptions {
+    pub project_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub key_usage: KmsKeyUsage,
+    pub encryption_algorithm: KmsKeyAlgorithm,
+}
+
+/// Request body for update_kms_key(). Any field left as None is left untouched by Infisical.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct UpdateKmsKeyOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_disabled: Option<bool>,
+}
+
+/// Query options for list_kms_keys().
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct ListKmsKeysOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+}
+
+/// Input for sign_with_kms_key() and verify_with_kms_key().
+///
+/// - is_digest: whether the data is already a digest of the message (matching the signing algorithm's hash),
+///   rather than the message itself
+#[derive(Clone, Copy, Debug)]
+pub struct KmsSigningOptions {
+    pub signing_algorithm: KmsSigningAlgorithm,
+    pub is_digest: bool,
+}
+
+/// A signature returned by sign_with_kms_key().
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct KmsSignature {
+    /// base64 encoded, as verify_with_kms_key() expects it
+    pub signature: String,
+    pub key_id: String,
+    pub signing_algorithm: KmsSigningAlgorithm,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct KmsSignBody<'a> {
+    pub data: String,
+    pub signing_algorithm: KmsSigningAlgorithm,
+    pub is_digest: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmsKeyResponse {
+    pub key: KmsKey,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmsKeysResponse {
+    pub keys: Vec<KmsKey>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmsEncryptResponse {
+    pub ciphertext: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmsDecryptResponse {
+    pub plaintext: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct KmsVerifyResponse {
+    pub signature_valid: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct KmsPublicKeyResponse {
+    pub public_key: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct KmsSigningAlgorithmsResponse {
+    pub signing_algorithms: Vec<KmsSigningAlgorithm>,
+}
+
+pub mod kms_util_functions {
+    pub fn construct_kms_key_endpoint_url(host: &str, path: Option<&str>) -> String {
+        let is_path = path.map_or_else(|| "".to_string(), |path| format!("/{path}"));
+
+        format!(
+            "{host_url}/api/{version}/kms/keys{path}",
+            host_url = host,
+            version = "v1",
+            path = is_path
+        )
+    }
+}
@@ -0,0 +1,498 @@
+/// Organizations:
+///
+/// An organization's users and roles: inviting users, listing, updating and removing their memberships, and
+/// managing custom organization roles.
+use reqwest::StatusCode;
+
+use error_handling::OrganizationError;
+use utils::{
+    organization_util_functions::{
+        construct_organization_invite_endpoint_url,
+        construct_organization_memberships_endpoint_url, construct_organization_roles_endpoint_url,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the organizations module, such as OrganizationMembership and
+/// OrganizationRole.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// User memberships
+impl UniversalAuthAccessToken {
+    /// list_organization_memberships()
+    ///
+    /// Lists an organization's users, invited ones included, with their organization roles.
+    pub async fn list_organization_memberships(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+    ) -> Result<Vec<OrganizationMembership>, OrganizationError> {
+        let endpoint_url =
+            construct_organization_memberships_endpoint_url(host, organization_id, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(organization_error(
+                "list_organization_memberships",
+                organization_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationMembershipsResponse>(&bytes)?.users)
+    }
+
+    /// get_organization_membership()
+    ///
+    /// Retrieves a user's membership in an organization by the membership's id.
+    pub async fn get_organization_membership(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+        membership_id: &str,
+    ) -> Result<OrganizationMembership, OrganizationError> {
+        let endpoint_url = construct_organization_memberships_endpoint_url(
+            host,
+            organization_id,
+            Some(membership_id),
+        );
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(membership_error(
+                "get_organization_membership",
+                organization_id,
+                membership_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationMembershipResponse>(&bytes)?.membership)
+    }
+
+    /// invite_users_to_organization()
+    ///
+    /// Invites users to an organization by email, optionally adding them to projects once they join. Returns the
+    /// signup links Infisical couldn't email, which is none of them when email delivery is set up.
+    ///
+    /// # Example
+    /// ```ignore
+    /// access_token
+    ///     .invite_users_to_organization(
+    ///         host,
+    ///         &client,
+    ///         organization_id,
+    ///         &InviteUsersOptions {
+    ///             emails: vec!["jane@example.com".to_string()],
+    ///             role: "member".to_string(),
+    ///             projects: vec![ProjectInvite {
+    ///                 id: project_id.to_string(),
+    ///                 role_slugs: vec!["viewer".to_string()],
+    ///             }],
+    ///         },
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn invite_users_to_organization(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+        options: &InviteUsersOptions,
+    ) -> Result<Vec<OrganizationInviteLink>, OrganizationError> {
+        let endpoint_url = construct_organization_invite_endpoint_url(host);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&InviteUsersBody {
+                invitee_emails: &options.emails,
+                organization_id,
+                organization_role_slug: &options.role,
+                projects: &options.projects,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(OrganizationError::InviteUsersError {
+                organization_id: organization_id.to_string(),
+                emails: options.emails.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<InviteUsersResponse>(&bytes)?
+            .complete_invite_links
+            .unwrap_or_default())
+    }
+
+    /// update_organization_membership()
+    ///
+    /// Changes a user's organization role, or deactivates/reactivates them.
+    pub async fn update_organization_membership(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+        membership_id: &str,
+        options: &UpdateOrganizationMembershipOptions,
+    ) -> Result<OrganizationMembership, OrganizationError> {
+        let endpoint_url = construct_organization_memberships_endpoint_url(
+            host,
+            organization_id,
+            Some(membership_id),
+        );
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(membership_error(
+                "update_organization_membership",
+                organization_id,
+                membership_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationMembershipResponse>(&bytes)?.membership)
+    }
+
+    /// remove_user_from_organization()
+    ///
+    /// Removes a user from an organization, and from all of its projects, or revokes their pending invite.
+    pub async fn remove_user_from_organization(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+        membership_id: &str,
+    ) -> Result<OrganizationMembership, OrganizationError> {
+        let endpoint_url = construct_organization_memberships_endpoint_url(
+            host,
+            organization_id,
+            Some(membership_id),
+        );
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(membership_error(
+                "remove_user_from_organization",
+                organization_id,
+                membership_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationMembershipResponse>(&bytes)?.membership)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Custom organization role management
+impl UniversalAuthAccessToken {
+    /// list_organization_roles()
+    ///
+    /// Lists an organization's roles, built-in roles included.
+    pub async fn list_organization_roles(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+    ) -> Result<Vec<OrganizationRole>, OrganizationError> {
+        let endpoint_url = construct_organization_roles_endpoint_url(host, organization_id, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(organization_error(
+                "list_organization_roles",
+                organization_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationRolesResponse>(&bytes)?
+            .data
+            .roles)
+    }
+
+    /// get_organization_role()
+    ///
+    /// Retrieves a custom organization role by its id.
+    pub async fn get_organization_role(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+        role_id: &str,
+    ) -> Result<OrganizationRole, OrganizationError> {
+        let endpoint_url =
+            construct_organization_roles_endpoint_url(host, organization_id, Some(role_id));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(role_error(
+                "get_organization_role",
+                organization_id,
+                role_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationRoleResponse>(&bytes)?.role)
+    }
+
+    /// create_organization_role()
+    ///
+    /// Creates a custom organization role from permission rules.
+    pub async fn create_organization_role(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+        options: &CreateOrganizationRoleOptions,
+    ) -> Result<OrganizationRole, OrganizationError> {
+        let endpoint_url = construct_organization_roles_endpoint_url(host, organization_id, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(role_error(
+                "create_organization_role",
+                organization_id,
+                &options.slug,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationRoleResponse>(&bytes)?.role)
+    }
+
+    /// update_organization_role()
+    ///
+    /// Updates a custom organization role by its id.
+    pub async fn update_organization_role(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+        role_id: &str,
+        options: &UpdateOrganizationRoleOptions,
+    ) -> Result<OrganizationRole, OrganizationError> {
+        let endpoint_url =
+            construct_organization_roles_endpoint_url(host, organization_id, Some(role_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(role_error(
+                "update_organization_role",
+                organization_id,
+                role_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationRoleResponse>(&bytes)?.role)
+    }
+
+    /// delete_organization_role()
+    ///
+    /// Deletes a custom organization role by its id. Members holding it have to be given another role first.
+    pub async fn delete_organization_role(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        organization_id: &str,
+        role_id: &str,
+    ) -> Result<OrganizationRole, OrganizationError> {
+        let endpoint_url =
+            construct_organization_roles_endpoint_url(host, organization_id, Some(role_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(role_error(
+                "delete_organization_role",
+                organization_id,
+                role_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<OrganizationRoleResponse>(&bytes)?.role)
+    }
+}
+
+fn organization_error(
+    operation: &str,
+    organization_id: &str,
+    error: ApiResponse,
+) -> OrganizationError {
+    OrganizationError::OrganizationRequestError {
+        operation: operation.to_string(),
+        organization_id: organization_id.to_string(),
+        error,
+    }
+}
+
+fn membership_error(
+    operation: &str,
+    organization_id: &str,
+    membership_id: &str,
+    error: ApiResponse,
+) -> OrganizationError {
+    OrganizationError::MembershipRequestError {
+        operation: operation.to_string(),
+        organization_id: organization_id.to_string(),
+        membership_id: membership_id.to_string(),
+        error,
+    }
+}
+
+fn role_error(
+    operation: &str,
+    organization_id: &str,
+    role: &str,
+    error: ApiResponse,
+) -> OrganizationError {
+    OrganizationError::RoleRequestError {
+        operation: operation.to_string(),
+        organization_id: organization_id.to_string(),
+        role: role.to_string(),
+        error,
+    }
+}
@@ -0,0 +1,60 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum OrganizationError {
+    #[error(
+        "Organizations::{operation}(): 
+        Organization ID: {organization_id}
+        Err: {error}"
+    )]
+    OrganizationRequestError {
+        operation: String,
+        organization_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Organizations::{operation}(): 
+        Organization ID: {organization_id}
+        Membership ID: {membership_id}
+        Err: {error}"
+    )]
+    MembershipRequestError {
+        operation: String,
+        organization_id: String,
+        membership_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Organizations::invite_users_to_organization(): 
+        Organization ID: {organization_id}
+        Emails: {emails:?}
+        Err: {error}"
+    )]
+    InviteUsersError {
+        organization_id: String,
+        emails: Vec<String>,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Organizations::{operation}(): 
+        Organization ID: {organization_id}
+        Role: {role}
+        Err: {error}"
+    )]
+    RoleRequestError {
+        operation: String,
+        organization_id: String,
+        role: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
@@ -0,0 +1,244 @@
+use serde::{Deserialize, Serialize};
+
+use crate::infisical::project_roles::permissions::PermissionRule;
+
+/// Where a user stands in joining an organization. Statuses this crate doesn't model yet deserialize as Unknown.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OrganizationMembershipStatus {
+    /// invited, but hasn't accepted the invite yet
+    Invited,
+    Accepted,
+    Verified,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct OrganizationUser {
+    pub id: String,
+    pub username: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+}
+
+/// A user's membership in an organization.
+///
+/// Fields:
+/// - id: the membership's id, used by update_organization_membership() and remove_user_from_organization()
+/// - role: the organization role's slug, "custom" for custom roles (see role_id)
+/// - is_active: whether the user can access the organization, deactivated users keep their membership
+/// - invite_email: the email the user was invited with, if they were invited
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct OrganizationMembership {
+    pub id: String,
+    pub org_id: String,
+    pub role: String,
+    #[serde(default)]
+    pub role_id: Option<String>,
+    pub status: OrganizationMembershipStatus,
+    #[serde(default = "default_is_active")]
+    pub is_active: bool,
+    #[serde(default)]
+    pub invite_email: Option<String>,
+    pub user: OrganizationUser,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+fn default_is_active() -> bool {
+    true
+}
+
+impl OrganizationMembership {
+    /// Whether the user has accepted their invite, i.e. is a member in full.
+    pub fn has_joined(&self) -> bool {
+        matches!(
+            self.status,
+            OrganizationMembershipStatus::Accepted | OrganizationMembershipStatus::Verified
+        )
+    }
+}
+
+/// Input for update_organization_membership(). Any field left as None is left untouched.
+///
+/// - role: the organization role's slug, e.g. "admin", "member", "no-access", or a custom role's slug
+/// - is_active: false deactivates the user without removing them
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOrganizationMembershipOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
+
+/// A project to add invited users to, with the project roles' slugs to give them.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProjectInvite {
+    pub id: String,
+    #[serde(rename = "projectRoleSlug")]
+    pub role_slugs: Vec<String>,
+}
+
+/// Input for invite_users_to_organization().
+///
+/// - emails: who to invite
+/// - role: the organization role's slug to give them, e.g. "member"
+/// - projects: projects to add them to once they join
+#[derive(Clone, Debug)]
+pub struct InviteUsersOptions {
+    pub emails: Vec<String>,
+    pub role: String,
+    pub projects: Vec<ProjectInvite>,
+}
+
+/// The link an invited user completes their signup with. Infisical only hands these out when it can't email them,
+/// e.g. when SMTP isn't configured.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OrganizationInviteLink {
+    pub email: String,
+    pub link: String,
+}
+
+/// An organization role, built-in or custom.
+///
+/// Organization roles use the same rules as project roles, over organization subjects such as "member", "groups"
+/// or "identity", most of which are kept as PermissionSubject::Other.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct OrganizationRole {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub permissions: Vec<PermissionRule>,
+    #[serde(default)]
+    pub org_id: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// Input for create_organization_role().
+///
+/// - slug: how the role is referred to when assigning it, e.g. in UpdateOrganizationMembershipOptions
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrganizationRoleOptions {
+    pub slug: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub permissions: Vec<PermissionRule>,
+}
+
+/// Input for update_organization_role(). Any field left as None is left untouched.
+///
+/// Note: permissions replaces the role's rules outright.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOrganizationRoleOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<PermissionRule>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct InviteUsersBody<'a> {
+    pub invitee_emails: &'a [String],
+    pub organization_id: &'a str,
+    pub organization_role_slug: &'a str,
+    #[serde(skip_serializing_if = "<[ProjectInvite]>::is_empty")]
+    pub projects: &'a [ProjectInvite],
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct InviteUsersResponse {
+    #[serde(default)]
+    pub complete_invite_links: Option<Vec<OrganizationInviteLink>>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OrganizationMembershipResponse {
+    pub membership: OrganizationMembership,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OrganizationMembershipsResponse {
+    pub users: Vec<OrganizationMembership>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OrganizationRoleResponse {
+    pub role: OrganizationRole,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OrganizationRolesResponse {
+    pub data: OrganizationRolesData,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OrganizationRolesData {
+    pub roles: Vec<OrganizationRole>,
+}
+
+pub mod organization_util_functions {
+    pub fn construct_organization_memberships_endpoint_url(
+        host: &str,
+        organization_id: &str,
+        membership_id: Option<&str>,
+    ) -> String {
+        let is_membership_id = membership_id.map_or_else(
+            || "".to_string(),
+            |membership_id| format!("/{membership_id}"),
+        );
+
+        format!(
+            "{host_url}/api/{version}/organizations/{organization_id}/memberships{membership_id}",
+            host_url = host,
+            version = "v2",
+            membership_id = is_membership_id
+        )
+    }
+
+    pub fn construct_organization_roles_endpoint_url(
+        host: &str,
+        organization_id: &str,
+        role_id: Option<&str>,
+    ) -> String {
+        let is_role_id = role_id.map_or_else(|| "".to_string(), |role_id| format!("/{role_id}"));
+
+        format!(
+            "{host_url}/api/{version}/organization/{organization_id}/roles{role_id}",
+            host_url = host,
+            version = "v1",
+            role_id = is_role_id
+        )
+    }
+
+    pub fn construct_organization_invite_endpoint_url(host: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/invite-org/signup",
+            host_url = host,
+            version = "v1",
+        )
+    }
+}
@@ -0,0 +1,533 @@
+/// PKI:
+///
+/// Infisical's private certificate authorities: creating root and intermediate CAs, importing CA certificates,
+/// issuing certificates (directly or through a certificate template), signing CSRs, and revocation.
+use std::collections::HashMap;
+
+use reqwest::StatusCode;
+
+use error_handling::PkiError;
+use utils::{
+    pki_util_functions::{construct_pki_endpoint_url, construct_project_cas_endpoint_url},
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the PKI module, such as CertificateAuthority and IssuedCertificate.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Certificate authority management
+impl UniversalAuthAccessToken {
+    /// list_certificate_authorities()
+    ///
+    /// Lists the CAs in a given project, optionally filtered by status and name.
+    pub async fn list_certificate_authorities(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        options: &ListCertificateAuthoritiesOptions,
+    ) -> Result<Vec<CertificateAuthority>, PkiError> {
+        let endpoint_url = construct_project_cas_endpoint_url(host, project_id);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(PkiError::ProjectRequestError {
+                operation: "list_certificate_authorities".to_string(),
+                project: project_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<CertificateAuthoritiesResponse>(&bytes)?.cas)
+    }
+
+    /// get_certificate_authority()
+    ///
+    /// Retrieves a single CA by its id.
+    pub async fn get_certificate_authority(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ca_id: &str,
+    ) -> Result<CertificateAuthority, PkiError> {
+        let endpoint_url = construct_pki_endpoint_url(host, &format!("ca/{ca_id}"));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ca_error("get_certificate_authority", ca_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<CertificateAuthorityResponse>(&bytes)?.ca)
+    }
+
+    /// create_certificate_authority()
+    ///
+    /// Creates a root or intermediate CA. Root CAs are active right away; intermediate CAs stay pending until
+    /// their certificate is imported (import_certificate_authority_certificate()) or signed by a parent CA
+    /// (sign_intermediate_certificate_authority()).
+    pub async fn create_certificate_authority(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateCertificateAuthorityOptions,
+    ) -> Result<CertificateAuthority, PkiError> {
+        let endpoint_url = construct_pki_endpoint_url(host, "ca");
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(PkiError::ProjectRequestError {
+                operation: "create_certificate_authority".to_string(),
+                project: options.project_slug.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<CertificateAuthorityResponse>(&bytes)?.ca)
+    }
+
+    /// set_certificate_authority_status()
+    ///
+    /// Enables (CertificateAuthorityStatus::Active) or disables (CertificateAuthorityStatus::Disabled) a CA.
+    pub async fn set_certificate_authority_status(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ca_id: &str,
+        status: CertificateAuthorityStatus,
+    ) -> Result<CertificateAuthority, PkiError> {
+        let endpoint_url = construct_pki_endpoint_url(host, &format!("ca/{ca_id}"));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("status", status);
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&form_data)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ca_error(
+                "set_certificate_authority_status",
+                ca_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<CertificateAuthorityResponse>(&bytes)?.ca)
+    }
+
+    /// delete_certificate_authority()
+    ///
+    /// Deletes a CA, along with every certificate it issued.
+    pub async fn delete_certificate_authority(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ca_id: &str,
+    ) -> Result<CertificateAuthority, PkiError> {
+        let endpoint_url = construct_pki_endpoint_url(host, &format!("ca/{ca_id}"));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ca_error(
+                "delete_certificate_authority",
+                ca_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<CertificateAuthorityResponse>(&bytes)?.ca)
+    }
+
+    /// get_certificate_authority_certificate()
+    ///
+    /// Retrieves a CA's own (active) certificate and chain.
+    pub async fn get_certificate_authority_certificate(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ca_id: &str,
+    ) -> Result<CaCertificate, PkiError> {
+        let endpoint_url = construct_pki_endpoint_url(host, &format!("ca/{ca_id}/certificate"));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ca_error(
+                "get_certificate_authority_certificate",
+                ca_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<CaCertificate>(&bytes)?)
+    }
+
+    /// get_certificate_authority_csr()
+    ///
+    /// Retrieves a pending intermediate CA's CSR, to be signed by its parent CA (in Infisical or elsewhere).
+    pub async fn get_certificate_authority_csr(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ca_id: &str,
+    ) -> Result<String, PkiError> {
+        let endpoint_url = construct_pki_endpoint_url(host, &format!("ca/{ca_id}/csr"));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ca_error(
+                "get_certificate_authority_csr",
+                ca_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<CertificateAuthorityCsrResponse>(&bytes)?.csr)
+    }
+
+    /// import_certificate_authority_certificate()
+    ///
+    /// Imports a pending intermediate CA's certificate (PEM), signed by an external parent CA from its CSR,
+    /// activating the CA.
+    pub async fn import_certificate_authority_certificate(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ca_id: &str,
+        certificate: &str,
+        certificate_chain: &str,
+    ) -> Result<(), PkiError> {
+        let endpoint_url =
+            construct_pki_endpoint_url(host, &format!("ca/{ca_id}/import-certificate"));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("certificate", certificate);
+        form_data.insert("certificateChain", certificate_chain);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&form_data)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ca_error(
+                "import_certificate_authority_certificate",
+                ca_id,
+                error_response,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// sign_intermediate_certificate_authority()
+    ///
+    /// Signs an intermediate CA's CSR with the CA `ca_id`. Import the result into the intermediate CA with
+    /// import_certificate_authority_certificate() if it lives in Infisical too.
+    pub async fn sign_intermediate_certificate_authority(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ca_id: &str,
+        options: &SignIntermediateOptions,
+    ) -> Result<SignedCertificate, PkiError> {
+        let endpoint_url =
+            construct_pki_endpoint_url(host, &format!("ca/{ca_id}/sign-intermediate"));
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ca_error(
+                "sign_intermediate_certificate_authority",
+                ca_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SignedCertificate>(&bytes)?)
+    }
+
+    /// list_certificate_revocation_lists()
+    ///
+    /// Retrieves a CA's certificate revocation lists.
+    pub async fn list_certificate_revocation_lists(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ca_id: &str,
+    ) -> Result<Vec<CertificateRevocationList>, PkiError> {
+        let endpoint_url = construct_pki_endpoint_url(host, &format!("ca/{ca_id}/crls"));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ca_error(
+                "list_certificate_revocation_lists",
+                ca_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<Vec<CertificateRevocationList>>(
+            &bytes,
+        )?)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Certificate issuance and revocation
+impl UniversalAuthAccessToken {
+    /// issue_certificate()
+    ///
+    /// Issues a certificate, with Infisical generating its key pair. The private key is only ever returned here.
+    pub async fn issue_certificate(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &IssueCertificateOptions,
+    ) -> Result<IssuedCertificate, PkiError> {
+        let endpoint_url = construct_pki_endpoint_url(host, "certificates/issue-certificate");
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(PkiError::CertificateError {
+                operation: "issue_certificate".to_string(),
+                certificate: options.common_name.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<IssuedCertificate>(&bytes)?)
+    }
+
+    /// sign_certificate()
+    ///
+    /// Issues a certificate from a CSR, for when the private key should never leave the requester.
+    pub async fn sign_certificate(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &SignCertificateOptions,
+    ) -> Result<SignedCertificate, PkiError> {
+        let endpoint_url = construct_pki_endpoint_url(host, "certificates/sign-certificate");
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(PkiError::CertificateError {
+                operation: "sign_certificate".to_string(),
+                certificate: options.common_name.clone().unwrap_or_default(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SignedCertificate>(&bytes)?)
+    }
+
+    /// revoke_certificate()
+    ///
+    /// Revokes a certificate by its serial number. It shows up in its CA's next CRL.
+    pub async fn revoke_certificate(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        serial_number: &str,
+        reason: RevocationReason,
+    ) -> Result<RevokedCertificate, PkiError> {
+        let endpoint_url =
+            construct_pki_endpoint_url(host, &format!("certificates/{serial_number}/revoke"));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("revocationReason", reason);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&form_data)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(PkiError::CertificateError {
+                operation: "revoke_certificate".to_string(),
+                certificate: serial_number.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<RevokedCertificate>(&bytes)?)
+    }
+}
+
+fn ca_error(operation: &str, ca_id: &str, error: ApiResponse) -> PkiError {
+    PkiError::CertificateAuthorityError {
+        operation: operation.to_string(),
+        ca_id: ca_id.to_string(),
+        error,
+    }
+}
@@ -0,0 +1,44 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum PkiError {
+    #[error(
+        "Pki::{operation}(): 
+        Project: {project}
+        Err: {error}"
+    )]
+    ProjectRequestError {
+        operation: String,
+        project: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Pki::{operation}(): 
+        CA ID: {ca_id}
+        Err: {error}"
+    )]
+    CertificateAuthorityError {
+        operation: String,
+        ca_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Pki::{operation}(): 
+        Certificate: {certificate}
+        Err: {error}"
+    )]
+    CertificateError {
+        operation: String,
+        certificate: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
@@ -0,0 +1,393 @@
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum CertificateAuthorityType {
+    #[default]
+    Root,
+    Intermediate,
+}
+
+/// An intermediate CA stays PendingCertificate until its certificate is imported or signed by a parent CA.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum CertificateAuthorityStatus {
+    Active,
+    Disabled,
+    PendingCertificate,
+    /// anything newer Infisical versions might report
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+pub enum PkiKeyAlgorithm {
+    #[default]
+    #[serde(rename = "RSA_2048")]
+    #[strum(to_string = "RSA_2048")]
+    Rsa2048,
+    #[serde(rename = "RSA_4096")]
+    #[strum(to_string = "RSA_4096")]
+    Rsa4096,
+    #[serde(rename = "EC_prime256v1")]
+    #[strum(to_string = "EC_prime256v1")]
+    EcPrime256v1,
+    #[serde(rename = "EC_secp384r1")]
+    #[strum(to_string = "EC_secp384r1")]
+    EcSecp384r1,
+}
+
+/// A certificate authority.
+///
+/// Fields:
+/// - type_/status: root or intermediate, and whether it can issue certificates yet
+/// - dn: the CA's full distinguished name, built from common_name, organization, ou, country, province and locality
+/// - max_path_length: how many intermediate CAs may follow this one in a chain, -1 for no limit
+/// - require_template_for_issuance: whether certificates can only be issued through a certificate template
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct CertificateAuthority {
+    pub id: String,
+    #[serde(default)]
+    pub project_id: String,
+    #[serde(rename(serialize = "type_", deserialize = "type"))]
+    pub type_: CertificateAuthorityType,
+    pub status: CertificateAuthorityStatus,
+    #[serde(default)]
+    pub friendly_name: String,
+    #[serde(default)]
+    pub common_name: String,
+    #[serde(default)]
+    pub organization: String,
+    #[serde(default)]
+    pub ou: String,
+    #[serde(default)]
+    pub country: String,
+    #[serde(default)]
+    pub province: String,
+    #[serde(default)]
+    pub locality: String,
+    #[serde(default)]
+    pub dn: String,
+    #[serde(default)]
+    pub serial_number: Option<String>,
+    #[serde(default)]
+    pub max_path_length: Option<i64>,
+    pub key_algorithm: PkiKeyAlgorithm,
+    #[serde(default)]
+    pub not_before: Option<String>,
+    #[serde(default)]
+    pub not_after: Option<String>,
+    #[serde(default)]
+    pub require_template_for_issuance: bool,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// Request body for create_certificate_authority().
+///
+/// not_before/not_after (RFC 3339 timestamps) only apply to root CAs; an intermediate CA's validity comes from the
+/// certificate its parent signs for it.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateCertificateAuthorityOptions {
+    pub project_slug: String,
+    #[serde(rename = "type")]
+    pub type_: CertificateAuthorityType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<String>,
+    pub common_name: String,
+    pub organization: String,
+    pub ou: String,
+    pub country: String,
+    pub province: String,
+    pub locality: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_path_length: Option<i64>,
+    pub key_algorithm: PkiKeyAlgorithm,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_template_for_issuance: Option<bool>,
+}
+
+/// Query options for list_certificate_authorities(). Any field left as None is not filtered on.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct ListCertificateAuthoritiesOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CertificateAuthorityStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
+
+/// A CA's own certificate and chain, PEM encoded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct CaCertificate {
+    pub certificate: String,
+    #[serde(default)]
+    pub certificate_chain: String,
+    pub serial_number: String,
+}
+
+/// Input for sign_intermediate_certificate_authority(). `csr` is the intermediate CA's CSR, see
+/// get_certificate_authority_csr().
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct SignIntermediateOptions {
+    pub csr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<String>,
+    pub not_after: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_path_length: Option<i64>,
+}
+
+/// Which CA a certificate is issued by: a CA directly, or a certificate template (which picks the CA, and
+/// constrains the certificate's names, TTL and key usages).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CertificateIssuer {
+    CertificateAuthority(String),
+    Template(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KeyUsage {
+    DigitalSignature,
+    KeyEncipherment,
+    NonRepudiation,
+    DataEncipherment,
+    KeyAgreement,
+    KeyCertSign,
+    CrlSign,
+    EncipherOnly,
+    DecipherOnly,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExtendedKeyUsage {
+    ClientAuth,
+    ServerAuth,
+    CodeSigning,
+    EmailProtection,
+    Timestamping,
+    OcspSigning,
+}
+
+/// Input for issue_certificate().
+///
+/// - alt_names: subject alternative names, e.g. DNS names or IP addresses
+/// - ttl: the certificate's lifetime in Infisical's duration format (e.g. "90d"), ignored if not_after is set
+/// - key_usages/extended_key_usages: left empty, Infisical defaults to digital_signature + key_encipherment
+#[derive(Clone, Debug)]
+pub struct IssueCertificateOptions {
+    pub issuer: CertificateIssuer,
+    pub common_name: String,
+    pub alt_names: Vec<String>,
+    pub ttl: String,
+    pub friendly_name: Option<String>,
+    pub key_usages: Vec<KeyUsage>,
+    pub extended_key_usages: Vec<ExtendedKeyUsage>,
+    pub not_before: Option<String>,
+    pub not_after: Option<String>,
+}
+
+/// Input for sign_certificate(). The common name and alt names default to the CSR's own if left empty.
+#[derive(Clone, Debug)]
+pub struct SignCertificateOptions {
+    pub issuer: CertificateIssuer,
+    pub csr: String,
+    pub common_name: Option<String>,
+    pub alt_names: Vec<String>,
+    pub ttl: String,
+    pub friendly_name: Option<String>,
+    pub key_usages: Vec<KeyUsage>,
+    pub extended_key_usages: Vec<ExtendedKeyUsage>,
+}
+
+/// A certificate signed from a CSR, PEM encoded. The private key never left the requester.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SignedCertificate {
+    pub certificate: String,
+    #[serde(default)]
+    pub certificate_chain: String,
+    #[serde(default)]
+    pub issuing_ca_certificate: String,
+    pub serial_number: String,
+}
+
+/// A certificate issued by Infisical, along with the private key it generated for it. PEM encoded.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuedCertificate {
+    pub certificate: String,
+    #[serde(default)]
+    pub certificate_chain: String,
+    #[serde(default)]
+    pub issuing_ca_certificate: String,
+    pub serial_number: String,
+    pub private_key: SecretString,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RevocationReason {
+    #[default]
+    Unspecified,
+    KeyCompromise,
+    CaCompromise,
+    AffiliationChanged,
+    Superseded,
+    CessationOfOperation,
+    CertificateHold,
+    PrivilegeWithdrawn,
+    #[serde(rename = "A_A_COMPROMISE")]
+    #[strum(to_string = "A_A_COMPROMISE")]
+    AaCompromise,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct RevokedCertificate {
+    pub serial_number: String,
+    pub revoked_at: String,
+}
+
+/// A CA's certificate revocation list, PEM encoded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CertificateRevocationList {
+    pub id: String,
+    pub crl: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CertificateRequestBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ca_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_template_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csr: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub alt_names: String,
+    pub ttl: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub key_usages: &'a [KeyUsage],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub extended_key_usages: &'a [ExtendedKeyUsage],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_after: Option<&'a str>,
+}
+
+impl<'a> CertificateRequestBody<'a> {
+    pub(crate) fn new(issuer: &'a CertificateIssuer, alt_names: &[String], ttl: &'a str) -> Self {
+        let (ca_id, certificate_template_id) = match issuer {
+            CertificateIssuer::CertificateAuthority(ca_id) => (Some(ca_id.as_str()), None),
+            CertificateIssuer::Template(template_id) => (None, Some(template_id.as_str())),
+        };
+
+        Self {
+            ca_id,
+            certificate_template_id,
+            csr: None,
+            common_name: None,
+            alt_names: alt_names.join(","),
+            ttl,
+            friendly_name: None,
+            key_usages: &[],
+            extended_key_usages: &[],
+            not_before: None,
+            not_after: None,
+        }
+    }
+}
+
+// issue_certificate() and sign_certificate() send their options as a CertificateRequestBody
+impl Serialize for IssueCertificateOptions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CertificateRequestBody {
+            common_name: Some(&self.common_name),
+            friendly_name: self.friendly_name.as_deref(),
+            key_usages: &self.key_usages,
+            extended_key_usages: &self.extended_key_usages,
+            not_before: self.not_before.as_deref(),
+            not_after: self.not_after.as_deref(),
+            ..CertificateRequestBody::new(&self.issuer, &self.alt_names, &self.ttl)
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for SignCertificateOptions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CertificateRequestBody {
+            csr: Some(&self.csr),
+            common_name: self.common_name.as_deref(),
+            friendly_name: self.friendly_name.as_deref(),
+            key_usages: &self.key_usages,
+            extended_key_usages: &self.extended_key_usages,
+            ..CertificateRequestBody::new(&self.issuer, &self.alt_names, &self.ttl)
+        }
+        .serialize(serializer)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CertificateAuthorityResponse {
+    pub ca: CertificateAuthority,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CertificateAuthoritiesResponse {
+    pub cas: Vec<CertificateAuthority>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CertificateAuthorityCsrResponse {
+    pub csr: String,
+}
+
+pub mod pki_util_functions {
+    pub fn construct_pki_endpoint_url(host: &str, path: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/pki/{path}",
+            host_url = host,
+            version = "v1",
+        )
+    }
+
+    pub fn construct_project_cas_endpoint_url(host: &str, project_id: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/workspace/{project_id}/cas",
+            host_url = host,
+            version = "v1",
+        )
+    }
+}
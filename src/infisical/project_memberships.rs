@@ -0,0 +1,473 @@
+/// Project memberships:
+///
+/// Adding machine identities and users to projects, removing them, and managing the project roles they hold,
+/// including temporary ones.
+use reqwest::StatusCode;
+
+use error_handling::ProjectMembershipError;
+use utils::{
+    project_membership_util_functions::{
+        construct_project_identity_memberships_endpoint_url,
+        construct_project_user_memberships_endpoint_url,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the project memberships module, such as ProjectRoleAssignment and
+/// ProjectIdentityMembership.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Machine identity memberships
+impl UniversalAuthAccessToken {
+    /// list_project_identity_memberships()
+    ///
+    /// Lists the machine identities that are members of a project, with their roles.
+    pub async fn list_project_identity_memberships(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+    ) -> Result<Vec<ProjectIdentityMembership>, ProjectMembershipError> {
+        let endpoint_url =
+            construct_project_identity_memberships_endpoint_url(host, project_id, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(project_error(
+                "list_project_identity_memberships",
+                project_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(
+            serde_json::from_slice::<ProjectIdentityMembershipsResponse>(&bytes)?
+                .identity_memberships,
+        )
+    }
+
+    /// get_project_identity_membership()
+    ///
+    /// Retrieves a machine identity's membership in a project, with its roles.
+    pub async fn get_project_identity_membership(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        identity_id: &str,
+    ) -> Result<ProjectIdentityMembership, ProjectMembershipError> {
+        let endpoint_url = construct_project_identity_memberships_endpoint_url(
+            host,
+            project_id,
+            Some(identity_id),
+        );
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(identity_membership_error(
+                "get_project_identity_membership",
+                project_id,
+                identity_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(
+            serde_json::from_slice::<ProjectIdentityMembershipResponse>(&bytes)?
+                .identity_membership,
+        )
+    }
+
+    /// add_identity_to_project()
+    ///
+    /// Adds a machine identity from the project's organization to the project, with the given roles.
+    ///
+    /// # Example
+    /// ```ignore
+    /// access_token
+    ///     .add_identity_to_project(
+    ///         host,
+    ///         &client,
+    ///         project_id,
+    ///         &identity.id,
+    ///         &[ProjectRoleAssignment::permanent("viewer")],
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn add_identity_to_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        identity_id: &str,
+        roles: &[ProjectRoleAssignment],
+    ) -> Result<ProjectIdentityMembership, ProjectMembershipError> {
+        let endpoint_url = construct_project_identity_memberships_endpoint_url(
+            host,
+            project_id,
+            Some(identity_id),
+        );
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&RoleAssignmentsBody::new(roles))
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(identity_membership_error(
+                "add_identity_to_project",
+                project_id,
+                identity_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(
+            serde_json::from_slice::<ProjectIdentityMembershipResponse>(&bytes)?
+                .identity_membership,
+        )
+    }
+
+    /// update_project_identity_roles()
+    ///
+    /// Replaces a machine identity's roles in a project, returning its new roles.
+    pub async fn update_project_identity_roles(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        identity_id: &str,
+        roles: &[ProjectRoleAssignment],
+    ) -> Result<Vec<ProjectMembershipRole>, ProjectMembershipError> {
+        let endpoint_url = construct_project_identity_memberships_endpoint_url(
+            host,
+            project_id,
+            Some(identity_id),
+        );
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&RoleAssignmentsBody::new(roles))
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(identity_membership_error(
+                "update_project_identity_roles",
+                project_id,
+                identity_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectMembershipRolesResponse>(&bytes)?.roles)
+    }
+
+    /// remove_identity_from_project()
+    ///
+    /// Removes a machine identity from a project. The identity itself is left alone.
+    pub async fn remove_identity_from_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        identity_id: &str,
+    ) -> Result<ProjectIdentityMembership, ProjectMembershipError> {
+        let endpoint_url = construct_project_identity_memberships_endpoint_url(
+            host,
+            project_id,
+            Some(identity_id),
+        );
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(identity_membership_error(
+                "remove_identity_from_project",
+                project_id,
+                identity_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(
+            serde_json::from_slice::<ProjectIdentityMembershipResponse>(&bytes)?
+                .identity_membership,
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// User memberships
+impl UniversalAuthAccessToken {
+    /// list_project_user_memberships()
+    ///
+    /// Lists the users that are members of a project, with their roles.
+    pub async fn list_project_user_memberships(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+    ) -> Result<Vec<ProjectUserMembership>, ProjectMembershipError> {
+        let endpoint_url =
+            construct_project_user_memberships_endpoint_url(host, "v1", project_id, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(project_error(
+                "list_project_user_memberships",
+                project_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectUserMembershipsResponse>(&bytes)?.memberships)
+    }
+
+    /// add_users_to_project()
+    ///
+    /// Adds users from the project's organization to the project by their usernames (usually their emails), with the
+    /// given role slugs. None gives them the "member" role. Use update_project_user_roles() for temporary roles.
+    pub async fn add_users_to_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        usernames: &[String],
+        role_slugs: Option<&[String]>,
+    ) -> Result<Vec<ProjectUserMembershipRecord>, ProjectMembershipError> {
+        let endpoint_url =
+            construct_project_user_memberships_endpoint_url(host, "v2", project_id, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&UserMembershipsBody {
+                usernames,
+                role_slugs,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(user_membership_error(
+                "add_users_to_project",
+                project_id,
+                usernames,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectUserMembershipRecordsResponse>(&bytes)?.memberships)
+    }
+
+    /// update_project_user_roles()
+    ///
+    /// Replaces a user's roles in a project, returning their new roles. `membership_id` is the id of the user's
+    /// ProjectUserMembership, not the user's id.
+    pub async fn update_project_user_roles(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        membership_id: &str,
+        roles: &[ProjectRoleAssignment],
+    ) -> Result<Vec<ProjectMembershipRole>, ProjectMembershipError> {
+        let endpoint_url = construct_project_user_memberships_endpoint_url(
+            host,
+            "v1",
+            project_id,
+            Some(membership_id),
+        );
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&RoleAssignmentsBody::new(roles))
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(user_membership_error(
+                "update_project_user_roles",
+                project_id,
+                &[membership_id.to_string()],
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectMembershipRolesResponse>(&bytes)?.roles)
+    }
+
+    /// remove_users_from_project()
+    ///
+    /// Removes users from a project by their usernames. They stay members of the organization.
+    pub async fn remove_users_from_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        usernames: &[String],
+    ) -> Result<Vec<ProjectUserMembershipRecord>, ProjectMembershipError> {
+        let endpoint_url =
+            construct_project_user_memberships_endpoint_url(host, "v2", project_id, None);
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&UserMembershipsBody {
+                usernames,
+                role_slugs: None,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(user_membership_error(
+                "remove_users_from_project",
+                project_id,
+                usernames,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectUserMembershipRecordsResponse>(&bytes)?.memberships)
+    }
+}
+
+fn project_error(operation: &str, project_id: &str, error: ApiResponse) -> ProjectMembershipError {
+    ProjectMembershipError::ProjectRequestError {
+        operation: operation.to_string(),
+        project_id: project_id.to_string(),
+        error,
+    }
+}
+
+fn identity_membership_error(
+    operation: &str,
+    project_id: &str,
+    identity_id: &str,
+    error: ApiResponse,
+) -> ProjectMembershipError {
+    ProjectMembershipError::IdentityMembershipError {
+        operation: operation.to_string(),
+        project_id: project_id.to_string(),
+        identity_id: identity_id.to_string(),
+        error,
+    }
+}
+
+fn user_membership_error(
+    operation: &str,
+    project_id: &str,
+    users: &[String],
+    error: ApiResponse,
+) -> ProjectMembershipError {
+    ProjectMembershipError::UserMembershipError {
+        operation: operation.to_string(),
+        project_id: project_id.to_string(),
+        users: users.to_vec(),
+        error,
+    }
+}
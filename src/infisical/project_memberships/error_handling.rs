@@ -0,0 +1,48 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum ProjectMembershipError {
+    #[error(
+        "ProjectMemberships::{operation}(): 
+        Project ID: {project_id}
+        Err: {error}"
+    )]
+    ProjectRequestError {
+        operation: String,
+        project_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "ProjectMemberships::{operation}(): 
+        Project ID: {project_id}
+        Identity ID: {identity_id}
+        Err: {error}"
+    )]
+    IdentityMembershipError {
+        operation: String,
+        project_id: String,
+        identity_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "ProjectMemberships::{operation}(): 
+        Project ID: {project_id}
+        Users: {users:?}
+        Err: {error}"
+    )]
+    UserMembershipError {
+        operation: String,
+        project_id: String,
+        users: Vec<String>,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
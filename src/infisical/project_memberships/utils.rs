@@ -0,0 +1,287 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::infisical::{
+    identities::utils::MachineIdentity,
+    utils::time_utils::{format_rfc3339_utc, parse_rfc3339_utc},
+};
+
+/// A project role to assign to a member, either for good or for a limited time.
+///
+/// - role: a built-in role's slug ("admin", "member", "viewer", "no-access"), or a custom role's slug
+/// - temporary: if set, the role only grants access for the given time range
+///
+/// # Example
+/// ```
+/// use infisical_rs::infisical::project_memberships::utils::ProjectRoleAssignment;
+///
+/// let roles = vec![
+///     ProjectRoleAssignment::permanent("viewer"),
+///     ProjectRoleAssignment::temporary("admin", "2h", None),
+/// ];
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProjectRoleAssignment {
+    pub role: String,
+    pub temporary: Option<TemporaryRoleAccess>,
+}
+
+/// How long a temporary role assignment lasts.
+///
+/// - range: how long the role is granted for, e.g. "30m", "2h" or "7d"
+/// - starts_at: when the role starts granting access, None for right away
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemporaryRoleAccess {
+    pub range: String,
+    pub starts_at: Option<SystemTime>,
+}
+
+impl ProjectRoleAssignment {
+    pub fn permanent(role: &str) -> Self {
+        Self {
+            role: role.to_string(),
+            temporary: None,
+        }
+    }
+
+    pub fn temporary(role: &str, range: &str, starts_at: Option<SystemTime>) -> Self {
+        Self {
+            role: role.to_string(),
+            temporary: Some(TemporaryRoleAccess {
+                range: range.to_string(),
+                starts_at,
+            }),
+        }
+    }
+}
+
+/// A role assigned to a project member.
+///
+/// Fields:
+/// - role: the built-in role's slug, or "custom" for custom roles (see custom_role_slug)
+/// - temporary_access_start_time/temporary_access_end_time: when a temporary role grants access, as RFC 3339 timestamps
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct ProjectMembershipRole {
+    pub id: String,
+    pub role: String,
+    #[serde(default)]
+    pub custom_role_id: Option<String>,
+    #[serde(default)]
+    pub custom_role_slug: Option<String>,
+    #[serde(default)]
+    pub custom_role_name: Option<String>,
+    #[serde(default)]
+    pub is_temporary: bool,
+    #[serde(default)]
+    pub temporary_range: Option<String>,
+    #[serde(default)]
+    pub temporary_access_start_time: Option<String>,
+    #[serde(default)]
+    pub temporary_access_end_time: Option<String>,
+}
+
+impl ProjectMembershipRole {
+    /// The slug of the role, whether it's a built-in or a custom one.
+    pub fn slug(&self) -> &str {
+        match (self.role.as_str(), &self.custom_role_slug) {
+            ("custom", Some(custom_role_slug)) => custom_role_slug,
+            (role, _) => role,
+        }
+    }
+
+    /// Whether the role grants access at the given time. Permanent roles always do.
+    pub fn is_active_at(&self, time: SystemTime) -> bool {
+        if !self.is_temporary {
+            return true;
+        }
+
+        let starts_at = self
+            .temporary_access_start_time
+            .as_deref()
+            .and_then(parse_rfc3339_utc);
+        let ends_at = self
+            .temporary_access_end_time
+            .as_deref()
+            .and_then(parse_rfc3339_utc);
+
+        match (starts_at, ends_at) {
+            (Some(starts_at), Some(ends_at)) => starts_at <= time && time < ends_at,
+            _ => false,
+        }
+    }
+}
+
+/// A machine identity's membership in a project.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct ProjectIdentityMembership {
+    pub id: String,
+    pub identity: MachineIdentity,
+    #[serde(default)]
+    pub roles: Vec<ProjectMembershipRole>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct ProjectMemberUser {
+    pub id: String,
+    pub username: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+}
+
+/// A user's membership in a project.
+///
+/// Fields:
+/// - id: the membership's id, used by update_project_user_roles()
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct ProjectUserMembership {
+    pub id: String,
+    pub user_id: String,
+    pub project_id: String,
+    pub user: ProjectMemberUser,
+    #[serde(default)]
+    pub roles: Vec<ProjectMembershipRole>,
+}
+
+/// A user membership as created or removed by add_users_to_project() and remove_users_from_project().
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct ProjectUserMembershipRecord {
+    pub id: String,
+    pub user_id: String,
+    pub project_id: String,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RoleAssignmentBody<'a> {
+    pub role: &'a str,
+    pub is_temporary: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary_mode: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary_range: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary_access_start_time: Option<String>,
+}
+
+impl<'a> RoleAssignmentBody<'a> {
+    pub fn new(assignment: &'a ProjectRoleAssignment) -> Self {
+        match &assignment.temporary {
+            None => Self {
+                role: &assignment.role,
+                is_temporary: false,
+                temporary_mode: None,
+                temporary_range: None,
+                temporary_access_start_time: None,
+            },
+            Some(temporary) => Self {
+                role: &assignment.role,
+                is_temporary: true,
+                // the only mode Infisical supports: a range counted from the start time
+                temporary_mode: Some("relative"),
+                temporary_range: Some(&temporary.range),
+                temporary_access_start_time: Some(format_rfc3339_utc(
+                    temporary.starts_at.unwrap_or_else(SystemTime::now),
+                )),
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct RoleAssignmentsBody<'a> {
+    pub roles: Vec<RoleAssignmentBody<'a>>,
+}
+
+impl<'a> RoleAssignmentsBody<'a> {
+    pub fn new(roles: &'a [ProjectRoleAssignment]) -> Self {
+        Self {
+            roles: roles.iter().map(RoleAssignmentBody::new).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UserMembershipsBody<'a> {
+    pub usernames: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_slugs: Option<&'a [String]>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectIdentityMembershipResponse {
+    pub identity_membership: ProjectIdentityMembership,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectIdentityMembershipsResponse {
+    pub identity_memberships: Vec<ProjectIdentityMembership>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectUserMembershipsResponse {
+    pub memberships: Vec<ProjectUserMembership>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectUserMembershipRecordsResponse {
+    pub memberships: Vec<ProjectUserMembershipRecord>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectMembershipRolesResponse {
+    pub roles: Vec<ProjectMembershipRole>,
+}
+
+pub mod project_membership_util_functions {
+    pub fn construct_project_identity_memberships_endpoint_url(
+        host: &str,
+        project_id: &str,
+        identity_id: Option<&str>,
+    ) -> String {
+        let is_identity_id =
+            identity_id.map_or_else(|| "".to_string(), |identity_id| format!("/{identity_id}"));
+
+        format!(
+            "{host_url}/api/{version}/workspace/{project_id}/identity-memberships{identity_id}",
+            host_url = host,
+            version = "v2",
+            identity_id = is_identity_id
+        )
+    }
+
+    /// construct_project_user_memberships_endpoint_url()
+    ///
+    /// Adding and removing users goes through v2, listing them and updating their roles through v1.
+    pub fn construct_project_user_memberships_endpoint_url(
+        host: &str,
+        version: &str,
+        project_id: &str,
+        membership_id: Option<&str>,
+    ) -> String {
+        let is_membership_id = membership_id.map_or_else(
+            || "".to_string(),
+            |membership_id| format!("/{membership_id}"),
+        );
+
+        format!(
+            "{host_url}/api/{version}/workspace/{project_id}/memberships{membership_id}",
+            host_url = host,
+            membership_id = is_membership_id
+        )
+    }
+}
@@ -0,0 +1,309 @@
+/// Project roles:
+///
+/// Custom project roles, and the typed permission rules they're made of.
+use reqwest::StatusCode;
+
+use error_handling::ProjectRoleError;
+use evaluation::ProjectPermissions;
+use utils::{
+    project_role_util_functions::{
+        construct_project_permissions_endpoint_url, construct_project_roles_endpoint_url,
+        unpack_permission_rules,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Evaluating permission rules locally, e.g. to check access before attempting a request.
+pub mod evaluation;
+
+/// Typed project permission rules, such as PermissionRule and PermissionConditions.
+pub mod permissions;
+
+/// Structs and utility functions used by the project roles module, such as ProjectRole and CreateProjectRoleOptions.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Custom project role management
+impl UniversalAuthAccessToken {
+    /// list_project_roles()
+    ///
+    /// Lists a project's roles, built-in roles included.
+    pub async fn list_project_roles(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+    ) -> Result<Vec<ProjectRole>, ProjectRoleError> {
+        let endpoint_url = construct_project_roles_endpoint_url(host, project_slug, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectRoleError::ProjectRequestError {
+                operation: "list_project_roles".to_string(),
+                project_slug: project_slug.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectRolesResponse>(&bytes)?.roles)
+    }
+
+    /// get_project_role()
+    ///
+    /// Retrieves a project role by its slug.
+    pub async fn get_project_role(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+        role_slug: &str,
+    ) -> Result<ProjectRole, ProjectRoleError> {
+        let endpoint_url = construct_project_roles_endpoint_url(
+            host,
+            project_slug,
+            Some(&format!("slug/{role_slug}")),
+        );
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(role_error(
+                "get_project_role",
+                project_slug,
+                role_slug,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectRoleResponse>(&bytes)?.role)
+    }
+
+    /// create_project_role()
+    ///
+    /// Creates a custom project role from typed permission rules.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let role = access_token
+    ///     .create_project_role(
+    ///         host,
+    ///         &client,
+    ///         "payments",
+    ///         &CreateProjectRoleOptions {
+    ///             slug: "prod-app-reader".to_string(),
+    ///             name: "Production app reader".to_string(),
+    ///             description: None,
+    ///             permissions: vec![
+    ///                 PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read])
+    ///                     .in_environment("prod")
+    ///                     .at_secret_path_glob("/apps/**"),
+    ///             ],
+    ///         },
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn create_project_role(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+        options: &CreateProjectRoleOptions,
+    ) -> Result<ProjectRole, ProjectRoleError> {
+        let endpoint_url = construct_project_roles_endpoint_url(host, project_slug, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(role_error(
+                "create_project_role",
+                project_slug,
+                &options.slug,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectRoleResponse>(&bytes)?.role)
+    }
+
+    /// update_project_role()
+    ///
+    /// Updates a custom project role by its id.
+    pub async fn update_project_role(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+        role_id: &str,
+        options: &UpdateProjectRoleOptions,
+    ) -> Result<ProjectRole, ProjectRoleError> {
+        let endpoint_url = construct_project_roles_endpoint_url(host, project_slug, Some(role_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(role_error(
+                "update_project_role",
+                project_slug,
+                role_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectRoleResponse>(&bytes)?.role)
+    }
+
+    /// delete_project_role()
+    ///
+    /// Deletes a custom project role by its id. Infisical refuses to delete roles still assigned to members.
+    pub async fn delete_project_role(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+        role_id: &str,
+    ) -> Result<ProjectRole, ProjectRoleError> {
+        let endpoint_url = construct_project_roles_endpoint_url(host, project_slug, Some(role_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(role_error(
+                "delete_project_role",
+                project_slug,
+                role_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectRoleResponse>(&bytes)?.role)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Effective permissions
+impl UniversalAuthAccessToken {
+    /// get_project_permissions()
+    ///
+    /// Retrieves the current identity's effective permissions in a project, i.e. the rules of every role it holds
+    /// there, for evaluation with ProjectPermissions.
+    pub async fn get_project_permissions(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+    ) -> Result<ProjectPermissions, ProjectRoleError> {
+        let endpoint_url = construct_project_permissions_endpoint_url(host, project_id);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectRoleError::ProjectPermissionsError {
+                project_id: project_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        let packed_rules = serde_json::from_slice::<ProjectPermissionsResponse>(&bytes)?
+            .data
+            .permissions;
+
+        Ok(ProjectPermissions::new(unpack_permission_rules(
+            &packed_rules,
+        )?))
+    }
+}
+
+fn role_error(
+    operation: &str,
+    project_slug: &str,
+    role: &str,
+    error: ApiResponse,
+) -> ProjectRoleError {
+    ProjectRoleError::RoleRequestError {
+        operation: operation.to_string(),
+        project_slug: project_slug.to_string(),
+        role: role.to_string(),
+        error,
+    }
+}
@@ -0,0 +1,70 @@
+use crate::infisical::{
+    project_roles::{
+        evaluation::PermissionResource,
+        permissions::{PermissionAction, PermissionRule, PermissionSubject},
+    },
+    utils::api_utils::ApiResponse,
+};
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum ProjectRoleError {
+    #[error(
+        "ProjectRoles::{operation}(): 
+        Project slug: {project_slug}
+        Err: {error}"
+    )]
+    ProjectRequestError {
+        operation: String,
+        project_slug: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "ProjectRoles::get_project_permissions(): 
+        Project ID: {project_id}
+        Err: {error}"
+    )]
+    ProjectPermissionsError {
+        project_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "ProjectRoles::{operation}(): 
+        Project slug: {project_slug}
+        Role: {role}
+        Err: {error}"
+    )]
+    RoleRequestError {
+        operation: String,
+        project_slug: String,
+        role: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
+
+// ---------------------------------------------------------------------------------------------------------
+
+/// Why ProjectPermissions::check() refused an action: either a rule forbids it (denied_by), or no rule allows it.
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+#[error(
+    "ProjectPermissions::check(): 
+        Action: {action}
+        Subject: {subject}
+        Resource: {resource:?}
+        Reason: {}",
+    denied_by.as_ref().map_or("no rule allows it", |_| "a rule forbids it")
+)]
+pub struct PermissionDenied {
+    pub action: PermissionAction,
+    pub subject: PermissionSubject,
+    pub resource: Box<PermissionResource>,
+    pub denied_by: Option<Box<PermissionRule>>,
+}
@@ -0,0 +1,224 @@
+/// Permission evaluation:
+///
+/// Answers "can this identity do X to resource Y" locally, from a project's permission rules, the same way
+/// Infisical's own permission checks do. Useful to fail fast before a request that would come back as
+/// ApiResponse::Forbidden.
+use crate::infisical::project_roles::{
+    error_handling::PermissionDenied,
+    permissions::{PermissionAction, PermissionCondition, PermissionRule, PermissionSubject},
+    utils::project_role_util_functions::matches_glob,
+};
+
+/// The resource a permission is checked against. Fields left as None can't satisfy conditions on them.
+///
+/// # Example
+/// ```
+/// use infisical_rs::infisical::project_roles::evaluation::PermissionResource;
+///
+/// let resource = PermissionResource::secret("prod", "/apps/payments", Some("DATABASE_URL"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PermissionResource {
+    pub environment: Option<String>,
+    pub secret_path: Option<String>,
+    pub secret_name: Option<String>,
+}
+
+impl PermissionResource {
+    /// A secret (or, without a name, any secret) at the given environment and path.
+    pub fn secret(environment: &str, secret_path: &str, secret_name: Option<&str>) -> Self {
+        Self {
+            environment: Some(environment.to_string()),
+            secret_path: Some(secret_path.to_string()),
+            secret_name: secret_name.map(str::to_string),
+        }
+    }
+}
+
+/// ProjectPermissions
+///
+/// A set of project permission rules, e.g. the current identity's from get_project_permissions(), and the
+/// evaluation of actions against them.
+///
+/// Rules follow Infisical's semantics: later rules take precedence over earlier ones, the last rule matching an
+/// action, subject and resource decides, and nothing is allowed unless a rule allows it. The "manage" action and
+/// "all" subject match every action and subject.
+///
+/// Reading secrets follows Infisical's split read action: PermissionAction::Read on secrets is allowed when both
+/// DescribeSecret and ReadValue are, whether a role grants them separately or through the legacy "read".
+///
+/// Conditions on fields this crate doesn't model (see PermissionConditions::other) can't be checked locally: rules
+/// allowing with such conditions are treated as not matching, and rules forbidding with them as matching. A check
+/// can therefore be stricter than Infisical, but never more lenient.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProjectPermissions {
+    pub rules: Vec<PermissionRule>,
+}
+
+impl ProjectPermissions {
+    pub fn new(rules: Vec<PermissionRule>) -> Self {
+        Self { rules }
+    }
+
+    /// can()
+    ///
+    /// Whether `action` on `subject` is allowed for the given resource.
+    pub fn can(
+        &self,
+        action: &PermissionAction,
+        subject: &PermissionSubject,
+        resource: &PermissionResource,
+    ) -> bool {
+        self.check(action, subject, resource).is_ok()
+    }
+
+    /// check()
+    ///
+    /// can(), as a Result explaining why the action isn't allowed.
+    pub fn check(
+        &self,
+        action: &PermissionAction,
+        subject: &PermissionSubject,
+        resource: &PermissionResource,
+    ) -> Result<(), PermissionDenied> {
+        // reading a secret takes both halves of the split read action, whichever way a role grants them
+        if *subject == PermissionSubject::Secrets && *action == PermissionAction::Read {
+            for action in [
+                PermissionAction::DescribeSecret,
+                PermissionAction::ReadValue,
+            ] {
+                self.check_action(&action, subject, resource)?;
+            }
+
+            return Ok(());
+        }
+
+        self.check_action(action, subject, resource)
+    }
+
+    fn check_action(
+        &self,
+        action: &PermissionAction,
+        subject: &PermissionSubject,
+        resource: &PermissionResource,
+    ) -> Result<(), PermissionDenied> {
+        match self.deciding_rule(action, subject, resource) {
+            Some(rule) if !rule.inverted => Ok(()),
+            denied_by => Err(PermissionDenied {
+                action: action.clone(),
+                subject: subject.clone(),
+                resource: Box::new(resource.clone()),
+                denied_by: denied_by.cloned().map(Box::new),
+            }),
+        }
+    }
+
+    /// check_secret_access()
+    ///
+    /// check() for a secret, e.g. PermissionAction::Edit before updating it, or PermissionAction::Create before
+    /// creating it.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let permissions = access_token.get_project_permissions(host, &client, project_id).await?;
+    ///
+    /// permissions.check_secret_access(&PermissionAction::Edit, "prod", "/apps/payments", Some("DATABASE_URL"))?;
+    /// ```
+    pub fn check_secret_access(
+        &self,
+        action: &PermissionAction,
+        environment: &str,
+        secret_path: &str,
+        secret_name: Option<&str>,
+    ) -> Result<(), PermissionDenied> {
+        self.check(
+            action,
+            &PermissionSubject::Secrets,
+            &PermissionResource::secret(environment, secret_path, secret_name),
+        )
+    }
+
+    pub fn can_read_secret(
+        &self,
+        environment: &str,
+        secret_path: &str,
+        secret_name: Option<&str>,
+    ) -> bool {
+        self.check_secret_access(
+            &PermissionAction::Read,
+            environment,
+            secret_path,
+            secret_name,
+        )
+        .is_ok()
+    }
+
+    // the last rule that applies, later rules take precedence
+    fn deciding_rule(
+        &self,
+        action: &PermissionAction,
+        subject: &PermissionSubject,
+        resource: &PermissionResource,
+    ) -> Option<&PermissionRule> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule_covers(rule, action, subject) && conditions_hold(rule, resource))
+    }
+}
+
+fn rule_covers(
+    rule: &PermissionRule,
+    action: &PermissionAction,
+    subject: &PermissionSubject,
+) -> bool {
+    let covers_subject = rule.subject == *subject || rule.subject.as_str() == "all";
+    let covers_action = rule.actions.iter().any(|rule_action| {
+        rule_action == action
+            || rule_action.as_str() == "manage"
+            // the legacy read action covers both halves of the split one
+            || (*rule_action == PermissionAction::Read
+                && matches!(
+                    action,
+                    PermissionAction::DescribeSecret | PermissionAction::ReadValue
+                ))
+    });
+
+    covers_subject && covers_action
+}
+
+fn conditions_hold(rule: &PermissionRule, resource: &PermissionResource) -> bool {
+    // unknown conditions can't be checked, assume whichever outcome is stricter
+    if !rule.conditions.other.is_empty() {
+        return rule.inverted;
+    }
+
+    let field_holds = |conditions: &[PermissionCondition], value: Option<&str>| {
+        conditions
+            .iter()
+            .all(|condition| condition_holds(condition, value))
+    };
+
+    field_holds(
+        &rule.conditions.environment,
+        resource.environment.as_deref(),
+    ) && field_holds(
+        &rule.conditions.secret_path,
+        resource.secret_path.as_deref(),
+    ) && field_holds(
+        &rule.conditions.secret_name,
+        resource.secret_name.as_deref(),
+    )
+}
+
+fn condition_holds(condition: &PermissionCondition, value: Option<&str>) -> bool {
+    match (condition, value) {
+        (PermissionCondition::Eq(expected), Some(value)) => value == expected,
+        (PermissionCondition::Ne(unexpected), Some(value)) => value != unexpected,
+        (PermissionCondition::In(expected), Some(value)) => expected.iter().any(|e| e == value),
+        (PermissionCondition::Glob(pattern), Some(value)) => matches_glob(pattern, value),
+        // a missing value never equals anything
+        (PermissionCondition::Ne(_), None) => true,
+        (_, None) => false,
+    }
+}
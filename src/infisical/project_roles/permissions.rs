@@ -0,0 +1,390 @@
+/// Permissions:
+///
+/// Project permission rules as typed values, and their (de)serialization to and from the JSON permission documents
+/// Infisical stores for project roles.
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _, ser::Error as _};
+use serde_json::{Map, Value};
+
+/// What a permission rule applies to. Subjects this crate doesn't model yet are kept as Other.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum PermissionSubject {
+    Secrets,
+    SecretFolders,
+    SecretImports,
+    DynamicSecrets,
+    SecretRotation,
+    Environments,
+    Tags,
+    Webhooks,
+    Integrations,
+    Members,
+    Identity,
+    Role,
+    AuditLogs,
+    Settings,
+    Kms,
+    Other(String),
+}
+
+impl PermissionSubject {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PermissionSubject::Secrets => "secrets",
+            PermissionSubject::SecretFolders => "secret-folders",
+            PermissionSubject::SecretImports => "secret-imports",
+            PermissionSubject::DynamicSecrets => "dynamic-secrets",
+            PermissionSubject::SecretRotation => "secret-rotation",
+            PermissionSubject::Environments => "environments",
+            PermissionSubject::Tags => "tags",
+            PermissionSubject::Webhooks => "webhooks",
+            PermissionSubject::Integrations => "integrations",
+            PermissionSubject::Members => "member",
+            PermissionSubject::Identity => "identity",
+            PermissionSubject::Role => "role",
+            PermissionSubject::AuditLogs => "audit-logs",
+            PermissionSubject::Settings => "settings",
+            PermissionSubject::Kms => "kms",
+            PermissionSubject::Other(subject) => subject,
+        }
+    }
+}
+
+impl From<&str> for PermissionSubject {
+    fn from(subject: &str) -> Self {
+        match subject {
+            "secrets" => PermissionSubject::Secrets,
+            "secret-folders" => PermissionSubject::SecretFolders,
+            "secret-imports" => PermissionSubject::SecretImports,
+            "dynamic-secrets" => PermissionSubject::DynamicSecrets,
+            "secret-rotation" => PermissionSubject::SecretRotation,
+            "environments" => PermissionSubject::Environments,
+            "tags" => PermissionSubject::Tags,
+            "webhooks" => PermissionSubject::Webhooks,
+            "integrations" => PermissionSubject::Integrations,
+            "member" => PermissionSubject::Members,
+            "identity" => PermissionSubject::Identity,
+            "role" => PermissionSubject::Role,
+            "audit-logs" => PermissionSubject::AuditLogs,
+            "settings" => PermissionSubject::Settings,
+            "kms" => PermissionSubject::Kms,
+            other => PermissionSubject::Other(other.to_string()),
+        }
+    }
+}
+
+/// What a permission rule allows (or, for inverted rules, forbids). Actions this crate doesn't model yet, e.g.
+/// dynamic secrets' "lease", are kept as Other.
+///
+/// Newer roles split reading secrets into DescribeSecret (seeing that a secret exists, and its metadata) and
+/// ReadValue (seeing its value). Read still covers both.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum PermissionAction {
+    Read,
+    DescribeSecret,
+    ReadValue,
+    Create,
+    Edit,
+    Delete,
+    Other(String),
+}
+
+impl PermissionAction {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PermissionAction::Read => "read",
+            PermissionAction::DescribeSecret => "describeSecret",
+            PermissionAction::ReadValue => "readValue",
+            PermissionAction::Create => "create",
+            PermissionAction::Edit => "edit",
+            PermissionAction::Delete => "delete",
+            PermissionAction::Other(action) => action,
+        }
+    }
+}
+
+impl From<&str> for PermissionAction {
+    fn from(action: &str) -> Self {
+        match action {
+            "read" => PermissionAction::Read,
+            "describeSecret" => PermissionAction::DescribeSecret,
+            "readValue" => PermissionAction::ReadValue,
+            "create" => PermissionAction::Create,
+            "edit" => PermissionAction::Edit,
+            "delete" => PermissionAction::Delete,
+            other => PermissionAction::Other(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for PermissionSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::fmt::Display for PermissionAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// subjects and actions are plain strings in permission documents
+impl From<String> for PermissionSubject {
+    fn from(subject: String) -> Self {
+        subject.as_str().into()
+    }
+}
+
+impl From<PermissionSubject> for String {
+    fn from(subject: PermissionSubject) -> Self {
+        subject.as_str().to_string()
+    }
+}
+
+impl From<String> for PermissionAction {
+    fn from(action: String) -> Self {
+        action.as_str().into()
+    }
+}
+
+impl From<PermissionAction> for String {
+    fn from(action: PermissionAction) -> Self {
+        action.as_str().to_string()
+    }
+}
+
+/// A condition on one of a resource's fields, e.g. its secret path.
+///
+/// - Glob: matches Infisical's glob syntax, e.g. "/apps/*/config" or "/apps/**"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PermissionCondition {
+    Eq(String),
+    Ne(String),
+    In(Vec<String>),
+    Glob(String),
+}
+
+impl PermissionCondition {
+    fn operator(&self) -> &'static str {
+        match self {
+            PermissionCondition::Eq(_) => "$eq",
+            PermissionCondition::Ne(_) => "$ne",
+            PermissionCondition::In(_) => "$in",
+            PermissionCondition::Glob(_) => "$glob",
+        }
+    }
+
+    fn value(&self) -> Value {
+        match self {
+            PermissionCondition::Eq(value)
+            | PermissionCondition::Ne(value)
+            | PermissionCondition::Glob(value) => Value::String(value.clone()),
+            PermissionCondition::In(values) => Value::from(values.clone()),
+        }
+    }
+
+    fn parse(operator: &str, value: Value) -> Result<Self, String> {
+        let condition = match operator {
+            "$eq" => serde_json::from_value(value).map(PermissionCondition::Eq),
+            "$ne" => serde_json::from_value(value).map(PermissionCondition::Ne),
+            "$in" => serde_json::from_value(value).map(PermissionCondition::In),
+            "$glob" => serde_json::from_value(value).map(PermissionCondition::Glob),
+            other => return Err(format!("unsupported condition operator {other}")),
+        };
+
+        condition.map_err(|error| format!("invalid {operator} condition: {error}"))
+    }
+}
+
+/// The conditions a resource has to meet for a rule to apply to it. Each field's conditions must all hold, and
+/// fields without conditions match anything.
+///
+/// Fields:
+/// - other: conditions on fields this crate doesn't model (e.g. secretTags), kept as-is
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PermissionConditions {
+    pub environment: Vec<PermissionCondition>,
+    pub secret_path: Vec<PermissionCondition>,
+    pub secret_name: Vec<PermissionCondition>,
+    pub other: BTreeMap<String, Value>,
+}
+
+impl PermissionConditions {
+    pub fn is_empty(&self) -> bool {
+        self.environment.is_empty()
+            && self.secret_path.is_empty()
+            && self.secret_name.is_empty()
+            && self.other.is_empty()
+    }
+}
+
+const ENVIRONMENT_FIELD: &str = "environment";
+const SECRET_PATH_FIELD: &str = "secretPath";
+const SECRET_NAME_FIELD: &str = "secretName";
+
+impl Serialize for PermissionConditions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut document = Map::new();
+
+        for (field, conditions) in [
+            (ENVIRONMENT_FIELD, &self.environment),
+            (SECRET_PATH_FIELD, &self.secret_path),
+            (SECRET_NAME_FIELD, &self.secret_name),
+        ] {
+            if !conditions.is_empty() {
+                let mut operators = Map::new();
+
+                // a field holds each operator once, a second one would silently replace the first
+                for condition in conditions {
+                    if operators
+                        .insert(condition.operator().to_string(), condition.value())
+                        .is_some()
+                    {
+                        return Err(S::Error::custom(format!(
+                            "more than one {} condition on {field}",
+                            condition.operator()
+                        )));
+                    }
+                }
+
+                document.insert(field.to_string(), Value::Object(operators));
+            }
+        }
+
+        for (field, conditions) in &self.other {
+            document.insert(field.clone(), conditions.clone());
+        }
+
+        document.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionConditions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let document = BTreeMap::<String, Value>::deserialize(deserializer)?;
+        let mut conditions = PermissionConditions::default();
+
+        for (field, value) in document {
+            let target = match field.as_str() {
+                ENVIRONMENT_FIELD => &mut conditions.environment,
+                SECRET_PATH_FIELD => &mut conditions.secret_path,
+                SECRET_NAME_FIELD => &mut conditions.secret_name,
+                _ => {
+                    conditions.other.insert(field, value);
+                    continue;
+                }
+            };
+
+            match value {
+                // a bare value is shorthand for $eq
+                Value::String(value) => target.push(PermissionCondition::Eq(value)),
+                Value::Object(operators) => {
+                    for (operator, value) in operators {
+                        target.push(
+                            PermissionCondition::parse(&operator, value)
+                                .map_err(D::Error::custom)?,
+                        );
+                    }
+                }
+                other => {
+                    return Err(D::Error::custom(format!(
+                        "invalid condition on {field}: {other}"
+                    )));
+                }
+            }
+        }
+
+        Ok(conditions)
+    }
+}
+
+/// A single project permission rule: the actions it allows on a subject, optionally only for resources meeting
+/// its conditions. Inverted rules forbid those actions instead, and take precedence over the rules allowing them.
+///
+/// # Example
+/// ```
+/// use infisical_rs::infisical::project_roles::permissions::{
+///     PermissionAction, PermissionRule, PermissionSubject,
+/// };
+///
+/// // read-only access to everything below /apps in production
+/// let rule = PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read])
+///     .in_environment("prod")
+///     .at_secret_path_glob("/apps/**");
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PermissionRule {
+    pub subject: PermissionSubject,
+    #[serde(rename = "action", deserialize_with = "deserialize_actions")]
+    pub actions: Vec<PermissionAction>,
+    #[serde(default, skip_serializing_if = "PermissionConditions::is_empty")]
+    pub conditions: PermissionConditions,
+    #[serde(default)]
+    pub inverted: bool,
+}
+
+impl PermissionRule {
+    pub fn allow(subject: PermissionSubject, actions: &[PermissionAction]) -> Self {
+        Self {
+            subject,
+            actions: actions.to_vec(),
+            conditions: PermissionConditions::default(),
+            inverted: false,
+        }
+    }
+
+    pub fn deny(subject: PermissionSubject, actions: &[PermissionAction]) -> Self {
+        Self {
+            inverted: true,
+            ..Self::allow(subject, actions)
+        }
+    }
+
+    /// Only applies the rule in the environment with the given slug.
+    ///
+    /// Note: a field can only hold one condition per operator, so calling this twice makes the rule fail to
+    /// serialize. Use PermissionCondition::In for several environments.
+    pub fn in_environment(mut self, environment: &str) -> Self {
+        self.conditions
+            .environment
+            .push(PermissionCondition::Eq(environment.to_string()));
+        self
+    }
+
+    /// Only applies the rule at secret paths matching the given glob. Like in_environment(), only once per rule.
+    pub fn at_secret_path_glob(mut self, secret_path_glob: &str) -> Self {
+        self.conditions
+            .secret_path
+            .push(PermissionCondition::Glob(secret_path_glob.to_string()));
+        self
+    }
+
+    /// Only applies the rule to secrets with names matching the given glob. Like in_environment(), only once per rule.
+    pub fn with_secret_name_glob(mut self, secret_name_glob: &str) -> Self {
+        self.conditions
+            .secret_name
+            .push(PermissionCondition::Glob(secret_name_glob.to_string()));
+        self
+    }
+}
+
+// a single action may be written as a bare string rather than a list
+fn deserialize_actions<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<PermissionAction>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Actions {
+        One(PermissionAction),
+        Many(Vec<PermissionAction>),
+    }
+
+    Ok(match Actions::deserialize(deserializer)? {
+        Actions::One(action) => vec![action],
+        Actions::Many(actions) => actions,
+    })
+}
@@ -0,0 +1,279 @@
+use serde::{Deserialize, Serialize};
+
+use crate::infisical::project_roles::permissions::PermissionRule;
+
+/// A custom project role.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct ProjectRole {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub permissions: Vec<PermissionRule>,
+    pub project_id: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Input for create_project_role().
+///
+/// - slug: how the role is referred to when assigning it, e.g. in ProjectRoleAssignment
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProjectRoleOptions {
+    pub slug: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub permissions: Vec<PermissionRule>,
+}
+
+/// Input for update_project_role(). Any field left as None is left untouched.
+///
+/// Note: permissions replaces the role's rules outright.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProjectRoleOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<PermissionRule>>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectPermissionsResponse {
+    pub data: ProjectPermissionsData,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectPermissionsData {
+    pub permissions: Vec<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectRoleResponse {
+    pub role: ProjectRole,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectRolesResponse {
+    pub roles: Vec<ProjectRole>,
+}
+
+pub mod project_role_util_functions {
+    use serde::de::Error as _;
+    use serde_json::Value;
+
+    use crate::infisical::project_roles::permissions::{
+        PermissionAction, PermissionConditions, PermissionRule, PermissionSubject,
+    };
+
+    pub fn construct_project_roles_endpoint_url(
+        host: &str,
+        project_slug: &str,
+        path: Option<&str>,
+    ) -> String {
+        let is_path = path.map_or_else(|| "".to_string(), |path| format!("/{path}"));
+
+        format!(
+            "{host_url}/api/{version}/workspace/{project_slug}/roles{path}",
+            host_url = host,
+            version = "v1",
+            path = is_path
+        )
+    }
+
+    pub fn construct_project_permissions_endpoint_url(host: &str, project_id: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/workspace/{project_id}/permissions",
+            host_url = host,
+            version = "v1",
+        )
+    }
+
+    /// unpack_permission_rules()
+    ///
+    /// Parses rules in the packed form Infisical returns a member's permissions in: one array per rule, holding its
+    /// comma-joined actions and subjects, then optionally its conditions and whether it's inverted. A rule with several
+    /// subjects is unpacked into one rule per subject.
+    pub fn unpack_permission_rules(
+        packed_rules: &[Value],
+    ) -> Result<Vec<PermissionRule>, serde_json::Error> {
+        let mut rules = Vec::with_capacity(packed_rules.len());
+
+        for packed_rule in packed_rules {
+            let fields = packed_rule.as_array().ok_or_else(|| {
+                serde_json::Error::custom(format!("packed rule isn't an array: {packed_rule}"))
+            })?;
+
+            let field_str = |index: usize| fields.get(index).and_then(Value::as_str).unwrap_or("");
+
+            let actions = field_str(0)
+                .split(',')
+                .filter(|action| !action.is_empty())
+                .map(PermissionAction::from)
+                .collect::<Vec<_>>();
+
+            // missing or falsy trailing fields are trimmed when packing
+            let conditions = match fields.get(2) {
+                Some(conditions @ Value::Object(_)) => {
+                    serde_json::from_value::<PermissionConditions>(conditions.clone())?
+                }
+                _ => PermissionConditions::default(),
+            };
+
+            let inverted = fields.get(3).is_some_and(|inverted| {
+                inverted.as_u64() == Some(1) || inverted.as_bool() == Some(true)
+            });
+
+            for subject in field_str(1)
+                .split(',')
+                .filter(|subject| !subject.is_empty())
+            {
+                rules.push(PermissionRule {
+                    subject: PermissionSubject::from(subject),
+                    actions: actions.clone(),
+                    conditions: conditions.clone(),
+                    inverted,
+                });
+            }
+        }
+
+        Ok(rules)
+    }
+
+    /// matches_glob()
+    ///
+    /// Matches a value (usually a secret path) against a permission condition's glob, the way Infisical does:
+    /// - `*` matches anything within a single path segment, `?` a single character within one
+    /// - `**` matches across segments, and a `/**` segment also matches no segments at all
+    /// - `{a,b}` matches either alternative, `[abc]`/`[a-z]`/`[!a]` a single character from (or not from) a set
+    ///
+    /// Trailing slashes on either side are ignored.
+    pub fn matches_glob(pattern: &str, value: &str) -> bool {
+        let value = trim_trailing_slash(value).chars().collect::<Vec<_>>();
+
+        expand_braces(trim_trailing_slash(pattern))
+            .iter()
+            .any(|pattern| glob_matches(&pattern.chars().collect::<Vec<_>>(), &value))
+    }
+
+    fn trim_trailing_slash(path: &str) -> &str {
+        match path.trim_end_matches('/') {
+            "" if path.starts_with('/') => "/",
+            trimmed => trimmed,
+        }
+    }
+
+    // expands the first (outermost) {a,b} group, recursively, into every pattern it stands for
+    fn expand_braces(pattern: &str) -> Vec<String> {
+        let Some(open) = pattern.find('{') else {
+            return vec![pattern.to_string()];
+        };
+
+        let mut depth = 0;
+        let mut close = None;
+        let mut alternatives = Vec::new();
+        let mut alternative_start = open + 1;
+
+        for (index, character) in pattern
+            .char_indices()
+            .skip_while(|(index, _)| *index <= open)
+        {
+            match character {
+                '{' => depth += 1,
+                '}' if depth > 0 => depth -= 1,
+                '}' => {
+                    alternatives.push(&pattern[alternative_start..index]);
+                    close = Some(index);
+                    break;
+                }
+                ',' if depth == 0 => {
+                    alternatives.push(&pattern[alternative_start..index]);
+                    alternative_start = index + 1;
+                }
+                _ => {}
+            }
+        }
+
+        // an unclosed brace is matched literally
+        let Some(close) = close else {
+            return vec![pattern.to_string()];
+        };
+
+        alternatives
+            .into_iter()
+            .flat_map(|alternative| {
+                expand_braces(&format!(
+                    "{}{alternative}{}",
+                    &pattern[..open],
+                    &pattern[close + 1..]
+                ))
+            })
+            .collect()
+    }
+
+    fn glob_matches(pattern: &[char], value: &[char]) -> bool {
+        match pattern {
+            [] => value.is_empty(),
+            // a "/**" segment, which also matches zero segments
+            ['/', '*', '*', rest @ ..] if rest.is_empty() || rest[0] == '/' => {
+                glob_matches(rest, value)
+                    || (value.first() == Some(&'/')
+                        && (1..=value.len()).any(|end| glob_matches(rest, &value[end..])))
+            }
+            ['*', '*', rest @ ..] => (0..=value.len()).any(|end| glob_matches(rest, &value[end..])),
+            ['*', rest @ ..] => {
+                let segment_end = value.iter().position(|c| *c == '/').unwrap_or(value.len());
+                (0..=segment_end).any(|end| glob_matches(rest, &value[end..]))
+            }
+            ['?', rest @ ..] => {
+                value.first().is_some_and(|c| *c != '/') && glob_matches(rest, &value[1..])
+            }
+            ['[', rest @ ..] => match rest.iter().position(|c| *c == ']') {
+                Some(class_end) if class_end > 0 => {
+                    value
+                        .first()
+                        .is_some_and(|c| *c != '/' && class_matches(&rest[..class_end], *c))
+                        && glob_matches(&rest[class_end + 1..], &value[1..])
+                }
+                _ => value.first() == Some(&'[') && glob_matches(rest, &value[1..]),
+            },
+            ['\\', escaped, rest @ ..] => {
+                value.first() == Some(escaped) && glob_matches(rest, &value[1..])
+            }
+            [literal, rest @ ..] => {
+                value.first() == Some(literal) && glob_matches(rest, &value[1..])
+            }
+        }
+    }
+
+    fn class_matches(class: &[char], character: char) -> bool {
+        let (negated, class) = match class {
+            ['!' | '^', rest @ ..] => (true, rest),
+            _ => (false, class),
+        };
+
+        let mut matched = false;
+        let mut index = 0;
+
+        while index < class.len() {
+            if index + 2 < class.len() && class[index + 1] == '-' {
+                matched |= (class[index]..=class[index + 2]).contains(&character);
+                index += 3;
+            } else {
+                matched |= class[index] == character;
+                index += 1;
+            }
+        }
+
+        matched != negated
+    }
+}
@@ -0,0 +1,561 @@
+/// Projects:
+///
+/// Listing and management of Infisical projects (referred to as workspaces throughout the API),
+/// along with their settings and folders.
+use std::collections::HashMap;
+
+use reqwest::StatusCode;
+
+use error_handling::ProjectError;
+use utils::{project_util_functions::construct_project_endpoint_url, *};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    environments::utils::environment_util_functions::validate_environment_slug,
+    utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the projects module, such as the Project and ProjectSettings structs.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Project management
+impl UniversalAuthAccessToken {
+    /// list_projects()
+    ///
+    /// Lists every project the current identity has access to.
+    pub async fn list_projects(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+    ) -> Result<Vec<Project>, ProjectError> {
+        let endpoint_url = construct_project_endpoint_url(host, "v1", None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::ListProjectsError {
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<WorkspacesResponse>(&bytes)?.workspaces)
+    }
+
+    /// get_project()
+    ///
+    /// Retrieves a single project by its id, including its environments.
+    pub async fn get_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+    ) -> Result<Project, ProjectError> {
+        let endpoint_url = construct_project_endpoint_url(host, "v1", Some(workspace_id));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::GetProjectError {
+                project: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<WorkspaceResponse>(&bytes)?.workspace)
+    }
+
+    /// get_project_by_slug()
+    ///
+    /// Retrieves a single project by its slug, including its environments.
+    pub async fn get_project_by_slug(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+    ) -> Result<Project, ProjectError> {
+        let endpoint_url = format!(
+            "{}/slug/{project_slug}",
+            construct_project_endpoint_url(host, "v2", None)
+        );
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::GetProjectError {
+                project: project_slug.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<Project>(&bytes)?)
+    }
+
+    /// create_project()
+    ///
+    /// Creates a new project in the current identity's organization.
+    ///
+    /// Note: the identity creating the project is made an admin of said project.
+    pub async fn create_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateProjectOptions,
+    ) -> Result<Project, ProjectError> {
+        let endpoint_url = construct_project_endpoint_url(host, "v2", None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::CreateProjectError {
+                project_name: options.project_name.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectResponse>(&bytes)?.project)
+    }
+
+    /// update_project()
+    ///
+    /// Updates the name, description, and/or auto-capitalization setting of a project by its id.
+    pub async fn update_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        options: &UpdateProjectOptions,
+    ) -> Result<Project, ProjectError> {
+        let endpoint_url = construct_project_endpoint_url(host, "v1", Some(workspace_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::UpdateProjectError {
+                project: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<WorkspaceResponse>(&bytes)?.workspace)
+    }
+
+    /// update_project_by_slug()
+    ///
+    /// Same as update_project(), but looks the project up by its slug instead.
+    pub async fn update_project_by_slug(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+        options: &UpdateProjectOptions,
+    ) -> Result<Project, ProjectError> {
+        let endpoint_url = construct_project_endpoint_url(host, "v2", Some(project_slug));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::UpdateProjectError {
+                project: project_slug.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<Project>(&bytes)?)
+    }
+
+    /// delete_project()
+    ///
+    /// Deletes a project by its id, along with every environment, folder, and secret in it.
+    /// Returns the deleted project.
+    pub async fn delete_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+    ) -> Result<Project, ProjectError> {
+        let endpoint_url = construct_project_endpoint_url(host, "v1", Some(workspace_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::DeleteProjectError {
+                project: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<WorkspaceResponse>(&bytes)?.workspace)
+    }
+
+    /// delete_project_by_slug()
+    ///
+    /// Same as delete_project(), but looks the project up by its slug instead.
+    pub async fn delete_project_by_slug(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_slug: &str,
+    ) -> Result<Project, ProjectError> {
+        let endpoint_url = construct_project_endpoint_url(host, "v2", Some(project_slug));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::DeleteProjectError {
+                project: project_slug.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<Project>(&bytes)?)
+    }
+
+    // the project bot is what decides whether a project is end-to-end encrypted or not:
+    // an active bot holds a copy of the project key and lets Infisical read secrets server-side
+    async fn get_project_bot(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+    ) -> Result<ProjectBot, ProjectError> {
+        let endpoint_url = format!("{host}/api/v1/bot/{workspace_id}");
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::GetProjectError {
+                project: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectBotResponse>(&bytes)?.bot)
+    }
+
+    /// get_project_settings()
+    ///
+    /// Retrieves a project's auto-capitalization, end-to-end encryption, and audit log retention settings.
+    pub async fn get_project_settings(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+    ) -> Result<ProjectSettings, ProjectError> {
+        let project = self.get_project(host, client, workspace_id).await?;
+        let bot = self.get_project_bot(host, client, workspace_id).await?;
+
+        Ok(ProjectSettings {
+            auto_capitalization: project.auto_capitalization,
+            e2ee: !bot.is_active,
+            audit_logs_retention_days: project.audit_logs_retention_days,
+        })
+    }
+
+    /// update_project_settings()
+    ///
+    /// Updates any of a project's auto-capitalization, end-to-end encryption, and audit log retention settings,
+    /// and returns the project's settings afterwards.
+    ///
+    /// Notes:
+    ///     - each setting has its own endpoint, so settings are applied one after the other and a failure
+    ///       part-way through can leave earlier settings applied.
+    ///     - end-to-end encryption can only be turned on from here. Turning it back off requires re-sharing the
+    ///       project key with the project bot (client-side), and returns a DisableE2EEUnsupported error instead.
+    ///     - audit log retention overrides require an Infisical Pro or above plan.
+    pub async fn update_project_settings(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        options: &UpdateProjectSettingsOptions,
+    ) -> Result<ProjectSettings, ProjectError> {
+        let project_url = construct_project_endpoint_url(host, "v1", Some(workspace_id));
+
+        if let Some(auto_capitalization) = options.auto_capitalization {
+            let mut form_data = HashMap::new();
+            form_data.insert("autoCapitalization", auto_capitalization);
+
+            let response = client
+                .post(format!("{project_url}/auto-capitalization"))
+                .bearer_auth(self.access_token())
+                .json(&form_data)
+                .send()
+                .await?;
+
+            // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+            if response.status().ne(&StatusCode::OK) {
+                let error_response = response.json::<ApiResponse>().await?;
+
+                #[cfg(not(feature = "logging_silent"))]
+                println!("error_response: {}", error_response);
+
+                return Err(ProjectError::UpdateProjectSettingsError {
+                    project: workspace_id.to_string(),
+                    setting: "autoCapitalization".to_string(),
+                    error: error_response,
+                });
+            }
+        }
+
+        if let Some(audit_logs_retention_days) = options.audit_logs_retention_days {
+            let mut form_data = HashMap::new();
+            form_data.insert("auditLogsRetentionDays", audit_logs_retention_days);
+
+            let response = client
+                .put(format!("{project_url}/audit-logs-retention"))
+                .bearer_auth(self.access_token())
+                .json(&form_data)
+                .send()
+                .await?;
+
+            // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+            if response.status().ne(&StatusCode::OK) {
+                let error_response = response.json::<ApiResponse>().await?;
+
+                #[cfg(not(feature = "logging_silent"))]
+                println!("error_response: {}", error_response);
+
+                return Err(ProjectError::UpdateProjectSettingsError {
+                    project: workspace_id.to_string(),
+                    setting: "auditLogsRetentionDays".to_string(),
+                    error: error_response,
+                });
+            }
+        }
+
+        if let Some(e2ee) = options.e2ee {
+            let bot = self.get_project_bot(host, client, workspace_id).await?;
+
+            // e2ee on == bot off, so there's only something to do when the two currently agree
+            if e2ee == bot.is_active {
+                if !e2ee {
+                    return Err(ProjectError::DisableE2EEUnsupported {
+                        project: workspace_id.to_string(),
+                    });
+                }
+
+                let mut form_data = HashMap::new();
+                form_data.insert("isActive", false);
+
+                let response = client
+                    .patch(format!(
+                        "{host}/api/v1/bot/{bot_id}/active",
+                        bot_id = bot.id
+                    ))
+                    .bearer_auth(self.access_token())
+                    .json(&form_data)
+                    .send()
+                    .await?;
+
+                // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+                if response.status().ne(&StatusCode::OK) {
+                    let error_response = response.json::<ApiResponse>().await?;
+
+                    #[cfg(not(feature = "logging_silent"))]
+                    println!("error_response: {}", error_response);
+
+                    return Err(ProjectError::UpdateProjectSettingsError {
+                        project: workspace_id.to_string(),
+                        setting: "e2ee".to_string(),
+                        error: error_response,
+                    });
+                }
+            }
+        }
+
+        self.get_project_settings(host, client, workspace_id).await
+    }
+
+    /// list_folders()
+    ///
+    /// Lists the folders directly under `path` (defaults to the root folder, "/") in a given project environment.
+    pub async fn list_folders(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        environment: &str,
+        path: Option<&str>,
+    ) -> Result<Vec<Folder>, ProjectError> {
+        validate_environment_slug(environment)
+            .map_err(|e| ProjectError::EnvironmentError(e.into()))?;
+
+        let path = path.unwrap_or("/");
+        let endpoint_url = format!("{host}/api/v1/folders");
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(&[
+                ("workspaceId", workspace_id),
+                ("environment", environment),
+                ("path", path),
+            ])
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ProjectError::ListFoldersError {
+                project: workspace_id.to_string(),
+                environment: environment.to_string(),
+                path: path.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<FoldersResponse>(&bytes)?.folders)
+    }
+
+    /// get_project_overview()
+    ///
+    /// Retrieves a project, its environments, and the folders at `path` (defaults to "/") in every one of
+    /// said environments, mostly to save on stitching together get_project() and list_folders() by hand.
+    pub async fn get_project_overview(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        path: Option<&str>,
+    ) -> Result<ProjectOverview, ProjectError> {
+        let project = self.get_project(host, client, workspace_id).await?;
+
+        let mut environments = project.environments.clone();
+        environments.sort_by_key(|environment| environment.position);
+
+        let mut folders = HashMap::with_capacity(environments.len());
+        for environment in &environments {
+            let environment_folders = self
+                .list_folders(host, client, workspace_id, &environment.slug, path)
+                .await?;
+            folders.insert(environment.slug.clone(), environment_folders);
+        }
+
+        Ok(ProjectOverview {
+            project,
+            environments,
+            folders,
+        })
+    }
+}
@@ -0,0 +1,86 @@
+use crate::infisical::{
+    environments::error_handling::EnvironmentError, utils::api_utils::ApiResponse,
+};
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum ProjectError {
+    #[error(
+        "Projects::list_projects(): 
+        Err: {error}"
+    )]
+    ListProjectsError { error: ApiResponse },
+
+    #[error(
+        "Projects::get_project(): 
+        Project: {project}
+        Err: {error}"
+    )]
+    GetProjectError { project: String, error: ApiResponse },
+
+    #[error(
+        "Projects::create_project(): 
+        Project name: {project_name}
+        Err: {error}"
+    )]
+    CreateProjectError {
+        project_name: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Projects::update_project(): 
+        Project: {project}
+        Err: {error}"
+    )]
+    UpdateProjectError { project: String, error: ApiResponse },
+
+    #[error(
+        "Projects::delete_project(): 
+        Project: {project}
+        Err: {error}"
+    )]
+    DeleteProjectError { project: String, error: ApiResponse },
+
+    #[error(
+        "Projects::update_project_settings(): 
+        Project: {project}
+        Setting: {setting}
+        Err: {error}"
+    )]
+    UpdateProjectSettingsError {
+        project: String,
+        setting: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Projects::update_project_settings(): 
+        Project: {project}
+        Turning end-to-end encryption off requires re-sharing the project key with the project bot, \
+        which can currently only be done from the Infisical dashboard."
+    )]
+    DisableE2EEUnsupported { project: String },
+
+    #[error(
+        "Projects::list_folders(): 
+        Project: {project}
+        Environment: {environment}
+        Path: {path}
+        Err: {error}"
+    )]
+    ListFoldersError {
+        project: String,
+        environment: String,
+        path: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    EnvironmentError(#[from] EnvironmentError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
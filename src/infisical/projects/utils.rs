@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::infisical::environments::utils::Environment;
+
+/// An Infisical project (referred to as a "workspace" throughout the API).
+///
+/// Fields:
+/// - id: the project's id, i.e. what gets passed around as `workspace_id` in the rest of the crate
+/// - name: the project's display name
+/// - slug: the project's slug
+/// - description: the project's description, if any
+/// - auto_capitalization: whether secret keys are automatically capitalized on creation
+/// - org_id: the id of the organization the project belongs to
+/// - version: the project's version (v3 projects are server-side encrypted only)
+/// - audit_logs_retention_days: the project's audit log retention override, if any
+/// - environments: the project's environments. Only populated by endpoints that return them (e.g. get_project())
+/// - created_at/updated_at: timestamps
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "RawProject")]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub auto_capitalization: bool,
+    pub org_id: String,
+    pub version: u64,
+    pub audit_logs_retention_days: Option<u64>,
+    pub environments: Vec<Environment>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+// the wire format of a project: v2+ endpoints name the organization orgId, while v1 workspace payloads
+// name it organization (and some return both)
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawProject {
+    id: String,
+    name: String,
+    slug: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    auto_capitalization: bool,
+    #[serde(default)]
+    org_id: Option<String>,
+    #[serde(default)]
+    organization: Option<String>,
+    #[serde(default)]
+    version: u64,
+    #[serde(default)]
+    audit_logs_retention_days: Option<u64>,
+    #[serde(default)]
+    environments: Vec<Environment>,
+    #[serde(default)]
+    created_at: String,
+    #[serde(default)]
+    updated_at: String,
+}
+
+impl From<RawProject> for Project {
+    fn from(raw: RawProject) -> Self {
+        Self {
+            id: raw.id,
+            name: raw.name,
+            slug: raw.slug,
+            description: raw.description,
+            auto_capitalization: raw.auto_capitalization,
+            org_id: raw.org_id.or(raw.organization).unwrap_or_default(),
+            version: raw.version,
+            audit_logs_retention_days: raw.audit_logs_retention_days,
+            environments: raw.environments,
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+        }
+    }
+}
+
+/// Request body for create_project().
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateProjectOptions {
+    pub project_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kms_key_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+/// Request body for update_project() and update_project_by_slug(). Any field left as None is left untouched.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct UpdateProjectOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_capitalization: Option<bool>,
+}
+
+/// A project's settings, as returned by get_project_settings().
+///
+/// Fields:
+/// - auto_capitalization: whether secret keys are automatically capitalized on creation
+/// - e2ee: whether the project is end-to-end encrypted (i.e. the project bot is inactive,
+///   and Infisical cannot read the project's secrets server-side)
+/// - audit_logs_retention_days: the project's audit log retention override, if any
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProjectSettings {
+    pub auto_capitalization: bool,
+    pub e2ee: bool,
+    pub audit_logs_retention_days: Option<u64>,
+}
+
+/// Input for update_project_settings(). Any field left as None is left untouched.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct UpdateProjectSettingsOptions {
+    pub auto_capitalization: Option<bool>,
+    pub e2ee: Option<bool>,
+    pub audit_logs_retention_days: Option<u64>,
+}
+
+/// A single folder inside a project environment.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct Folder {
+    pub id: String,
+    pub name: String,
+}
+
+/// Everything needed to get a picture of a project in one go, as returned by get_project_overview().
+///
+/// Fields:
+/// - project: the project itself
+/// - environments: the project's environments, sorted by position
+/// - folders: the folders found at the requested path, keyed by environment slug
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ProjectOverview {
+    pub project: Project,
+    pub environments: Vec<Environment>,
+    pub folders: HashMap<String, Vec<Folder>>,
+}
+
+// wrapper structs for the JSON envelopes Infisical returns projects in
+#[derive(Deserialize)]
+pub(crate) struct WorkspaceResponse {
+    pub workspace: Project,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WorkspacesResponse {
+    pub workspaces: Vec<Project>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectResponse {
+    pub project: Project,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectBot {
+    pub id: String,
+    pub is_active: bool,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectBotResponse {
+    pub bot: ProjectBot,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct FoldersResponse {
+    pub folders: Vec<Folder>,
+}
+
+pub mod project_util_functions {
+    pub fn construct_project_endpoint_url(
+        host: &str,
+        version: &str,
+        workspace_id: Option<&str>,
+    ) -> String {
+        let is_workspace_id =
+            workspace_id.map_or_else(|| "".to_string(), |workspace_id| format!("/{workspace_id}"));
+
+        format!(
+            "{host_url}/api/{version}/workspace{workspace_id}",
+            host_url = host,
+            version = version,
+            workspace_id = is_workspace_id
+        )
+    }
+}
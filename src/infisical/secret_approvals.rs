@@ -0,0 +1,450 @@
+/// Secret Approvals:
+///
+/// Secret approval policies protecting environments from direct writes, and the change requests
+/// opened against them (listing, reviewing, merging and closing them).
+use std::collections::HashMap;
+
+use reqwest::StatusCode;
+
+use error_handling::SecretApprovalError;
+use utils::{
+    secret_approval_util_functions::{
+        construct_secret_approval_policy_endpoint_url, construct_secret_change_request_endpoint_url,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    environments::utils::environment_util_functions::validate_environment_slug,
+    secrets::{error_handling::SecretsError, utils::SecretScope},
+    utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the secret approvals module, such as SecretApprovalPolicy and SecretChangeRequest.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Secret approval policies for a given project (workspace)
+impl UniversalAuthAccessToken {
+    /// list_secret_approval_policies()
+    ///
+    /// Lists every secret approval policy in a given project.
+    pub async fn list_secret_approval_policies(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+    ) -> Result<Vec<SecretApprovalPolicy>, SecretApprovalError> {
+        let endpoint_url = construct_secret_approval_policy_endpoint_url(host, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .query(&[("workspaceId", workspace_id)])
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretApprovalError::ListPoliciesError {
+                workspace_id: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretApprovalPoliciesResponse>(&bytes)?.approvals)
+    }
+
+    /// create_secret_approval_policy()
+    ///
+    /// Protects an environment (or a secret path glob within it) with an approval policy. Once created, writes to the
+    /// protected scope open change requests instead of being applied directly.
+    pub async fn create_secret_approval_policy(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateSecretApprovalPolicyOptions,
+    ) -> Result<SecretApprovalPolicy, SecretApprovalError> {
+        validate_environment_slug(&options.environment)?;
+
+        let endpoint_url = construct_secret_approval_policy_endpoint_url(host, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretApprovalError::CreatePolicyError {
+                workspace_id: options.workspace_id.clone(),
+                environment: options.environment.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretApprovalPolicyResponse>(&bytes)?.approval)
+    }
+
+    /// update_secret_approval_policy()
+    ///
+    /// Updates a secret approval policy. Any field left as None in `options` is left untouched.
+    pub async fn update_secret_approval_policy(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        policy_id: &str,
+        options: &UpdateSecretApprovalPolicyOptions,
+    ) -> Result<SecretApprovalPolicy, SecretApprovalError> {
+        let endpoint_url = construct_secret_approval_policy_endpoint_url(host, Some(policy_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretApprovalError::UpdatePolicyError {
+                policy_id: policy_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretApprovalPolicyResponse>(&bytes)?.approval)
+    }
+
+    /// delete_secret_approval_policy()
+    ///
+    /// Deletes a secret approval policy. Open change requests under it are left as they are.
+    pub async fn delete_secret_approval_policy(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        policy_id: &str,
+    ) -> Result<SecretApprovalPolicy, SecretApprovalError> {
+        let endpoint_url = construct_secret_approval_policy_endpoint_url(host, Some(policy_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretApprovalError::DeletePolicyError {
+                policy_id: policy_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretApprovalPolicyResponse>(&bytes)?.approval)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Secret change requests for a given project (workspace)
+impl UniversalAuthAccessToken {
+    /// open_secret_change_request()
+    ///
+    /// Submits a set of secret changes to a given scope. If an approval policy protects the scope, Infisical opens a
+    /// change request holding the changes, returned as SecretWriteOutcome::PendingApproval. Otherwise the changes are
+    /// written directly, and returned as SecretWriteOutcome::Applied.
+    pub async fn open_secret_change_request(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        changes: &SecretChanges,
+    ) -> Result<SecretWriteOutcome, SecretApprovalError> {
+        let written_secrets = match changes {
+            SecretChanges::Create(secrets) => {
+                self.create_secrets_batch(host, client, scope, secrets)
+                    .await
+            }
+            SecretChanges::Update(secrets) => {
+                self.update_secrets_batch(host, client, scope, secrets)
+                    .await
+            }
+            SecretChanges::Delete(secret_keys) => {
+                let secret_keys: Vec<&str> = secret_keys.iter().map(String::as_str).collect();
+                self.delete_secrets_batch(host, client, scope, &secret_keys)
+                    .await
+            }
+        };
+
+        match written_secrets {
+            Ok(secrets) => Ok(SecretWriteOutcome::Applied(secrets)),
+            Err(SecretsError::ChangeRequestOpened { change_request, .. }) => {
+                Ok(SecretWriteOutcome::PendingApproval(change_request))
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// list_secret_change_requests()
+    ///
+    /// Lists the change requests in a given project, optionally filtered by environment, status and committer.
+    pub async fn list_secret_change_requests(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        options: &ListSecretChangeRequestsOptions,
+    ) -> Result<Vec<SecretChangeRequest>, SecretApprovalError> {
+        let endpoint_url = construct_secret_change_request_endpoint_url(host, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .query(&[("workspaceId", workspace_id)])
+            .query(options)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretApprovalError::ListChangeRequestsError {
+                workspace_id: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretChangeRequestsResponse>(&bytes)?.approvals)
+    }
+
+    /// get_secret_change_request()
+    ///
+    /// Retrieves a single change request by its id, including its commits and reviews.
+    pub async fn get_secret_change_request(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        change_request_id: &str,
+    ) -> Result<SecretChangeRequest, SecretApprovalError> {
+        let endpoint_url =
+            construct_secret_change_request_endpoint_url(host, Some(change_request_id));
+
+        self.send_change_request_action(
+            client.get(&endpoint_url),
+            "get_secret_change_request",
+            change_request_id,
+        )
+        .await
+    }
+
+    /// review_secret_change_request()
+    ///
+    /// Approves or rejects a change request as the current identity, with an optional comment.
+    pub async fn review_secret_change_request(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        change_request_id: &str,
+        status: SecretChangeRequestReviewStatus,
+        comment: Option<&str>,
+    ) -> Result<SecretChangeRequestReview, SecretApprovalError> {
+        let endpoint_url = construct_secret_change_request_endpoint_url(
+            host,
+            Some(&format!("{change_request_id}/review")),
+        );
+
+        let mut form_data = HashMap::new();
+        form_data.insert("status", status.to_string());
+        if let Some(comment) = comment {
+            form_data.insert("comment", comment.to_string());
+        }
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&form_data)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretApprovalError::ChangeRequestError {
+                operation: "review_secret_change_request".to_string(),
+                change_request_id: change_request_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretChangeRequestReviewResponse>(&bytes)?.review)
+    }
+
+    /// approve_secret_change_request()
+    ///
+    /// Convenience wrapper around review_secret_change_request().
+    pub async fn approve_secret_change_request(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        change_request_id: &str,
+        comment: Option<&str>,
+    ) -> Result<SecretChangeRequestReview, SecretApprovalError> {
+        self.review_secret_change_request(
+            host,
+            client,
+            change_request_id,
+            SecretChangeRequestReviewStatus::Approved,
+            comment,
+        )
+        .await
+    }
+
+    /// reject_secret_change_request()
+    ///
+    /// Convenience wrapper around review_secret_change_request().
+    pub async fn reject_secret_change_request(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        change_request_id: &str,
+        comment: Option<&str>,
+    ) -> Result<SecretChangeRequestReview, SecretApprovalError> {
+        self.review_secret_change_request(
+            host,
+            client,
+            change_request_id,
+            SecretChangeRequestReviewStatus::Rejected,
+            comment,
+        )
+        .await
+    }
+
+    /// merge_secret_change_request()
+    ///
+    /// Applies an approved change request's changes. Under a soft-enforced policy, privileged members can merge
+    /// before the required approvals are in by giving a `bypass_reason`.
+    pub async fn merge_secret_change_request(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        change_request_id: &str,
+        bypass_reason: Option<&str>,
+    ) -> Result<SecretChangeRequest, SecretApprovalError> {
+        let endpoint_url = construct_secret_change_request_endpoint_url(
+            host,
+            Some(&format!("{change_request_id}/merge")),
+        );
+
+        let mut form_data = HashMap::new();
+        if let Some(bypass_reason) = bypass_reason {
+            form_data.insert("bypassReason", bypass_reason);
+        }
+
+        self.send_change_request_action(
+            client.post(&endpoint_url).json(&form_data),
+            "merge_secret_change_request",
+            change_request_id,
+        )
+        .await
+    }
+
+    /// set_secret_change_request_status()
+    ///
+    /// Closes (SecretChangeRequestStatus::Close) or reopens (SecretChangeRequestStatus::Open) a change request
+    /// without merging it.
+    pub async fn set_secret_change_request_status(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        change_request_id: &str,
+        status: SecretChangeRequestStatus,
+    ) -> Result<SecretChangeRequest, SecretApprovalError> {
+        let endpoint_url = construct_secret_change_request_endpoint_url(
+            host,
+            Some(&format!("{change_request_id}/status")),
+        );
+
+        let mut form_data = HashMap::new();
+        form_data.insert("status", status);
+
+        self.send_change_request_action(
+            client.post(&endpoint_url).json(&form_data),
+            "set_secret_change_request_status",
+            change_request_id,
+        )
+        .await
+    }
+
+    // every endpoint acting on a single change request responds with the change request itself
+    async fn send_change_request_action(
+        &self,
+        request: reqwest::RequestBuilder,
+        operation: &str,
+        change_request_id: &str,
+    ) -> Result<SecretChangeRequest, SecretApprovalError> {
+        let response = request.bearer_auth(self.access_token()).send().await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretApprovalError::ChangeRequestError {
+                operation: operation.to_string(),
+                change_request_id: change_request_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretChangeRequestResponse>(&bytes)?.approval)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::infisical::{
+    environments::error_handling::EnvironmentSlugError, secrets::error_handling::SecretsError,
+    utils::api_utils::ApiResponse,
+};
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum SecretApprovalError {
+    #[error(
+        "SecretApprovals::list_secret_approval_policies(): 
+        Workspace ID: {workspace_id}
+        Err: {error}"
+    )]
+    ListPoliciesError {
+        workspace_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretApprovals::create_secret_approval_policy(): 
+        Workspace ID: {workspace_id}
+        Environment: {environment}
+        Err: {error}"
+    )]
+    CreatePolicyError {
+        workspace_id: String,
+        environment: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretApprovals::update_secret_approval_policy(): 
+        Policy ID: {policy_id}
+        Err: {error}"
+    )]
+    UpdatePolicyError {
+        policy_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretApprovals::delete_secret_approval_policy(): 
+        Policy ID: {policy_id}
+        Err: {error}"
+    )]
+    DeletePolicyError {
+        policy_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretApprovals::list_secret_change_requests(): 
+        Workspace ID: {workspace_id}
+        Err: {error}"
+    )]
+    ListChangeRequestsError {
+        workspace_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretApprovals::{operation}(): 
+        Change request ID: {change_request_id}
+        Err: {error}"
+    )]
+    ChangeRequestError {
+        operation: String,
+        change_request_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SecretsError(#[from] SecretsError),
+    #[error(transparent)]
+    InvalidEnvironmentSlug(#[from] EnvironmentSlugError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
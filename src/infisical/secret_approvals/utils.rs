@@ -0,0 +1,289 @@
+use serde::{Deserialize, Serialize};
+
+use crate::infisical::{
+    environments::utils::Environment,
+    secrets::utils::{BatchCreateSecret, BatchUpdateSecret, InfisicalSecret},
+};
+
+/// Whether a policy's approvals are mandatory (hard), or can be bypassed by privileged members with a reason (soft).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ApprovalEnforcementLevel {
+    #[default]
+    Hard,
+    Soft,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ApproverType {
+    User,
+    Group,
+}
+
+/// A user or group allowed to approve changes under a policy.
+///
+/// Users can be referenced either by id or by username (`name`), groups by id only.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SecretApprover {
+    #[serde(rename = "type")]
+    pub type_: ApproverType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl SecretApprover {
+    pub fn user(username: &str) -> Self {
+        Self {
+            type_: ApproverType::User,
+            id: None,
+            name: Some(username.to_string()),
+        }
+    }
+
+    pub fn group(group_id: &str) -> Self {
+        Self {
+            type_: ApproverType::Group,
+            id: Some(group_id.to_string()),
+            name: None,
+        }
+    }
+}
+
+/// A secret approval policy, protecting an environment (and optionally a secret path glob) from direct writes.
+///
+/// Fields:
+/// - approvals: how many approvers need to approve a change request before it can be merged
+/// - secret_path: the secret path (glob) the policy applies to, None for the whole environment
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SecretApprovalPolicy {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    pub approvals: u64,
+    #[serde(default)]
+    pub secret_path: Option<String>,
+    #[serde(default)]
+    pub enforcement_level: ApprovalEnforcementLevel,
+    #[serde(default)]
+    pub environment: Option<Environment>,
+    #[serde(default)]
+    pub approvers: Vec<SecretApprover>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// Request body for create_secret_approval_policy().
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateSecretApprovalPolicyOptions {
+    pub workspace_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub environment: String,
+    pub secret_path: Option<String>,
+    pub approvers: Vec<SecretApprover>,
+    pub approvals: u64,
+    pub enforcement_level: ApprovalEnforcementLevel,
+}
+
+/// Request body for update_secret_approval_policy(). Any field left as None is left untouched by Infisical.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct UpdateSecretApprovalPolicyOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approvers: Option<Vec<SecretApprover>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approvals: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforcement_level: Option<ApprovalEnforcementLevel>,
+}
+
+/// Infisical calls a closed change request "close".
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SecretChangeRequestStatus {
+    Open,
+    Close,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SecretChangeRequestReviewStatus {
+    Approved,
+    Rejected,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SecretChangeRequestReviewer {
+    pub user_id: String,
+    pub status: SecretChangeRequestReviewStatus,
+}
+
+/// A review left on a change request, as returned by review_secret_change_request().
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SecretChangeRequestReview {
+    pub id: String,
+    pub status: SecretChangeRequestReviewStatus,
+    #[serde(default)]
+    pub reviewer_user_id: Option<String>,
+}
+
+/// A secret change request (Infisical's "secret approval request"): a set of secret changes to a protected
+/// environment, waiting for approval before being merged.
+///
+/// Fields:
+/// - slug: the change request's human readable title
+/// - status/has_merged: an open request can be merged once approved; merged requests are closed
+/// - reviewers: the reviews left on the request so far
+/// - commits: the individual secret changes, as returned by Infisical
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SecretChangeRequest {
+    pub id: String,
+    #[serde(default)]
+    pub slug: String,
+    pub status: SecretChangeRequestStatus,
+    #[serde(default)]
+    pub has_merged: bool,
+    #[serde(default)]
+    pub policy_id: Option<String>,
+    #[serde(default)]
+    pub committer_user_id: Option<String>,
+    #[serde(default)]
+    pub environment: Option<String>,
+    #[serde(default)]
+    pub secret_path: Option<String>,
+    #[serde(default)]
+    pub reviewers: Vec<SecretChangeRequestReviewer>,
+    #[serde(default)]
+    pub commits: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+impl SecretChangeRequest {
+    pub fn approvals(&self) -> usize {
+        self.reviewers
+            .iter()
+            .filter(|reviewer| reviewer.status == SecretChangeRequestReviewStatus::Approved)
+            .count()
+    }
+
+    pub fn is_rejected(&self) -> bool {
+        self.reviewers
+            .iter()
+            .any(|reviewer| reviewer.status == SecretChangeRequestReviewStatus::Rejected)
+    }
+}
+
+/// Query options for list_secret_change_requests(). Any field left as None is not filtered on.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct ListSecretChangeRequestsOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SecretChangeRequestStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+}
+
+/// The changes a change request is opened with. Infisical opens one change request per batch write,
+/// so a single change request holds changes of a single kind.
+#[derive(Clone, Debug)]
+pub enum SecretChanges {
+    Create(Vec<BatchCreateSecret>),
+    Update(Vec<BatchUpdateSecret>),
+    Delete(Vec<String>),
+}
+
+/// What happened to a set of secret changes: either no approval policy applied and they were written directly,
+/// or they are now waiting for approval in a change request.
+pub enum SecretWriteOutcome {
+    Applied(Vec<InfisicalSecret>),
+    PendingApproval(Box<SecretChangeRequest>),
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SecretApprovalPolicyResponse {
+    pub approval: SecretApprovalPolicy,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SecretApprovalPoliciesResponse {
+    pub approvals: Vec<SecretApprovalPolicy>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SecretChangeRequestResponse {
+    pub approval: SecretChangeRequest,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SecretChangeRequestsResponse {
+    pub approvals: Vec<SecretChangeRequest>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SecretChangeRequestReviewResponse {
+    pub review: SecretChangeRequestReview,
+}
+
+pub mod secret_approval_util_functions {
+    use crate::infisical::utils::api_utils::ApiResponse;
+
+    pub fn construct_secret_approval_policy_endpoint_url(host: &str, path: Option<&str>) -> String {
+        let is_path = path.map_or_else(|| "".to_string(), |path| format!("/{path}"));
+
+        format!(
+            "{host_url}/api/{version}/secret-approvals{path}",
+            host_url = host,
+            version = "v1",
+            path = is_path
+        )
+    }
+
+    pub fn construct_secret_change_request_endpoint_url(host: &str, path: Option<&str>) -> String {
+        let is_path = path.map_or_else(|| "".to_string(), |path| format!("/{path}"));
+
+        format!(
+            "{host_url}/api/{version}/secret-approval-requests{path}",
+            host_url = host,
+            version = "v1",
+            path = is_path
+        )
+    }
+
+    /// is_approval_required()
+    ///
+    /// Whether an error response is Infisical refusing a direct write because an approval policy protects the
+    /// environment/path, rather than the identity lacking permissions altogether.
+    pub fn is_approval_required(error: &ApiResponse) -> bool {
+        error.status_code() == Some(403)
+            && error
+                .message()
+                .is_some_and(|message| message.to_lowercase().contains("approval"))
+    }
+}
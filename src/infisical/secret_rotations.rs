@@ -0,0 +1,238 @@
+/// Secret Rotations:
+///
+/// Configuration of Infisical's automatic secret rotations, e.g. rotating a database user's password every week
+/// and writing the new credentials back into a project's secrets.
+use std::collections::HashMap;
+
+use reqwest::StatusCode;
+
+use error_handling::SecretRotationError;
+use utils::{
+    secret_rotation_util_functions::{
+        construct_secret_rotation_endpoint_url, construct_secret_rotation_providers_endpoint_url,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    environments::utils::environment_util_functions::validate_environment_slug,
+    utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the secret rotations module, such as SecretRotation and SecretRotationStatus.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Secret rotation management for a given project (workspace)
+impl UniversalAuthAccessToken {
+    /// list_secret_rotation_providers()
+    ///
+    /// Lists the rotation providers available to a given project, along with the inputs/outputs each one expects.
+    pub async fn list_secret_rotation_providers(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+    ) -> Result<Vec<SecretRotationProvider>, SecretRotationError> {
+        let endpoint_url = construct_secret_rotation_providers_endpoint_url(host, workspace_id);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretRotationError::ListProvidersError {
+                workspace_id: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretRotationProvidersResponse>(&bytes)?.providers)
+    }
+
+    /// list_secret_rotations()
+    ///
+    /// Lists every secret rotation configured in a given project, including each one's last run status.
+    pub async fn list_secret_rotations(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+    ) -> Result<Vec<SecretRotation>, SecretRotationError> {
+        let endpoint_url = construct_secret_rotation_endpoint_url(host, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .query(&[("workspaceId", workspace_id)])
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretRotationError::ListSecretRotationsError {
+                workspace_id: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretRotationsResponse>(&bytes)?.secret_rotations)
+    }
+
+    /// get_secret_rotation()
+    ///
+    /// Retrieves a single secret rotation by its id. Infisical has no endpoint for this, so the project's
+    /// rotations are listed and filtered. Call status() on the result for its last run's outcome and error.
+    pub async fn get_secret_rotation(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        rotation_id: &str,
+    ) -> Result<SecretRotation, SecretRotationError> {
+        self.list_secret_rotations(host, client, workspace_id)
+            .await?
+            .into_iter()
+            .find(|rotation| rotation.id == rotation_id)
+            .ok_or_else(|| SecretRotationError::SecretRotationNotFound {
+                workspace_id: workspace_id.to_string(),
+                rotation_id: rotation_id.to_string(),
+            })
+    }
+
+    /// create_secret_rotation()
+    ///
+    /// Creates a new secret rotation. The secrets referenced in `outputs` need to exist beforehand, and the first
+    /// rotation is run by Infisical right away.
+    pub async fn create_secret_rotation(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateSecretRotationOptions,
+    ) -> Result<SecretRotation, SecretRotationError> {
+        validate_environment_slug(&options.environment)?;
+
+        let endpoint_url = construct_secret_rotation_endpoint_url(host, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretRotationError::CreateSecretRotationError {
+                workspace_id: options.workspace_id.clone(),
+                environment: options.environment.clone(),
+                secret_path: options.secret_path.clone(),
+                provider: options.provider.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretRotationResponse>(&bytes)?.secret_rotation)
+    }
+
+    /// rotate_secrets_now()
+    ///
+    /// Triggers an immediate rotation, outside of the rotation's regular interval.
+    pub async fn rotate_secrets_now(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        rotation_id: &str,
+    ) -> Result<SecretRotation, SecretRotationError> {
+        let endpoint_url = construct_secret_rotation_endpoint_url(host, Some("restart"));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("id", rotation_id);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&form_data)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretRotationError::RotateSecretsError {
+                rotation_id: rotation_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretRotationResponse>(&bytes)?.secret_rotation)
+    }
+
+    /// delete_secret_rotation()
+    ///
+    /// Deletes a secret rotation. The secrets it wrote into are left as they are.
+    pub async fn delete_secret_rotation(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        rotation_id: &str,
+    ) -> Result<SecretRotation, SecretRotationError> {
+        let endpoint_url = construct_secret_rotation_endpoint_url(host, Some(rotation_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretRotationError::DeleteSecretRotationError {
+                rotation_id: rotation_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SecretRotationResponse>(&bytes)?.secret_rotation)
+    }
+}
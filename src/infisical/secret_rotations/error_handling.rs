@@ -0,0 +1,82 @@
+use crate::infisical::{
+    environments::error_handling::EnvironmentSlugError, utils::api_utils::ApiResponse,
+};
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum SecretRotationError {
+    #[error(
+        "SecretRotations::list_secret_rotation_providers(): 
+        Workspace ID: {workspace_id}
+        Err: {error}"
+    )]
+    ListProvidersError {
+        workspace_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretRotations::list_secret_rotations(): 
+        Workspace ID: {workspace_id}
+        Err: {error}"
+    )]
+    ListSecretRotationsError {
+        workspace_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretRotations::get_secret_rotation(): 
+        Workspace ID: {workspace_id}
+        Rotation ID: {rotation_id}
+        Err: no such secret rotation"
+    )]
+    SecretRotationNotFound {
+        workspace_id: String,
+        rotation_id: String,
+    },
+
+    #[error(
+        "SecretRotations::create_secret_rotation(): 
+        Workspace ID: {workspace_id}
+        Environment: {environment}
+        Secret path: {secret_path}
+        Provider: {provider}
+        Err: {error}"
+    )]
+    CreateSecretRotationError {
+        workspace_id: String,
+        environment: String,
+        secret_path: String,
+        provider: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretRotations::rotate_secrets_now(): 
+        Rotation ID: {rotation_id}
+        Err: {error}"
+    )]
+    RotateSecretsError {
+        rotation_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretRotations::delete_secret_rotation(): 
+        Rotation ID: {rotation_id}
+        Err: {error}"
+    )]
+    DeleteSecretRotationError {
+        rotation_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    InvalidEnvironmentSlug(#[from] EnvironmentSlugError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A secret rotation provider, i.e. a system Infisical knows how to rotate credentials for
+/// (e.g. "postgres", "mysql", "sendgrid", "aws-iam").
+///
+/// Fields:
+/// - name: the provider's name, what you'd pass in as `provider` to create_secret_rotation()
+/// - title/description/image: display information from the Infisical dashboard
+/// - template: the provider's input/output template, describing which inputs the provider requires and which
+///   outputs (e.g. db_username, db_password) it writes back into secrets
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SecretRotationProvider {
+    pub name: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub image: Option<String>,
+    #[serde(default)]
+    pub template: serde_json::Value,
+}
+
+/// The outcome of a secret rotation's last run.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SecretRotationState {
+    Success,
+    Failed,
+    /// anything newer Infisical versions might report
+    #[serde(other)]
+    Unknown,
+}
+
+/// The environment a secret rotation writes its outputs into.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SecretRotationEnvironment {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+}
+
+/// A secret a rotation writes one of its outputs into.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SecretRotationOutputSecret {
+    pub id: String,
+    pub secret_key: String,
+    #[serde(default)]
+    pub version: u64,
+}
+
+/// Maps a rotation provider's output (e.g. "db_password") to the secret it gets written into.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SecretRotationOutput {
+    pub key: String,
+    pub secret: SecretRotationOutputSecret,
+}
+
+/// A secret rotation configuration.
+///
+/// Fields:
+/// - id: the rotation's id, used when triggering or deleting it
+/// - interval: how often the rotation runs, in days
+/// - provider: the rotation provider's name
+/// - environment/secret_path: where the rotated secrets live
+/// - outputs: which secrets each of the provider's outputs get written into
+/// - last_rotated_at/status/status_message: the last run's timestamp, outcome and error message (if any),
+///   see status()
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SecretRotation {
+    pub id: String,
+    pub interval: u64,
+    pub provider: String,
+    pub environment: SecretRotationEnvironment,
+    pub secret_path: String,
+    #[serde(default)]
+    pub outputs: Vec<SecretRotationOutput>,
+    #[serde(default)]
+    pub last_rotated_at: Option<String>,
+    #[serde(default)]
+    pub status: Option<SecretRotationState>,
+    #[serde(default)]
+    pub status_message: Option<String>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// A secret rotation's last run, as returned by SecretRotation::status().
+///
+/// - state: None if the rotation hasn't run yet
+/// - last_error: the last run's error message, only set if the last run failed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretRotationStatus {
+    pub last_rotated_at: Option<String>,
+    pub state: Option<SecretRotationState>,
+    pub last_error: Option<String>,
+}
+
+impl SecretRotation {
+    pub fn status(&self) -> SecretRotationStatus {
+        let last_error = match self.status {
+            Some(SecretRotationState::Failed) => self.status_message.clone(),
+            _ => None,
+        };
+
+        SecretRotationStatus {
+            last_rotated_at: self.last_rotated_at.clone(),
+            state: self.status,
+            last_error,
+        }
+    }
+
+    pub fn has_failed(&self) -> bool {
+        self.status == Some(SecretRotationState::Failed)
+    }
+}
+
+/// Request body for create_secret_rotation().
+///
+/// - interval: how often to rotate, in days
+/// - provider: a provider name from list_secret_rotation_providers()
+/// - inputs: the provider's inputs (as described by its template), e.g. the database host and admin credentials
+/// - outputs: maps each of the provider's outputs (e.g. "db_password") to the id of the secret it should be written into
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateSecretRotationOptions {
+    pub workspace_id: String,
+    pub environment: String,
+    pub secret_path: String,
+    pub interval: u64,
+    pub provider: String,
+    pub inputs: serde_json::Value,
+    pub outputs: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SecretRotationProvidersResponse {
+    pub providers: Vec<SecretRotationProvider>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SecretRotationResponse {
+    pub secret_rotation: SecretRotation,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SecretRotationsResponse {
+    pub secret_rotations: Vec<SecretRotation>,
+}
+
+pub mod secret_rotation_util_functions {
+    pub fn construct_secret_rotation_endpoint_url(host: &str, path: Option<&str>) -> String {
+        let is_path = path.map_or_else(|| "".to_string(), |path| format!("/{path}"));
+
+        format!(
+            "{host_url}/api/{version}/secret-rotations{path}",
+            host_url = host,
+            version = "v1",
+            path = is_path
+        )
+    }
+
+    pub fn construct_secret_rotation_providers_endpoint_url(
+        host: &str,
+        workspace_id: &str,
+    ) -> String {
+        format!(
+            "{host_url}/api/{version}/secret-rotation-providers/{workspace_id}",
+            host_url = host,
+            version = "v1",
+        )
+    }
+}
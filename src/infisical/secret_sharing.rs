@@ -0,0 +1,190 @@
+/// Secret Sharing:
+///
+/// One-time secret sharing links: sharing a value with someone outside of Infisical through a link that expires
+/// after a given time and/or number of views, optionally behind a password.
+use std::{collections::HashMap, time::SystemTime};
+
+use reqwest::StatusCode;
+use secrecy::{ExposeSecret, SecretString};
+
+use error_handling::SecretSharingError;
+use utils::{
+    secret_sharing_util_functions::{
+        construct_secret_sharing_endpoint_url, construct_shared_secret_url,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    utils::{api_utils::ApiResponse, time_utils::format_rfc3339_utc},
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the secret sharing module, such as SharedSecret and SharedSecretLink.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Management of the secrets shared by the current identity
+impl UniversalAuthAccessToken {
+    /// create_shared_secret()
+    ///
+    /// Shares a value, returning the link to hand over to whoever needs it.
+    pub async fn create_shared_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        secret_value: &SecretString,
+        options: &CreateSharedSecretOptions,
+    ) -> Result<SharedSecretLink, SecretSharingError> {
+        let endpoint_url = construct_secret_sharing_endpoint_url(host, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&CreateSharedSecretBody {
+                secret_value: secret_value.expose_secret(),
+                expires_at: format_rfc3339_utc(SystemTime::now() + options.expires_in),
+                expires_after_views: options.max_views,
+                password: options
+                    .password
+                    .as_ref()
+                    .map(|password| password.expose_secret()),
+                access_type: options.access_type,
+                name: options.name.as_deref(),
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretSharingError::CreateSharedSecretError {
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        let id = serde_json::from_slice::<CreateSharedSecretResponse>(&bytes)?.id;
+
+        Ok(SharedSecretLink {
+            url: construct_shared_secret_url(host, &id),
+            id,
+        })
+    }
+
+    /// list_shared_secrets()
+    ///
+    /// Lists the secrets shared by the current identity that haven't expired yet, `limit` at a time.
+    pub async fn list_shared_secrets(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<SharedSecretSummary>, SecretSharingError> {
+        let endpoint_url = construct_secret_sharing_endpoint_url(host, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .query(&[("offset", offset), ("limit", limit)])
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretSharingError::ListSharedSecretsError {
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SharedSecretsResponse>(&bytes)?.secrets)
+    }
+
+    /// delete_shared_secret()
+    ///
+    /// Deletes a shared secret, invalidating its link right away.
+    pub async fn delete_shared_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        shared_secret_id: &str,
+    ) -> Result<(), SecretSharingError> {
+        let endpoint_url = construct_secret_sharing_endpoint_url(host, Some(shared_secret_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretSharingError::DeleteSharedSecretError {
+                shared_secret_id: shared_secret_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// get_shared_secret()
+///
+/// Opens a shared secret by its id, the way its link would, so no access token is needed. Secrets shared with
+/// SharedSecretAccessType::Organization can only be opened by signed-in organization members, through the link.
+///
+/// Each successful call counts as a view.
+pub async fn get_shared_secret(
+    host: &str,
+    client: &reqwest::Client,
+    shared_secret_id: &str,
+    password: Option<&SecretString>,
+) -> Result<SharedSecret, SecretSharingError> {
+    let endpoint_url =
+        construct_secret_sharing_endpoint_url(host, Some(&format!("public/{shared_secret_id}")));
+
+    let mut form_data = HashMap::new();
+    if let Some(password) = password {
+        form_data.insert("password", password.expose_secret());
+    }
+
+    let response = client.post(&endpoint_url).json(&form_data).send().await?;
+
+    // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+    if response.status().ne(&StatusCode::OK) {
+        let error_response = response.json::<ApiResponse>().await?;
+
+        #[cfg(not(feature = "logging_silent"))]
+        println!("error_response: {}", error_response);
+
+        return Err(SecretSharingError::GetSharedSecretError {
+            shared_secret_id: shared_secret_id.to_string(),
+            error: error_response,
+        });
+    }
+
+    let bytes = response.bytes().await?;
+
+    Ok(serde_json::from_slice::<SharedSecretResponse>(&bytes)?.secret)
+}
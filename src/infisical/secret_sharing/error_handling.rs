@@ -0,0 +1,43 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum SecretSharingError {
+    #[error(
+        "SecretSharing::create_shared_secret(): 
+        Err: {error}"
+    )]
+    CreateSharedSecretError { error: ApiResponse },
+
+    #[error(
+        "SecretSharing::list_shared_secrets(): 
+        Err: {error}"
+    )]
+    ListSharedSecretsError { error: ApiResponse },
+
+    #[error(
+        "SecretSharing::get_shared_secret(): 
+        Shared secret ID: {shared_secret_id}
+        Err: {error}"
+    )]
+    GetSharedSecretError {
+        shared_secret_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "SecretSharing::delete_shared_secret(): 
+        Shared secret ID: {shared_secret_id}
+        Err: {error}"
+    )]
+    DeleteSharedSecretError {
+        shared_secret_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
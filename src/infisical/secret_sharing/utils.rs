@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+/// Who can open a shared secret's link: anyone holding it, or only members of the sharer's organization.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SharedSecretAccessType {
+    #[default]
+    Anyone,
+    Organization,
+}
+
+/// Input for create_shared_secret().
+///
+/// - expires_in: how long the link stays valid for
+/// - max_views: how many times the secret can be viewed before it's deleted, None for unlimited
+/// - password: required to view the secret, if set
+/// - name: a label shown in the sharer's list of shared secrets
+#[derive(Clone, Debug)]
+pub struct CreateSharedSecretOptions {
+    pub expires_in: Duration,
+    pub max_views: Option<u64>,
+    pub password: Option<SecretString>,
+    pub access_type: SharedSecretAccessType,
+    pub name: Option<String>,
+}
+
+impl Default for CreateSharedSecretOptions {
+    /// a link valid for a day, that can be opened exactly once
+    fn default() -> Self {
+        Self {
+            expires_in: Duration::from_secs(24 * 60 * 60),
+            max_views: Some(1),
+            password: None,
+            access_type: SharedSecretAccessType::Anyone,
+            name: None,
+        }
+    }
+}
+
+/// A freshly created shared secret: its id, and the link to hand over.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SharedSecretLink {
+    pub id: String,
+    pub url: String,
+}
+
+/// A shared secret as listed by its sharer. The secret value itself is never listed.
+///
+/// Fields:
+/// - expires_at: when the link stops working, as an RFC 3339 timestamp
+/// - expires_after_views: how many views are left before the secret is deleted, None for unlimited
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SharedSecretSummary {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub expires_at: String,
+    #[serde(default)]
+    pub expires_after_views: Option<u64>,
+    #[serde(default)]
+    pub access_type: SharedSecretAccessType,
+    #[serde(default)]
+    pub created_at: String,
+}
+
+/// A shared secret as opened through its link, see get_shared_secret().
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedSecret {
+    pub secret_value: SecretString,
+    pub expires_at: String,
+    #[serde(default)]
+    pub expires_after_views: Option<u64>,
+    #[serde(default)]
+    pub access_type: SharedSecretAccessType,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub org_name: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateSharedSecretBody<'a> {
+    pub secret_value: &'a str,
+    pub expires_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after_views: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<&'a str>,
+    pub access_type: SharedSecretAccessType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CreateSharedSecretResponse {
+    pub id: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SharedSecretResponse {
+    pub secret: SharedSecret,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SharedSecretsResponse {
+    pub secrets: Vec<SharedSecretSummary>,
+}
+
+pub mod secret_sharing_util_functions {
+    pub fn construct_secret_sharing_endpoint_url(host: &str, path: Option<&str>) -> String {
+        let is_path = path.map_or_else(|| "".to_string(), |path| format!("/{path}"));
+
+        format!(
+            "{host_url}/api/{version}/secret-sharing{path}",
+            host_url = host,
+            version = "v1",
+            path = is_path
+        )
+    }
+
+    /// construct_shared_secret_url()
+    ///
+    /// The link a shared secret is opened with in the browser.
+    pub fn construct_shared_secret_url(host: &str, shared_secret_id: &str) -> String {
+        format!(
+            "{host_url}/shared/secret/{shared_secret_id}",
+            host_url = host.trim_end_matches('/'),
+        )
+    }
+}
@@ -0,0 +1,1154 @@
+/// Secrets:
+///
+/// Structs and functions used to retrieve and manage secrets in a given project (workspace).
+use std::collections::HashMap;
+
+use reqwest::{
+    StatusCode,
+    header::{ETAG, IF_NONE_MATCH},
+};
+use secrecy::{ExposeSecret, SecretBox};
+
+use error_handling::SecretsError;
+use utils::{
+    secret_util_functions::{
+        construct_batch_raw_secrets_endpoint_url, construct_raw_secrets_endpoint_url,
+    },
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    environments::utils::environment_util_functions::validate_environment_slug,
+    secret_approvals::utils::secret_approval_util_functions::is_approval_required,
+    tags::utils::CreateTagOptions, utils::api_utils::ApiResponse,
+};
+
+/// Caching listed secrets, invalidated per environment and secret path, e.g. on webhook deliveries.
+pub mod cache;
+/// Diffing the secrets of two scopes, e.g. two environments.
+pub mod diff;
+pub mod error_handling;
+
+/// Promoting secrets from one scope to another, e.g. `staging` to `prod`.
+pub mod promotion;
+
+/// Structs and utility functions used by the secrets module, such as InfisicalSecret and SecretScope.
+pub mod utils;
+
+/// Watching a scope for secret changes, e.g. rotations, as a Stream.
+pub mod watch;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Secret retrieval and management
+impl UniversalAuthAccessToken {
+    /// list_secrets()
+    ///
+    /// Lists the secrets in a given scope (project, environment, and secret path).
+    ///
+    /// Filtering by tags happens server-side: only secrets carrying every slug in `options.tag_slugs` are returned.
+    pub async fn list_secrets(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        options: &ListSecretsOptions,
+    ) -> Result<Vec<InfisicalSecret>, SecretsError> {
+        // without an ETag to match against, Infisical always responds with the secrets
+        Ok(self
+            .send_list_secrets_request(host, client, scope, options, None)
+            .await?
+            .map(|listed| listed.secrets)
+            .unwrap_or_default())
+    }
+
+    // list_secrets(), as a conditional request when given the ETag of a previous listing.
+    // Returns None if the secrets haven't changed since.
+    async fn send_list_secrets_request(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        options: &ListSecretsOptions,
+        if_none_match: Option<&str>,
+    ) -> Result<Option<ListedSecrets>, SecretsError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_raw_secrets_endpoint_url(host, None);
+
+        let mut query = vec![
+            ("workspaceId", scope.workspace_id.clone()),
+            ("environment", scope.environment.clone()),
+            ("secretPath", scope.secret_path.clone()),
+            ("recursive", options.recursive.to_string()),
+            ("include_imports", options.include_imports.to_string()),
+            (
+                "expandSecretReferences",
+                options.expand_secret_references.to_string(),
+            ),
+        ];
+
+        if !options.tag_slugs.is_empty() {
+            query.push(("tagSlugs", options.tag_slugs.join(",")));
+        }
+
+        let mut request = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(&query);
+
+        if let Some(etag) = if_none_match {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        let response = request.send().await?;
+
+        if if_none_match.is_some() && response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretsError::ListSecretsError {
+                workspace_id: scope.workspace_id.clone(),
+                environment: scope.environment.clone(),
+                secret_path: scope.secret_path.clone(),
+                error: error_response,
+            });
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+
+        let bytes = response.bytes().await?;
+
+        let secrets = serde_json::from_slice::<SecretsResponse>(&bytes)?
+            .secrets
+            .into_iter()
+            .map(|secret| InfisicalSecret {
+                data: SecretBox::new(Box::new(secret)),
+            })
+            .collect();
+
+        Ok(Some(ListedSecrets { secrets, etag }))
+    }
+
+    /// get_secret()
+    ///
+    /// Retrieves a single shared secret by its key from a given scope.
+    pub async fn get_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        self.get_secret_of_type(host, client, scope, secret_key, SecretType::Shared)
+            .await
+    }
+
+    /// get_personal_secret()
+    ///
+    /// Retrieves the current user's personal override of a secret, returning a SecretNotFound error
+    /// if there isn't one.
+    ///
+    /// Personal overrides belong to users, so this needs a user's access token. With a machine identity's token,
+    /// Infisical's error is returned as a GetSecretError.
+    pub async fn get_personal_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        self.get_secret_of_type(host, client, scope, secret_key, SecretType::Personal)
+            .await
+    }
+
+    /// resolve_secret()
+    ///
+    /// Retrieves the current user's personal override of a secret if there is one, and the shared secret otherwise.
+    /// This mirrors how the Infisical CLI resolves secret values.
+    ///
+    /// Needs a user's access token, see get_personal_secret(). Only a missing override falls back to the shared
+    /// secret; Infisical's error for a machine identity's token is returned as is.
+    pub async fn resolve_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        match self
+            .get_personal_secret(host, client, scope, secret_key)
+            .await
+        {
+            Err(SecretsError::SecretNotFound { .. }) => {
+                self.get_secret(host, client, scope, secret_key).await
+            }
+            personal_secret => personal_secret,
+        }
+    }
+
+    async fn get_secret_of_type(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        secret_type: SecretType,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_raw_secrets_endpoint_url(host, Some(secret_key));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(&[
+                ("workspaceId", scope.workspace_id.as_str()),
+                ("environment", &scope.environment),
+                ("secretPath", &scope.secret_path),
+                ("type", &secret_type.to_string()),
+            ])
+            .send()
+            .await?;
+
+        // a missing secret gets its own error, mainly so resolve_secret() can fall back to the shared value
+        if response.status().eq(&StatusCode::NOT_FOUND) {
+            return Err(SecretsError::SecretNotFound {
+                secret_key: secret_key.to_string(),
+                secret_type,
+                environment: scope.environment.clone(),
+                secret_path: scope.secret_path.clone(),
+            });
+        }
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretsError::GetSecretError {
+                secret_key: secret_key.to_string(),
+                environment: scope.environment.clone(),
+                secret_path: scope.secret_path.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(InfisicalSecret {
+            data: SecretBox::new(Box::new(
+                serde_json::from_slice::<SecretResponse>(&bytes)?.secret,
+            )),
+        })
+    }
+
+    /// create_secret()
+    ///
+    /// Creates a single secret in a given scope. Set `options.type_` to SecretType::Personal to create
+    /// a personal override of an existing shared secret instead, which needs a user's access token (Infisical
+    /// rejects it for a machine identity's token, returned as a CreateSecretError).
+    pub async fn create_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        options: &CreateSecretOptions,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_raw_secrets_endpoint_url(host, Some(secret_key));
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&SecretRequestBody {
+                workspace_id: &scope.workspace_id,
+                environment: &scope.environment,
+                secret_path: &scope.secret_path,
+                options,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            if is_approval_required(&error_response) {
+                return Err(SecretsError::ApprovalRequired {
+                    operation: "create_secret".to_string(),
+                    environment: scope.environment.clone(),
+                    secret_path: scope.secret_path.clone(),
+                    error: error_response,
+                });
+            }
+
+            return Err(SecretsError::CreateSecretError {
+                secret_key: secret_key.to_string(),
+                environment: scope.environment.clone(),
+                secret_path: scope.secret_path.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        match serde_json::from_slice::<SecretWriteResponse<SecretResponse>>(&bytes)? {
+            SecretWriteResponse::Applied(response) => Ok(InfisicalSecret {
+                data: SecretBox::new(Box::new(response.secret)),
+            }),
+            SecretWriteResponse::PendingApproval { approval } => {
+                Err(SecretsError::ChangeRequestOpened {
+                    operation: "create_secret".to_string(),
+                    environment: scope.environment.clone(),
+                    secret_path: scope.secret_path.clone(),
+                    change_request: approval,
+                })
+            }
+        }
+    }
+
+    /// create_personal_secret()
+    ///
+    /// Convenience wrapper around create_secret() that creates a personal override of an existing shared secret.
+    /// Needs a user's access token, see create_secret().
+    pub async fn create_personal_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        secret_value: &str,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        self.create_secret(
+            host,
+            client,
+            scope,
+            secret_key,
+            &CreateSecretOptions {
+                secret_value: secret_value.to_string(),
+                type_: SecretType::Personal,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// delete_secret()
+    ///
+    /// Deletes a single secret from a given scope. Deleting a SecretType::Personal secret only removes the
+    /// current user's personal override, leaving the shared secret in place, and needs a user's access token
+    /// (Infisical rejects it for a machine identity's token, returned as a DeleteSecretError).
+    ///
+    /// Returns the deleted secret.
+    pub async fn delete_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        secret_type: SecretType,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_raw_secrets_endpoint_url(host, Some(secret_key));
+
+        let mut form_data = HashMap::new();
+        form_data.insert("type", secret_type);
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&SecretRequestBody {
+                workspace_id: &scope.workspace_id,
+                environment: &scope.environment,
+                secret_path: &scope.secret_path,
+                options: &form_data,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            if is_approval_required(&error_response) {
+                return Err(SecretsError::ApprovalRequired {
+                    operation: "delete_secret".to_string(),
+                    environment: scope.environment.clone(),
+                    secret_path: scope.secret_path.clone(),
+                    error: error_response,
+                });
+            }
+
+            return Err(SecretsError::DeleteSecretError {
+                secret_key: secret_key.to_string(),
+                environment: scope.environment.clone(),
+                secret_path: scope.secret_path.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        match serde_json::from_slice::<SecretWriteResponse<SecretResponse>>(&bytes)? {
+            SecretWriteResponse::Applied(response) => Ok(InfisicalSecret {
+                data: SecretBox::new(Box::new(response.secret)),
+            }),
+            SecretWriteResponse::PendingApproval { approval } => {
+                Err(SecretsError::ChangeRequestOpened {
+                    operation: "delete_secret".to_string(),
+                    environment: scope.environment.clone(),
+                    secret_path: scope.secret_path.clone(),
+                    change_request: approval,
+                })
+            }
+        }
+    }
+
+    /// update_secret()
+    ///
+    /// Updates a single secret in a given scope. Any field left as None in `options` is left untouched.
+    pub async fn update_secret(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        options: &UpdateSecretOptions,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let endpoint_url = construct_raw_secrets_endpoint_url(host, Some(secret_key));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&SecretRequestBody {
+                workspace_id: &scope.workspace_id,
+                environment: &scope.environment,
+                secret_path: &scope.secret_path,
+                options,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            if is_approval_required(&error_response) {
+                return Err(SecretsError::ApprovalRequired {
+                    operation: "update_secret".to_string(),
+                    environment: scope.environment.clone(),
+                    secret_path: scope.secret_path.clone(),
+                    error: error_response,
+                });
+            }
+
+            return Err(SecretsError::UpdateSecretError {
+                secret_key: secret_key.to_string(),
+                environment: scope.environment.clone(),
+                secret_path: scope.secret_path.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        match serde_json::from_slice::<SecretWriteResponse<SecretResponse>>(&bytes)? {
+            SecretWriteResponse::Applied(response) => Ok(InfisicalSecret {
+                data: SecretBox::new(Box::new(response.secret)),
+            }),
+            SecretWriteResponse::PendingApproval { approval } => {
+                Err(SecretsError::ChangeRequestOpened {
+                    operation: "update_secret".to_string(),
+                    environment: scope.environment.clone(),
+                    secret_path: scope.secret_path.clone(),
+                    change_request: approval,
+                })
+            }
+        }
+    }
+
+    /// attach_secret_tags()
+    ///
+    /// Adds the given tags (by tag id) to a secret, keeping whatever tags it already had.
+    pub async fn attach_secret_tags(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        tag_ids: &[&str],
+    ) -> Result<InfisicalSecret, SecretsError> {
+        let secret = self.get_secret(host, client, scope, secret_key).await?;
+
+        let mut updated_tag_ids: Vec<String> =
+            secret.tags().iter().map(|tag| tag.id.clone()).collect();
+        for tag_id in tag_ids {
+            if !updated_tag_ids.iter().any(|id| id == tag_id) {
+                updated_tag_ids.push(tag_id.to_string());
+            }
+        }
+
+        self.update_secret(
+            host,
+            client,
+            scope,
+            secret_key,
+            &UpdateSecretOptions {
+                tag_ids: Some(updated_tag_ids),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// detach_secret_tags()
+    ///
+    /// Removes the given tags (by tag id) from a secret, leaving any other tags in place.
+    pub async fn detach_secret_tags(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        tag_ids: &[&str],
+    ) -> Result<InfisicalSecret, SecretsError> {
+        let secret = self.get_secret(host, client, scope, secret_key).await?;
+
+        let updated_tag_ids: Vec<String> = secret
+            .tags()
+            .iter()
+            .filter(|tag| !tag_ids.contains(&tag.id.as_str()))
+            .map(|tag| tag.id.clone())
+            .collect();
+
+        self.update_secret(
+            host,
+            client,
+            scope,
+            secret_key,
+            &UpdateSecretOptions {
+                tag_ids: Some(updated_tag_ids),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// set_secret_metadata()
+    ///
+    /// Sets a single metadata entry on a secret, replacing any existing entry with the same key
+    /// and leaving every other entry in place.
+    pub async fn set_secret_metadata(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        metadata: &InfisicalSecretMetadata,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        let secret = self.get_secret(host, client, scope, secret_key).await?;
+
+        let mut updated_metadata: Vec<InfisicalSecretMetadata> = secret
+            .secret_metadata()
+            .iter()
+            .filter(|metadata_entry| metadata_entry.key != metadata.key)
+            .cloned()
+            .collect();
+        updated_metadata.push(metadata.clone());
+
+        self.replace_secret_metadata(host, client, scope, secret_key, updated_metadata)
+            .await
+    }
+
+    /// replace_secret_metadata()
+    ///
+    /// Replaces every metadata entry on a secret with the given entries. Passing an empty Vec clears the secret's metadata.
+    pub async fn replace_secret_metadata(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        metadata: Vec<InfisicalSecretMetadata>,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        self.update_secret(
+            host,
+            client,
+            scope,
+            secret_key,
+            &UpdateSecretOptions {
+                secret_metadata: Some(metadata),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// remove_secret_metadata()
+    ///
+    /// Removes every metadata entry with one of the given keys from a secret.
+    pub async fn remove_secret_metadata(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        metadata_keys: &[&str],
+    ) -> Result<InfisicalSecret, SecretsError> {
+        let secret = self.get_secret(host, client, scope, secret_key).await?;
+
+        let updated_metadata: Vec<InfisicalSecretMetadata> = secret
+            .secret_metadata()
+            .iter()
+            .filter(|metadata_entry| !metadata_keys.contains(&metadata_entry.key.as_str()))
+            .cloned()
+            .collect();
+
+        self.replace_secret_metadata(host, client, scope, secret_key, updated_metadata)
+            .await
+    }
+
+    /// set_secret_reminder()
+    ///
+    /// Configures a rotation reminder on a secret, repeating every `repeat_days` days, with an optional note.
+    pub async fn set_secret_reminder(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+        repeat_days: u64,
+        note: Option<&str>,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        self.update_secret(
+            host,
+            client,
+            scope,
+            secret_key,
+            &UpdateSecretOptions {
+                secret_reminder_repeat_days: Some(Some(repeat_days)),
+                secret_reminder_note: Some(note.map(|note| note.to_string())),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// clear_secret_reminder()
+    ///
+    /// Removes the rotation reminder (and its note) from a secret.
+    pub async fn clear_secret_reminder(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_key: &str,
+    ) -> Result<InfisicalSecret, SecretsError> {
+        self.update_secret(
+            host,
+            client,
+            scope,
+            secret_key,
+            &UpdateSecretOptions {
+                secret_reminder_repeat_days: Some(None),
+                secret_reminder_note: Some(None),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    // shared by all three batch endpoints, which only differ in method and entry shape
+    async fn send_secrets_batch<T: serde::Serialize>(
+        &self,
+        request: reqwest::RequestBuilder,
+        operation: &str,
+        scope: &SecretScope,
+        secrets: &[T],
+    ) -> Result<Vec<InfisicalSecret>, SecretsError> {
+        validate_environment_slug(&scope.environment)?;
+
+        let response = request
+            .bearer_auth(self.access_token())
+            .json(&SecretRequestBody {
+                workspace_id: &scope.workspace_id,
+                environment: &scope.environment,
+                secret_path: &scope.secret_path,
+                options: &BatchSecretsBody { secrets },
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            if is_approval_required(&error_response) {
+                return Err(SecretsError::ApprovalRequired {
+                    operation: operation.to_string(),
+                    environment: scope.environment.clone(),
+                    secret_path: scope.secret_path.clone(),
+                    error: error_response,
+                });
+            }
+
+            return Err(SecretsError::BatchSecretsError {
+                operation: operation.to_string(),
+                workspace_id: scope.workspace_id.clone(),
+                environment: scope.environment.clone(),
+                secret_path: scope.secret_path.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        match serde_json::from_slice::<SecretWriteResponse<SecretsResponse>>(&bytes)? {
+            SecretWriteResponse::Applied(response) => Ok(response
+                .secrets
+                .into_iter()
+                .map(|secret| InfisicalSecret {
+                    data: SecretBox::new(Box::new(secret)),
+                })
+                .collect()),
+            SecretWriteResponse::PendingApproval { approval } => {
+                Err(SecretsError::ChangeRequestOpened {
+                    operation: operation.to_string(),
+                    environment: scope.environment.clone(),
+                    secret_path: scope.secret_path.clone(),
+                    change_request: approval,
+                })
+            }
+        }
+    }
+
+    /// create_secrets_batch()
+    ///
+    /// Creates several shared secrets in a given scope in a single request. Either every secret is created, or none are.
+    pub async fn create_secrets_batch(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secrets: &[BatchCreateSecret],
+    ) -> Result<Vec<InfisicalSecret>, SecretsError> {
+        let endpoint_url = construct_batch_raw_secrets_endpoint_url(host);
+
+        self.send_secrets_batch(
+            client.post(&endpoint_url),
+            "create_secrets_batch",
+            scope,
+            secrets,
+        )
+        .await
+    }
+
+    /// update_secrets_batch()
+    ///
+    /// Updates several shared secrets in a given scope in a single request. Either every secret is updated, or none are.
+    pub async fn update_secrets_batch(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secrets: &[BatchUpdateSecret],
+    ) -> Result<Vec<InfisicalSecret>, SecretsError> {
+        let endpoint_url = construct_batch_raw_secrets_endpoint_url(host);
+
+        self.send_secrets_batch(
+            client.patch(&endpoint_url),
+            "update_secrets_batch",
+            scope,
+            secrets,
+        )
+        .await
+    }
+
+    /// delete_secrets_batch()
+    ///
+    /// Deletes several shared secrets from a given scope in a single request. Either every secret is deleted, or none are.
+    ///
+    /// Returns the deleted secrets.
+    pub async fn delete_secrets_batch(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_keys: &[&str],
+    ) -> Result<Vec<InfisicalSecret>, SecretsError> {
+        let endpoint_url = construct_batch_raw_secrets_endpoint_url(host);
+
+        let secrets: Vec<BatchDeleteSecret> = secret_keys
+            .iter()
+            .map(|secret_key| BatchDeleteSecret {
+                secret_key,
+                type_: SecretType::Shared,
+            })
+            .collect();
+
+        self.send_secrets_batch(
+            client.delete(&endpoint_url),
+            "delete_secrets_batch",
+            scope,
+            &secrets,
+        )
+        .await
+    }
+
+    // undoes the create batch of a create-then-update sequence whose update batch failed, so the scope is left as
+    // it was found. Returns the error to report: the update error as is if nothing was created, and otherwise a
+    // PartiallyAppliedError saying whether the created secrets were deleted again.
+    pub(crate) async fn roll_back_created_secrets(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        operation: &str,
+        created_secrets: &[BatchCreateSecret],
+        update_error: SecretsError,
+    ) -> SecretsError {
+        let created_keys: Vec<&str> = created_secrets
+            .iter()
+            .map(|secret| secret.secret_key.as_str())
+            .collect();
+
+        if created_keys.is_empty() {
+            return update_error;
+        }
+
+        // the delete batch is all-or-nothing too, so either every created secret is rolled back or none is
+        match self
+            .delete_secrets_batch(host, client, scope, &created_keys)
+            .await
+        {
+            Ok(_) => SecretsError::PartiallyAppliedError {
+                operation: operation.to_string(),
+                rolled_back_secret_keys: created_keys.iter().map(|key| key.to_string()).collect(),
+                applied_secret_keys: Vec::new(),
+                error: Box::new(update_error),
+                rollback_error: None,
+            },
+            Err(rollback_error) => SecretsError::PartiallyAppliedError {
+                operation: operation.to_string(),
+                rolled_back_secret_keys: Vec::new(),
+                applied_secret_keys: created_keys.iter().map(|key| key.to_string()).collect(),
+                error: Box::new(update_error),
+                rollback_error: Some(Box::new(rollback_error)),
+            },
+        }
+    }
+
+    // lists the shared secrets in a scope, narrowed down to the requested keys (if any)
+    pub(crate) async fn list_transferable_secrets(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        scope: &SecretScope,
+        secret_keys: Option<&Vec<String>>,
+    ) -> Result<Vec<InfisicalSecret>, SecretsError> {
+        let secrets: Vec<InfisicalSecret> = self
+            .list_secrets(host, client, scope, &ListSecretsOptions::default())
+            .await?
+            .into_iter()
+            .filter(|secret| secret.secret_type() == SecretType::Shared)
+            .collect();
+
+        let Some(secret_keys) = secret_keys else {
+            return Ok(secrets);
+        };
+
+        if let Some(missing_key) = secret_keys.iter().find(|key| {
+            !secrets
+                .iter()
+                .any(|secret| secret.secret_key() == key.as_str())
+        }) {
+            return Err(SecretsError::SecretNotFound {
+                secret_key: missing_key.clone(),
+                secret_type: SecretType::Shared,
+                environment: scope.environment.clone(),
+                secret_path: scope.secret_path.clone(),
+            });
+        }
+
+        Ok(secrets
+            .into_iter()
+            .filter(|secret| secret_keys.iter().any(|key| key == secret.secret_key()))
+            .collect())
+    }
+
+    // tag ids are per-project, so map the given secrets' tags over to the destination project by slug,
+    // creating any tag the destination project is missing
+    pub(crate) async fn map_secret_tags_to_project(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        secrets: &[InfisicalSecret],
+        source: &SecretScope,
+        destination: &SecretScope,
+    ) -> Result<HashMap<String, String>, SecretsError> {
+        let mut tag_ids_by_slug: HashMap<String, String> = HashMap::new();
+
+        if source.workspace_id == destination.workspace_id {
+            for secret in secrets {
+                for tag in secret.tags() {
+                    tag_ids_by_slug.insert(tag.slug.clone(), tag.id.clone());
+                }
+            }
+
+            return Ok(tag_ids_by_slug);
+        }
+
+        for tag in self
+            .list_tags(host, client, &destination.workspace_id)
+            .await?
+        {
+            tag_ids_by_slug.insert(tag.slug, tag.id);
+        }
+
+        for secret in secrets {
+            for tag in secret.tags() {
+                if tag_ids_by_slug.contains_key(&tag.slug) {
+                    continue;
+                }
+
+                let created_tag = self
+                    .create_tag(
+                        host,
+                        client,
+                        &destination.workspace_id,
+                        &CreateTagOptions {
+                            slug: tag.slug.clone(),
+                            color: tag.color.clone(),
+                        },
+                    )
+                    .await?;
+                tag_ids_by_slug.insert(created_tag.slug, created_tag.id);
+            }
+        }
+
+        Ok(tag_ids_by_slug)
+    }
+
+    /// move_secrets()
+    ///
+    /// Moves secrets from one environment/secret path to another within the same project. Moved secrets keep their
+    /// version history, tags, and metadata.
+    ///
+    /// Arguments:
+    ///     - source: where the secrets currently live
+    ///     - destination: where the secrets should end up. Has to be in the same project as `source`
+    ///     - options: which secrets to move (None moves every shared secret), and whether to overwrite or skip
+    ///       secrets that already exist at the destination
+    pub async fn move_secrets(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        source: &SecretScope,
+        destination: &SecretScope,
+        options: &TransferSecretsOptions,
+    ) -> Result<MoveSecretsResult, SecretsError> {
+        if source.workspace_id != destination.workspace_id {
+            return Err(SecretsError::CrossProjectMoveUnsupported {
+                source_workspace_id: source.workspace_id.clone(),
+                destination_workspace_id: destination.workspace_id.clone(),
+            });
+        }
+        validate_environment_slug(&destination.environment)?;
+
+        let secret_ids: Vec<String> = self
+            .list_transferable_secrets(host, client, source, options.secret_keys.as_ref())
+            .await?
+            .iter()
+            .map(|secret| secret.data.expose_secret().id.clone())
+            .collect();
+
+        // the move endpoint wants the project's slug rather than its id
+        let project = self.get_project(host, client, &source.workspace_id).await?;
+
+        let endpoint_url = format!("{host}/api/v3/secrets/move");
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&MoveSecretsBody {
+                project_slug: &project.slug,
+                source_environment: &source.environment,
+                source_secret_path: &source.secret_path,
+                destination_environment: &destination.environment,
+                destination_secret_path: &destination.secret_path,
+                secret_ids,
+                should_overwrite: options.on_conflict == SecretConflictBehavior::Overwrite,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SecretsError::MoveSecretsError {
+                source_environment: source.environment.clone(),
+                source_secret_path: source.secret_path.clone(),
+                destination_environment: destination.environment.clone(),
+                destination_secret_path: destination.secret_path.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<MoveSecretsResult>(&bytes)?)
+    }
+
+    /// copy_secrets()
+    ///
+    /// Copies secrets from one scope to another, across environments or across projects. Copies carry over the
+    /// source secrets' values, comments, tags, and metadata, but start out with a fresh version history.
+    ///
+    /// When copying across projects, tags are matched up by slug, and any tag missing from the destination project
+    /// is created there first.
+    ///
+    /// Copies are written as a create batch for new keys, then an update batch for overwritten ones. If the update
+    /// batch fails, the created secrets are deleted again on a best-effort basis, and the error is a
+    /// SecretsError::PartiallyAppliedError listing which secrets were rolled back and which were left behind.
+    /// Tags created in the destination project are never rolled back.
+    ///
+    /// Arguments:
+    ///     - source: where the secrets currently live
+    ///     - destination: where the copies should end up
+    ///     - options: which secrets to copy (None copies every shared secret), and whether to overwrite or skip
+    ///       secrets that already exist at the destination
+    pub async fn copy_secrets(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        source: &SecretScope,
+        destination: &SecretScope,
+        options: &TransferSecretsOptions,
+    ) -> Result<CopySecretsResult, SecretsError> {
+        let source_secrets = self
+            .list_transferable_secrets(host, client, source, options.secret_keys.as_ref())
+            .await?;
+
+        let destination_keys: Vec<String> = self
+            .list_transferable_secrets(host, client, destination, None)
+            .await?
+            .iter()
+            .map(|secret| secret.secret_key().to_string())
+            .collect();
+
+        let tag_ids_by_slug = self
+            .map_secret_tags_to_project(host, client, &source_secrets, source, destination)
+            .await?;
+
+        let mut result = CopySecretsResult::default();
+        let mut secrets_to_create = Vec::new();
+        let mut secrets_to_update = Vec::new();
+
+        for secret in &source_secrets {
+            let secret_data = secret.data.expose_secret();
+            let tag_ids: Vec<String> = secret_data
+                .tags
+                .iter()
+                .filter_map(|tag| tag_ids_by_slug.get(&tag.slug).cloned())
+                .collect();
+
+            if !destination_keys
+                .iter()
+                .any(|key| key == &secret_data.secret_key)
+            {
+                result.created.push(secret_data.secret_key.clone());
+                secrets_to_create.push(BatchCreateSecret {
+                    secret_key: secret_data.secret_key.clone(),
+                    options: CreateSecretOptions {
+                        secret_value: secret_data.secret_value.clone(),
+                        secret_comment: Some(secret_data.secret_comment.clone()),
+                        skip_multiline_encoding: secret_data.skip_multiline_encoding,
+                        tag_ids,
+                        secret_metadata: secret_data.secret_metadata.clone(),
+                        type_: SecretType::Shared,
+                    },
+                });
+                continue;
+            }
+
+            match options.on_conflict {
+                SecretConflictBehavior::Skip => {
+                    result.skipped.push(secret_data.secret_key.clone());
+                }
+                SecretConflictBehavior::Overwrite => {
+                    result.overwritten.push(secret_data.secret_key.clone());
+                    secrets_to_update.push(BatchUpdateSecret {
+                        secret_key: secret_data.secret_key.clone(),
+                        options: UpdateSecretOptions {
+                            secret_value: Some(secret_data.secret_value.clone()),
+                            secret_comment: Some(secret_data.secret_comment.clone()),
+                            skip_multiline_encoding: secret_data.skip_multiline_encoding,
+                            tag_ids: Some(tag_ids),
+                            secret_metadata: Some(secret_data.secret_metadata.clone()),
+                            ..Default::default()
+                        },
+                    });
+                }
+            }
+        }
+
+        if !secrets_to_create.is_empty() {
+            self.create_secrets_batch(host, client, destination, &secrets_to_create)
+                .await?;
+        }
+
+        if !secrets_to_update.is_empty()
+            && let Err(update_error) = self
+                .update_secrets_batch(host, client, destination, &secrets_to_update)
+                .await
+        {
+            return Err(self
+                .roll_back_created_secrets(
+                    host,
+                    client,
+                    destination,
+                    "copy_secrets",
+                    &secrets_to_create,
+                    update_error,
+                )
+                .await);
+        }
+
+        Ok(result)
+    }
+}
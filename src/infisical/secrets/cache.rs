@@ -0,0 +1,210 @@
+/// Secret cache:
+///
+/// Keeps listed secrets around per scope (project, environment, and secret path), so they're fetched from Infisical
+/// once and then served from memory until something invalidates them.
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use secrecy::SecretString;
+use tokio::sync::broadcast;
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    secrets::{
+        error_handling::SecretsError,
+        utils::{
+            InfisicalSecret, ListSecretsOptions, SecretScope,
+            secret_util_functions::is_secret_path_within,
+        },
+    },
+};
+
+/// Published by SecretCache::invalidate(), e.g. when a webhook reports a change.
+///
+/// - changed: the scope reported as changed
+/// - evicted: the cached scopes that were dropped because of it, possibly none
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretCacheInvalidation {
+    pub changed: SecretScope,
+    pub evicted: Vec<SecretScope>,
+}
+
+/// SecretCache
+///
+/// Caches list_secrets() results per scope. A change only evicts the cached scopes it could have affected: the
+/// changed path and the folders below it, plus (when listing recursively) the folders above it.
+///
+/// Secrets imported from other paths (see ListSecretsOptions::include_imports) aren't tracked, changes to an import's
+/// source won't evict the scopes importing it.
+///
+/// # Example
+/// ```ignore
+/// let cache = Arc::new(SecretCache::new(
+///     access_token, // Arc<UniversalAuthAccessToken>
+///     host,
+///     client.clone(),
+///     ListSecretsOptions::default(),
+/// ));
+///
+/// let scope = SecretScope::new("<workspace id>", "prod", Some("/db"));
+/// let database_url = cache.secret_value(&scope, "DATABASE_URL").await?;
+///
+/// // e.g. from a webhook delivery
+/// cache.invalidate(&scope);
+/// ```
+pub struct SecretCache {
+    access_token: Arc<UniversalAuthAccessToken>,
+    host: String,
+    client: reqwest::Client,
+    options: ListSecretsOptions,
+    state: Mutex<SecretCacheState>,
+    invalidations: broadcast::Sender<SecretCacheInvalidation>,
+}
+
+#[derive(Default)]
+struct SecretCacheState {
+    entries: HashMap<SecretScope, Arc<Vec<InfisicalSecret>>>,
+    // bumped on every invalidation, so a fetch racing one doesn't put stale secrets back
+    generation: u64,
+}
+
+impl SecretCache {
+    /// Every scope is listed with the same `options`.
+    pub fn new(
+        access_token: Arc<UniversalAuthAccessToken>,
+        host: &str,
+        client: reqwest::Client,
+        options: ListSecretsOptions,
+    ) -> Self {
+        let (invalidations, _) = broadcast::channel(64);
+
+        Self {
+            access_token,
+            host: host.to_string(),
+            client,
+            options,
+            state: Mutex::new(SecretCacheState::default()),
+            invalidations,
+        }
+    }
+
+    /// list_secrets()
+    ///
+    /// Returns the secrets of a scope, fetching them from Infisical only if they aren't cached yet.
+    pub async fn list_secrets(
+        &self,
+        scope: &SecretScope,
+    ) -> Result<Arc<Vec<InfisicalSecret>>, SecretsError> {
+        let generation = {
+            let state = self.state();
+
+            if let Some(secrets) = state.entries.get(scope) {
+                return Ok(secrets.clone());
+            }
+
+            state.generation
+        };
+
+        let secrets = Arc::new(
+            self.access_token
+                .list_secrets(&self.host, &self.client, scope, &self.options)
+                .await?,
+        );
+
+        let mut state = self.state();
+        if state.generation == generation {
+            state.entries.insert(scope.clone(), secrets.clone());
+        }
+
+        Ok(secrets)
+    }
+
+    /// secret_value()
+    ///
+    /// Returns the value of a single secret through list_secrets(), None if the scope has no such secret.
+    pub async fn secret_value(
+        &self,
+        scope: &SecretScope,
+        secret_key: &str,
+    ) -> Result<Option<SecretString>, SecretsError> {
+        Ok(self
+            .list_secrets(scope)
+            .await?
+            .iter()
+            .find(|secret| secret.secret_key() == secret_key)
+            .map(|secret| SecretString::from(secret.secret_value())))
+    }
+
+    /// insert()
+    ///
+    /// Caches secrets fetched elsewhere, replacing whatever was cached for the scope.
+    pub fn insert(&self, scope: SecretScope, secrets: Vec<InfisicalSecret>) {
+        self.state().entries.insert(scope, Arc::new(secrets));
+    }
+
+    /// invalidate()
+    ///
+    /// Evicts the cached scopes affected by a change in `changed`, and notifies subscribers. Returns the evicted scopes.
+    pub fn invalidate(&self, changed: &SecretScope) -> Vec<SecretScope> {
+        let evicted = {
+            let mut state = self.state();
+            state.generation += 1;
+
+            let evicted = state
+                .entries
+                .keys()
+                .filter(|cached| self.is_affected(cached, changed))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for scope in &evicted {
+                state.entries.remove(scope);
+            }
+
+            evicted
+        };
+
+        // sending only fails when nobody is subscribed
+        let _ = self.invalidations.send(SecretCacheInvalidation {
+            changed: changed.clone(),
+            evicted: evicted.clone(),
+        });
+
+        evicted
+    }
+
+    /// clear()
+    ///
+    /// Evicts every cached scope. Subscribers aren't notified.
+    pub fn clear(&self) {
+        let mut state = self.state();
+        state.generation += 1;
+        state.entries.clear();
+    }
+
+    /// The scopes currently cached.
+    pub fn cached_scopes(&self) -> Vec<SecretScope> {
+        self.state().entries.keys().cloned().collect()
+    }
+
+    /// A receiver notified on every invalidate() call. Receivers lagging more than 64 invalidations behind miss the
+    /// oldest ones.
+    pub fn subscribe(&self) -> broadcast::Receiver<SecretCacheInvalidation> {
+        self.invalidations.subscribe()
+    }
+
+    fn is_affected(&self, cached: &SecretScope, changed: &SecretScope) -> bool {
+        cached.workspace_id == changed.workspace_id
+            && cached.environment == changed.environment
+            && (is_secret_path_within(&cached.secret_path, &changed.secret_path)
+                || (self.options.recursive
+                    && is_secret_path_within(&changed.secret_path, &cached.secret_path)))
+    }
+
+    // the state stays consistent even if a holder panicked, nothing in it is updated halfway
+    fn state(&self) -> MutexGuard<'_, SecretCacheState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
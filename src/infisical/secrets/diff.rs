@@ -0,0 +1,214 @@
+/// Secret diffs:
+///
+/// Compares the shared secrets in two scopes (two environments, two secret paths, or two projects)
+/// without exposing any secret values unless explicitly asked to.
+use std::collections::BTreeMap;
+
+use secrecy::{ExposeSecret, SecretString};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    secrets::{
+        error_handling::SecretsError,
+        utils::{
+            InfisicalSecret, InfisicalSecretMetadata, ListSecretsOptions, SecretScope, SecretType,
+        },
+    },
+};
+
+/// Options for diff_secrets().
+///
+/// Fields:
+/// - include_values: also return both sides' values for secrets whose values differ
+/// - tag_slugs: only compare secrets carrying every one of the given tags
+#[derive(Debug, Clone, Default)]
+pub struct SecretDiffOptions {
+    pub include_values: bool,
+    pub tag_slugs: Vec<String>,
+}
+
+/// Both sides' values of a secret whose value differs. Only populated when `include_values` is set.
+#[derive(Debug)]
+pub struct SecretDiffValues {
+    pub source: SecretString,
+    pub target: SecretString,
+}
+
+/// A secret present on both sides of a diff that differs in some way.
+///
+/// Fields:
+/// - secret_key: the secret's key
+/// - value_changed: whether the secret's value differs
+/// - values: both sides' values, if `include_values` was set and the value differs
+/// - comment_changed: whether the secret's comment differs
+/// - tags_only_in_source/tags_only_in_target: tag slugs found on only one side
+/// - metadata_only_in_source/metadata_only_in_target: metadata entries found on only one side
+///   (an entry whose value differs shows up on both)
+#[derive(Debug)]
+pub struct SecretDiffEntry {
+    pub secret_key: String,
+    pub value_changed: bool,
+    pub values: Option<SecretDiffValues>,
+    pub comment_changed: bool,
+    pub tags_only_in_source: Vec<String>,
+    pub tags_only_in_target: Vec<String>,
+    pub metadata_only_in_source: Vec<InfisicalSecretMetadata>,
+    pub metadata_only_in_target: Vec<InfisicalSecretMetadata>,
+}
+
+impl SecretDiffEntry {
+    /// whether anything other than the secret's value differs
+    pub fn attributes_changed(&self) -> bool {
+        self.comment_changed
+            || !self.tags_only_in_source.is_empty()
+            || !self.tags_only_in_target.is_empty()
+            || !self.metadata_only_in_source.is_empty()
+            || !self.metadata_only_in_target.is_empty()
+    }
+}
+
+/// The result of diff_secrets(). Every list is sorted by secret key.
+///
+/// Fields:
+/// - only_in_source: keys of secrets that only exist in the source scope
+/// - only_in_target: keys of secrets that only exist in the target scope
+/// - changed: secrets that exist on both sides but differ
+/// - unchanged: keys of secrets that exist on both sides and are identical
+#[derive(Debug, Default)]
+pub struct SecretDiff {
+    pub only_in_source: Vec<String>,
+    pub only_in_target: Vec<String>,
+    pub changed: Vec<SecretDiffEntry>,
+    pub unchanged: Vec<String>,
+}
+
+impl SecretDiff {
+    pub fn is_empty(&self) -> bool {
+        self.only_in_source.is_empty() && self.only_in_target.is_empty() && self.changed.is_empty()
+    }
+}
+
+pub mod diff_util_functions {
+    use super::*;
+
+    /// diff_secret_lists()
+    ///
+    /// Diffs two already-fetched lists of secrets. Personal overrides are ignored on both sides,
+    /// so only shared values are compared.
+    pub fn diff_secret_lists(
+        source: &[InfisicalSecret],
+        target: &[InfisicalSecret],
+        include_values: bool,
+    ) -> SecretDiff {
+        let shared_by_key = |secrets: &[InfisicalSecret]| -> BTreeMap<String, usize> {
+            secrets
+                .iter()
+                .enumerate()
+                .filter(|(_, secret)| secret.secret_type() == SecretType::Shared)
+                .map(|(index, secret)| (secret.secret_key().to_string(), index))
+                .collect()
+        };
+
+        let source_keys = shared_by_key(source);
+        let target_keys = shared_by_key(target);
+
+        let mut diff = SecretDiff {
+            only_in_target: target_keys
+                .keys()
+                .filter(|key| !source_keys.contains_key(*key))
+                .cloned()
+                .collect(),
+            ..Default::default()
+        };
+
+        for (secret_key, source_index) in &source_keys {
+            let Some(target_index) = target_keys.get(secret_key) else {
+                diff.only_in_source.push(secret_key.clone());
+                continue;
+            };
+
+            let source_data = source[*source_index].data.expose_secret();
+            let target_data = target[*target_index].data.expose_secret();
+
+            let value_changed = source_data.secret_value != target_data.secret_value;
+
+            let entry = SecretDiffEntry {
+                secret_key: secret_key.clone(),
+                value_changed,
+                values: (include_values && value_changed).then(|| SecretDiffValues {
+                    source: SecretString::from(source_data.secret_value.clone()),
+                    target: SecretString::from(target_data.secret_value.clone()),
+                }),
+                comment_changed: source_data.secret_comment != target_data.secret_comment,
+                tags_only_in_source: source_data
+                    .tags
+                    .iter()
+                    .filter(|tag| !target_data.tags.iter().any(|other| other.slug == tag.slug))
+                    .map(|tag| tag.slug.clone())
+                    .collect(),
+                tags_only_in_target: target_data
+                    .tags
+                    .iter()
+                    .filter(|tag| !source_data.tags.iter().any(|other| other.slug == tag.slug))
+                    .map(|tag| tag.slug.clone())
+                    .collect(),
+                metadata_only_in_source: source_data
+                    .secret_metadata
+                    .iter()
+                    .filter(|entry| !target_data.secret_metadata.contains(entry))
+                    .cloned()
+                    .collect(),
+                metadata_only_in_target: target_data
+                    .secret_metadata
+                    .iter()
+                    .filter(|entry| !source_data.secret_metadata.contains(entry))
+                    .cloned()
+                    .collect(),
+            };
+
+            if entry.value_changed || entry.attributes_changed() {
+                diff.changed.push(entry);
+            } else {
+                diff.unchanged.push(secret_key.clone());
+            }
+        }
+
+        diff
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Secret diffing
+impl UniversalAuthAccessToken {
+    /// diff_secrets()
+    ///
+    /// Fetches the shared secrets in two scopes and diffs them, e.g. `staging` vs `prod` of the same project.
+    ///
+    /// Secret values are only compared, and never returned, unless `options.include_values` is set.
+    pub async fn diff_secrets(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        source: &SecretScope,
+        target: &SecretScope,
+        options: &SecretDiffOptions,
+    ) -> Result<SecretDiff, SecretsError> {
+        let list_options = ListSecretsOptions {
+            tag_slugs: options.tag_slugs.clone(),
+            ..Default::default()
+        };
+
+        let source_secrets = self
+            .list_secrets(host, client, source, &list_options)
+            .await?;
+        let target_secrets = self
+            .list_secrets(host, client, target, &list_options)
+            .await?;
+
+        Ok(diff_util_functions::diff_secret_lists(
+            &source_secrets,
+            &target_secrets,
+            options.include_values,
+        ))
+    }
+}
@@ -0,0 +1,186 @@
+use crate::infisical::{
+    environments::error_handling::EnvironmentSlugError, projects::error_handling::ProjectError,
+    secret_approvals::utils::SecretChangeRequest, secrets::utils::SecretType,
+    tags::error_handling::TagError, utils::api_utils::ApiResponse,
+};
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum SecretsError {
+    #[error(
+        "Secrets::list_secrets(): 
+        Workspace ID: {workspace_id}
+        Environment: {environment}
+        Secret path: {secret_path}
+        Err: {error}"
+    )]
+    ListSecretsError {
+        workspace_id: String,
+        environment: String,
+        secret_path: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Secrets::get_secret(): 
+        Secret: {secret_key}
+        Environment: {environment}
+        Secret path: {secret_path}
+        Err: {error}"
+    )]
+    GetSecretError {
+        secret_key: String,
+        environment: String,
+        secret_path: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Secrets: no {secret_type} secret named {secret_key} exists in {environment}:{secret_path}"
+    )]
+    SecretNotFound {
+        secret_key: String,
+        secret_type: SecretType,
+        environment: String,
+        secret_path: String,
+    },
+
+    #[error(
+        "Secrets::create_secret(): 
+        Secret: {secret_key}
+        Environment: {environment}
+        Secret path: {secret_path}
+        Err: {error}"
+    )]
+    CreateSecretError {
+        secret_key: String,
+        environment: String,
+        secret_path: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Secrets::delete_secret(): 
+        Secret: {secret_key}
+        Environment: {environment}
+        Secret path: {secret_path}
+        Err: {error}"
+    )]
+    DeleteSecretError {
+        secret_key: String,
+        environment: String,
+        secret_path: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Secrets::update_secret(): 
+        Secret: {secret_key}
+        Environment: {environment}
+        Secret path: {secret_path}
+        Err: {error}"
+    )]
+    UpdateSecretError {
+        secret_key: String,
+        environment: String,
+        secret_path: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Secrets::{operation}(): 
+        Workspace ID: {workspace_id}
+        Environment: {environment}
+        Secret path: {secret_path}
+        Err: {error}"
+    )]
+    BatchSecretsError {
+        operation: String,
+        workspace_id: String,
+        environment: String,
+        secret_path: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Secrets::{operation}(): 
+        Environment: {environment}
+        Secret path: {secret_path}
+        Err: writes to this scope require approval, open a change request instead
+        {error}"
+    )]
+    ApprovalRequired {
+        operation: String,
+        environment: String,
+        secret_path: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Secrets::{operation}(): 
+        Environment: {environment}
+        Secret path: {secret_path}
+        Err: the scope is protected by an approval policy, changes are pending approval in change request {}",
+        change_request.id
+    )]
+    ChangeRequestOpened {
+        operation: String,
+        environment: String,
+        secret_path: String,
+        change_request: Box<SecretChangeRequest>,
+    },
+
+    #[error(
+        "Secrets::move_secrets(): 
+        Source: {source_environment}:{source_secret_path}
+        Destination: {destination_environment}:{destination_secret_path}
+        Err: {error}"
+    )]
+    MoveSecretsError {
+        source_environment: String,
+        source_secret_path: String,
+        destination_environment: String,
+        destination_secret_path: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Secrets::move_secrets(): secrets can only be moved within a single project \
+        (source: {source_workspace_id}, destination: {destination_workspace_id}). \
+        Use copy_secrets() followed by delete_secrets_batch() instead."
+    )]
+    CrossProjectMoveUnsupported {
+        source_workspace_id: String,
+        destination_workspace_id: String,
+    },
+
+    /// The update batch of a create-then-update write failed after the create batch went through. Updates are
+    /// all-or-nothing, so none of them were applied; the created secrets were deleted again (rolled_back_secret_keys),
+    /// unless that failed too, in which case they're still in the scope (applied_secret_keys) and rollback_error says why.
+    #[error(
+        "Secrets::{operation}(): updating secrets failed after creating some. \n\
+        Rolled back: {rolled_back_secret_keys:?} \n\
+        Left applied: {applied_secret_keys:?} \n\
+        Update error: {error}{}",
+        rollback_error.as_ref().map(|rollback_error| format!(" \nRollback error: {rollback_error}")).unwrap_or_default()
+    )]
+    PartiallyAppliedError {
+        operation: String,
+        rolled_back_secret_keys: Vec<String>,
+        applied_secret_keys: Vec<String>,
+        error: Box<SecretsError>,
+        rollback_error: Option<Box<SecretsError>>,
+    },
+
+    #[error(transparent)]
+    InvalidEnvironmentSlug(#[from] EnvironmentSlugError),
+    #[error(transparent)]
+    ProjectError(#[from] ProjectError),
+    #[error(transparent)]
+    TagError(#[from] TagError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
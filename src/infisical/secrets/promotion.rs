@@ -0,0 +1,363 @@
+/// Environment promotion:
+///
+/// Promotes secrets from one scope to another (usually `staging` to `prod`), computing the changes up front
+/// so they can be reviewed with a dry run before anything is written.
+use std::fmt::Display;
+
+use secrecy::ExposeSecret;
+use serde::{Deserialize, Serialize};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    secrets::{
+        diff::diff_util_functions::diff_secret_lists,
+        error_handling::SecretsError,
+        utils::{
+            BatchCreateSecret, BatchUpdateSecret, CreateSecretOptions, InfisicalSecret,
+            SecretScope, SecretType, UpdateSecretOptions,
+        },
+    },
+};
+
+/// Which source secrets a promotion should consider.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub enum SecretKeySelector {
+    /// every shared secret in the source scope
+    #[default]
+    All,
+    /// only the secrets with one of the given keys
+    Keys(Vec<String>),
+    /// only the secrets whose key starts with the given prefix
+    Prefix(String),
+    /// only the secrets carrying every one of the given tag slugs
+    TagSlugs(Vec<String>),
+}
+
+impl SecretKeySelector {
+    pub fn matches(&self, secret: &InfisicalSecret) -> bool {
+        match self {
+            SecretKeySelector::All => true,
+            SecretKeySelector::Keys(keys) => keys.iter().any(|key| key == secret.secret_key()),
+            SecretKeySelector::Prefix(prefix) => secret.secret_key().starts_with(prefix.as_str()),
+            SecretKeySelector::TagSlugs(tag_slugs) => tag_slugs
+                .iter()
+                .all(|slug| secret.tags().iter().any(|tag| &tag.slug == slug)),
+        }
+    }
+}
+
+/// Options for promote_secrets().
+///
+/// Fields:
+/// - selector: which source secrets to promote
+/// - comment: comment recorded on every created/updated secret. Left as None, created secrets carry over the source
+///   secret's comment and updated secrets keep the target secret's own
+/// - dry_run: only compute and return the promotion plan, without writing anything
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PromotionOptions {
+    pub selector: SecretKeySelector,
+    pub comment: Option<String>,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PromotionAction {
+    Create,
+    Update,
+}
+
+/// A single secret a promotion creates or updates in the target scope.
+///
+/// value_changed/tags_changed/metadata_changed are only ever set for updates.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PromotionChange {
+    pub secret_key: String,
+    pub action: PromotionAction,
+    pub value_changed: bool,
+    pub tags_changed: bool,
+    pub metadata_changed: bool,
+}
+
+/// Returned by promote_secrets(): what the promotion changes (or, for a dry run, would change).
+///
+/// Fields:
+/// - source/target: the scopes being promoted from and to
+/// - changes: the secrets created or updated in the target scope, sorted by key
+/// - unchanged: keys of selected secrets that are already identical in the target scope
+/// - applied: whether the changes were actually written (false for dry runs)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PromotionPlan {
+    pub source: SecretScope,
+    pub target: SecretScope,
+    pub changes: Vec<PromotionChange>,
+    pub unchanged: Vec<String>,
+    pub applied: bool,
+}
+
+impl PromotionPlan {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+// mainly for dry-run output, in a roughly diff-like format
+impl Display for PromotionPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} {}:{} -> {}:{} ({} change(s), {} unchanged)",
+            if self.applied {
+                "Promoted"
+            } else {
+                "Promotion plan"
+            },
+            self.source.environment,
+            self.source.secret_path,
+            self.target.environment,
+            self.target.secret_path,
+            self.changes.len(),
+            self.unchanged.len(),
+        )?;
+
+        for change in &self.changes {
+            match change.action {
+                PromotionAction::Create => writeln!(f, "  + {}", change.secret_key)?,
+                PromotionAction::Update => {
+                    let changed_fields: Vec<&str> = [
+                        (change.value_changed, "value"),
+                        (change.tags_changed, "tags"),
+                        (change.metadata_changed, "metadata"),
+                    ]
+                    .into_iter()
+                    .filter_map(|(changed, field)| changed.then_some(field))
+                    .collect();
+
+                    writeln!(
+                        f,
+                        "  ~ {} ({})",
+                        change.secret_key,
+                        changed_fields.join(", ")
+                    )?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub mod promotion_util_functions {
+    use super::*;
+
+    /// plan_promotion()
+    ///
+    /// Computes what promoting the selected `source_secrets` into `target_secrets` would change. Secrets only
+    /// found in the target are left alone, and comment-only differences are ignored (comments aren't promoted).
+    pub fn plan_promotion(
+        source: &SecretScope,
+        target: &SecretScope,
+        source_secrets: &[InfisicalSecret],
+        target_secrets: &[InfisicalSecret],
+        selector: &SecretKeySelector,
+    ) -> PromotionPlan {
+        let selected_secrets: Vec<&InfisicalSecret> = source_secrets
+            .iter()
+            .filter(|secret| secret.secret_type() == SecretType::Shared && selector.matches(secret))
+            .collect();
+
+        // diff everything, then narrow the results down to the selected keys
+        let selected_keys: Vec<&str> = selected_secrets
+            .iter()
+            .map(|secret| secret.secret_key())
+            .collect();
+        let diff = diff_secret_lists(source_secrets, target_secrets, false);
+
+        let mut changes: Vec<PromotionChange> = diff
+            .only_in_source
+            .iter()
+            .filter(|key| selected_keys.contains(&key.as_str()))
+            .map(|key| PromotionChange {
+                secret_key: key.clone(),
+                action: PromotionAction::Create,
+                value_changed: false,
+                tags_changed: false,
+                metadata_changed: false,
+            })
+            .collect();
+
+        let mut unchanged: Vec<String> = diff
+            .unchanged
+            .into_iter()
+            .filter(|key| selected_keys.contains(&key.as_str()))
+            .collect();
+
+        for entry in diff
+            .changed
+            .iter()
+            .filter(|entry| selected_keys.contains(&entry.secret_key.as_str()))
+        {
+            let tags_changed =
+                !entry.tags_only_in_source.is_empty() || !entry.tags_only_in_target.is_empty();
+            let metadata_changed = !entry.metadata_only_in_source.is_empty()
+                || !entry.metadata_only_in_target.is_empty();
+
+            if !(entry.value_changed || tags_changed || metadata_changed) {
+                unchanged.push(entry.secret_key.clone());
+                continue;
+            }
+
+            changes.push(PromotionChange {
+                secret_key: entry.secret_key.clone(),
+                action: PromotionAction::Update,
+                value_changed: entry.value_changed,
+                tags_changed,
+                metadata_changed,
+            });
+        }
+
+        changes.sort_by(|a, b| a.secret_key.cmp(&b.secret_key));
+        unchanged.sort();
+
+        PromotionPlan {
+            source: source.clone(),
+            target: target.clone(),
+            changes,
+            unchanged,
+            applied: false,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Environment promotion
+impl UniversalAuthAccessToken {
+    /// promote_secrets()
+    ///
+    /// Promotes the selected shared secrets from `source` to `target`: secrets missing from the target are created,
+    /// and secrets whose value, tags, or metadata differ are updated. Secrets that only exist in the target are
+    /// left alone. Updated secrets keep their comment unless `options.comment` is set.
+    ///
+    /// With `options.dry_run` set, nothing is written and the returned plan describes what would change
+    /// (its Display impl prints a short, diff-like summary).
+    ///
+    /// Notes:
+    ///     - the promotion as a whole is not atomic: creates and updates are sent as one batch request each, and
+    ///       only each batch on its own is all-or-nothing. If the update batch fails after the create batch went
+    ///       through, the newly created secrets are deleted again as a best-effort rollback, and the error is a
+    ///       SecretsError::PartiallyAppliedError listing which secrets were rolled back and which are still applied.
+    ///     - tags created in the target project are never rolled back.
+    ///     - when promoting across projects, tags are matched up by slug (and created in the target project if missing).
+    pub async fn promote_secrets(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        source: &SecretScope,
+        target: &SecretScope,
+        options: &PromotionOptions,
+    ) -> Result<PromotionPlan, SecretsError> {
+        let source_secrets = self
+            .list_transferable_secrets(host, client, source, None)
+            .await?;
+        let target_secrets = self
+            .list_transferable_secrets(host, client, target, None)
+            .await?;
+
+        let mut plan = promotion_util_functions::plan_promotion(
+            source,
+            target,
+            &source_secrets,
+            &target_secrets,
+            &options.selector,
+        );
+
+        if options.dry_run || plan.is_empty() {
+            return Ok(plan);
+        }
+
+        let promoted_secrets: Vec<InfisicalSecret> = source_secrets
+            .into_iter()
+            .filter(|secret| {
+                plan.changes
+                    .iter()
+                    .any(|change| change.secret_key == secret.secret_key())
+            })
+            .collect();
+
+        let tag_ids_by_slug = self
+            .map_secret_tags_to_project(host, client, &promoted_secrets, source, target)
+            .await?;
+
+        let mut secrets_to_create = Vec::new();
+        let mut secrets_to_update = Vec::new();
+
+        for secret in &promoted_secrets {
+            let secret_data = secret.data.expose_secret();
+            let tag_ids: Vec<String> = secret_data
+                .tags
+                .iter()
+                .filter_map(|tag| tag_ids_by_slug.get(&tag.slug).cloned())
+                .collect();
+
+            let is_new = plan.changes.iter().any(|change| {
+                change.secret_key == secret_data.secret_key
+                    && change.action == PromotionAction::Create
+            });
+
+            if is_new {
+                secrets_to_create.push(BatchCreateSecret {
+                    secret_key: secret_data.secret_key.clone(),
+                    options: CreateSecretOptions {
+                        secret_value: secret_data.secret_value.clone(),
+                        secret_comment: Some(
+                            options
+                                .comment
+                                .clone()
+                                .unwrap_or_else(|| secret_data.secret_comment.clone()),
+                        ),
+                        skip_multiline_encoding: secret_data.skip_multiline_encoding,
+                        tag_ids,
+                        secret_metadata: secret_data.secret_metadata.clone(),
+                        type_: SecretType::Shared,
+                    },
+                });
+            } else {
+                secrets_to_update.push(BatchUpdateSecret {
+                    secret_key: secret_data.secret_key.clone(),
+                    options: UpdateSecretOptions {
+                        secret_value: Some(secret_data.secret_value.clone()),
+                        secret_comment: options.comment.clone(),
+                        skip_multiline_encoding: secret_data.skip_multiline_encoding,
+                        tag_ids: Some(tag_ids),
+                        secret_metadata: Some(secret_data.secret_metadata.clone()),
+                        ..Default::default()
+                    },
+                });
+            }
+        }
+
+        if !secrets_to_create.is_empty() {
+            self.create_secrets_batch(host, client, target, &secrets_to_create)
+                .await?;
+        }
+
+        if !secrets_to_update.is_empty()
+            && let Err(update_error) = self
+                .update_secrets_batch(host, client, target, &secrets_to_update)
+                .await
+        {
+            return Err(self
+                .roll_back_created_secrets(
+                    host,
+                    client,
+                    target,
+                    "promote_secrets",
+                    &secrets_to_create,
+                    update_error,
+                )
+                .await);
+        }
+
+        plan.applied = true;
+        Ok(plan)
+    }
+}
@@ -1,18 +1,73 @@
 use secrecy::{zeroize::Zeroize, ExposeSecret, SecretBox, SerializableSecret};
 use serde::{Deserialize, Serialize};
 
-use super::auth_methods::universal_auth::utils::*;
+use crate::infisical::auth_methods::universal_auth::utils::*;
+use crate::infisical::environments::utils::environment_util_functions::validate_environment_slug;
+use crate::infisical::secret_approvals::utils::SecretChangeRequest;
 // use serde_with::serde_as;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct InfisicalSecretTag {
-    color: String,
-    id: String,
-    name: String,
-    slug: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub slug: String,
+}
+
+/// Used to filter a secret's tags with InfisicalSecret::query_tags().
+///
+/// Every field that is set has to match for a tag to be returned, and fields left as None are ignored,
+/// so the default (empty) query matches every tag.
+///
+/// # Example
+/// ```
+/// use infisical_rs::infisical::secrets::utils::TagQuery;
+///
+/// let query = TagQuery {
+///     slug: Some("payments".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct TagQuery {
+    pub id: Option<String>,
+    pub color: Option<String>,
+    pub name: Option<String>,
+    pub slug: Option<String>,
+}
+
+impl TagQuery {
+    pub fn matches(&self, tag: &InfisicalSecretTag) -> bool {
+        let field_matches = |filter: &Option<String>, value: Option<&str>| {
+            filter.as_deref().is_none_or(|f| Some(f) == value)
+        };
+
+        field_matches(&self.id, Some(&tag.id))
+            && field_matches(&self.color, tag.color.as_deref())
+            && field_matches(&self.name, tag.name.as_deref())
+            && field_matches(&self.slug, Some(&tag.slug))
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Whether a secret is the shared value every member of the project sees, or a personal override
+/// of said shared value that only the user that created it sees.
+///
+/// Personal overrides belong to users: the personal secret calls need a user's access token, and listings made
+/// with a machine identity's token only ever contain shared secrets.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash, strum::Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SecretType {
+    #[default]
+    Shared,
+    Personal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct InfisicalSecretMetadata {
     pub key: String,
     pub value: String,
@@ -31,14 +86,18 @@ pub struct InfisicalSecretData {
     // pub metadata: Option<String>,
     pub secret_comment: String,
     pub secret_key: String,
+    #[serde(default)]
     pub secret_metadata: Vec<InfisicalSecretMetadata>,
-    pub secret_reminder_note: String,
-    pub secret_reminder_repeat_days: u64,
+    // both of these come back as null when no reminder is configured
+    #[serde(default)]
+    pub secret_reminder_note: Option<String>,
+    #[serde(default)]
+    pub secret_reminder_repeat_days: Option<u64>,
     pub secret_value: String,
     pub skip_multiline_encoding: Option<bool>,
     pub tags: Vec<InfisicalSecretTag>,
     #[serde(rename(serialize = "type_", deserialize = "type"))]
-    pub type_: String,
+    pub type_: SecretType,
     pub updated_at: String,
     pub version: u64,
     pub workspace: String,
@@ -68,6 +127,13 @@ impl Zeroize for InfisicalSecretData {
     }
 }
 
+// nothing sensitive in here, but InfisicalSecretData needs every field to be zeroizable
+impl Zeroize for SecretType {
+    fn zeroize(&mut self) {
+        *self = SecretType::default();
+    }
+}
+
 impl Zeroize for InfisicalSecretMetadata {
     fn zeroize(&mut self) {
         self.key.zeroize();
@@ -91,40 +157,367 @@ pub struct InfisicalSecret {
 
 // mainly just convenience functions
 impl InfisicalSecret {
+    /// get_secret_metadata()
+    ///
+    /// Returns every metadata entry on the secret with the given key.
     pub fn get_secret_metadata(&self, metadata_key: &str) -> Vec<InfisicalSecretMetadata> {
         self.data
             .expose_secret()
             .secret_metadata
-            .clone()
-            .into_iter()
+            .iter()
             .filter(|metadata_entry| metadata_entry.key == metadata_key)
+            .cloned()
             .collect()
     }
 
-    pub fn search_tags(
-        &self,
-        tag_id: Option<&str>,
-        tag_color: Option<&str>,
-        tag_name: Option<&str>,
-        tag_slug: Option<&str>,
-    ) -> Vec<InfisicalSecretTag> {
+    /// has_metadata()
+    ///
+    /// Checks whether the secret has a metadata entry with the given key, and (if given) the given value.
+    pub fn has_metadata(&self, metadata_key: &str, metadata_value: Option<&str>) -> bool {
+        self.data
+            .expose_secret()
+            .secret_metadata
+            .iter()
+            .any(|metadata_entry| {
+                metadata_entry.key == metadata_key
+                    && metadata_value.is_none_or(|value| metadata_entry.value == value)
+            })
+    }
+
+    /// query_tags()
+    ///
+    /// Returns the secret's tags matching every filter set in the given TagQuery.
+    pub fn query_tags(&self, query: &TagQuery) -> Vec<InfisicalSecretTag> {
         self.data
             .expose_secret()
             .tags
-            .clone()
-            .into_iter()
-            .filter(|entry| match tag_id {
-                // if the user specified a tag ig, we can assume they mean to return a single specific tag
-                // otherwise, we can't necessarily (i think) assume a tag color, name, or slug are unique
-                // so we return a vec of tags and match on whatever user input
-                Some(specific_id) => entry.id.eq(specific_id),
+            .iter()
+            .filter(|tag| query.matches(tag))
+            .cloned()
+            .collect()
+    }
+
+    pub fn secret_key(&self) -> &str {
+        &self.data.expose_secret().secret_key
+    }
+
+    pub fn secret_value(&self) -> &str {
+        &self.data.expose_secret().secret_value
+    }
+
+    pub fn tags(&self) -> &Vec<InfisicalSecretTag> {
+        &self.data.expose_secret().tags
+    }
+
+    pub fn secret_type(&self) -> SecretType {
+        self.data.expose_secret().type_
+    }
+
+    pub fn secret_metadata(&self) -> &Vec<InfisicalSecretMetadata> {
+        &self.data.expose_secret().secret_metadata
+    }
+
+    pub fn secret_reminder_note(&self) -> Option<&str> {
+        self.data.expose_secret().secret_reminder_note.as_deref()
+    }
+
+    pub fn secret_reminder_repeat_days(&self) -> Option<u64> {
+        self.data.expose_secret().secret_reminder_repeat_days
+    }
+
+    pub fn version(&self) -> u64 {
+        self.data.expose_secret().version
+    }
+}
+
+/// Where a given set of secrets lives: a project (workspace), one of its environments, and a folder path.
+///
+/// # Example
+/// ```
+/// use infisical_rs::infisical::secrets::utils::SecretScope;
+///
+/// let scope = SecretScope::new("<workspace id>", "staging", Some("/payments"));
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct SecretScope {
+    pub workspace_id: String,
+    pub environment: String,
+    pub secret_path: String,
+}
+
+impl SecretScope {
+    /// secret_path defaults to the environment's root folder ("/") if left as None
+    pub fn new(workspace_id: &str, environment: &str, secret_path: Option<&str>) -> Self {
+        Self {
+            workspace_id: workspace_id.to_string(),
+            environment: environment.to_string(),
+            secret_path: secret_path.unwrap_or("/").to_string(),
+        }
+    }
+}
+
+/// Options for list_secrets().
+///
+/// Fields:
+/// - tag_slugs: only return secrets carrying every one of the given tags (filtered server-side)
+/// - recursive: also return secrets from folders below the scope's secret path
+/// - include_imports: also return secrets imported into the scope's secret path
+/// - expand_secret_references: expand ${...} secret references in returned values
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ListSecretsOptions {
+    pub tag_slugs: Vec<String>,
+    pub recursive: bool,
+    pub include_imports: bool,
+    pub expand_secret_references: bool,
+}
+
+/// Request body for update_secret(). Any field left as None is left untouched.
+///
+/// Note: tag_ids replaces the secret's tags outright. attach_secret_tags() and detach_secret_tags()
+/// are usually what you want instead.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct UpdateSecretOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_secret_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_multiline_encoding: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_ids: Option<Vec<String>>,
+    /// SecretType::Personal updates the current user's personal override, which needs a user's access token
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<SecretType>,
+    /// replaces the secret's metadata outright
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_metadata: Option<Vec<InfisicalSecretMetadata>>,
+    /// Some(None) clears the reminder note
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_reminder_note: Option<Option<String>>,
+    /// Some(None) clears the reminder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_reminder_repeat_days: Option<Option<u64>>,
+}
+
+/// Request body for create_secret().
+///
+/// Creating a secret with `type_: SecretType::Personal` creates a personal override of an existing shared secret
+/// for the current user, and requires the shared secret to exist first as well as a user's access token.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateSecretOptions {
+    pub secret_value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_multiline_encoding: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tag_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub secret_metadata: Vec<InfisicalSecretMetadata>,
+    #[serde(rename = "type")]
+    pub type_: SecretType,
+}
+
+/// A single entry for create_secrets_batch().
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct BatchCreateSecret {
+    pub secret_key: String,
+    #[serde(flatten)]
+    pub options: CreateSecretOptions,
+}
+
+/// A single entry for update_secrets_batch().
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct BatchUpdateSecret {
+    pub secret_key: String,
+    #[serde(flatten)]
+    pub options: UpdateSecretOptions,
+}
+
+/// What to do when a secret being moved or copied already exists at the destination.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SecretConflictBehavior {
+    /// leave the destination secret as-is
+    #[default]
+    Skip,
+    /// replace the destination secret's value, comment, tags, and metadata with the source secret's
+    Overwrite,
+}
+
+/// Options for move_secrets() and copy_secrets().
+///
+/// Fields:
+/// - secret_keys: the keys of the secrets to move/copy. None moves/copies every shared secret in the source scope
+/// - on_conflict: what to do when a secret already exists at the destination
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TransferSecretsOptions {
+    pub secret_keys: Option<Vec<String>>,
+    pub on_conflict: SecretConflictBehavior,
+}
+
+/// Returned by move_secrets(). Infisical only reports whether either side changed at all.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct MoveSecretsResult {
+    pub is_source_updated: bool,
+    pub is_destination_updated: bool,
+}
+
+/// Returned by copy_secrets(), listing what happened to each copied key.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct CopySecretsResult {
+    pub created: Vec<String>,
+    pub overwritten: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+// request/response envelopes for the v3 raw secrets endpoints
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SecretRequestBody<'a, T: Serialize> {
+    pub workspace_id: &'a str,
+    pub environment: &'a str,
+    pub secret_path: &'a str,
+    #[serde(flatten)]
+    pub options: &'a T,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SecretResponse {
+    pub secret: InfisicalSecretData,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SecretsResponse {
+    pub secrets: Vec<InfisicalSecretData>,
+}
+
+// writes to a scope protected by an approval policy return the change request they opened instead
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum SecretWriteResponse<T> {
+    Applied(T),
+    PendingApproval { approval: Box<SecretChangeRequest> },
+}
+
+// a list_secrets() response, with the ETag to make the next listing conditional on
+pub(crate) struct ListedSecrets {
+    pub secrets: Vec<InfisicalSecret>,
+    pub etag: Option<String>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct BatchSecretsBody<'a, T: Serialize> {
+    pub secrets: &'a [T],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BatchDeleteSecret<'a> {
+    pub secret_key: &'a str,
+    #[serde(rename = "type")]
+    pub type_: SecretType,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MoveSecretsBody<'a> {
+    pub project_slug: &'a str,
+    pub source_environment: &'a str,
+    pub source_secret_path: &'a str,
+    pub destination_environment: &'a str,
+    pub destination_secret_path: &'a str,
+    pub secret_ids: Vec<String>,
+    pub should_overwrite: bool,
+}
+
+pub mod secret_util_functions {
+    use std::collections::HashMap;
+
+    use super::{InfisicalSecret, SecretType};
+
+    /// filter_secrets_by_metadata()
+    ///
+    /// Returns the secrets carrying a metadata entry with the given key, and (if given) the given value,
+    /// e.g. every secret with `owner=payments`.
+    pub fn filter_secrets_by_metadata<'a>(
+        secrets: &'a [InfisicalSecret],
+        metadata_key: &str,
+        metadata_value: Option<&str>,
+    ) -> Vec<&'a InfisicalSecret> {
+        secrets
+            .iter()
+            .filter(|secret| secret.has_metadata(metadata_key, metadata_value))
+            .collect()
+    }
+
+    /// resolve_secret_overrides()
+    ///
+    /// Collapses a list of shared secrets and personal overrides (e.g. from list_secrets()) down to one secret per key,
+    /// picking the personal override when there is one and the shared value otherwise. This mirrors how the Infisical
+    /// CLI resolves secret values.
+    ///
+    /// Secrets keep the order their key first appeared in.
+    pub fn resolve_secret_overrides(secrets: Vec<InfisicalSecret>) -> Vec<InfisicalSecret> {
+        let mut resolved: Vec<InfisicalSecret> = Vec::with_capacity(secrets.len());
+        let mut key_positions: HashMap<String, usize> = HashMap::with_capacity(secrets.len());
+
+        for secret in secrets {
+            match key_positions.get(secret.secret_key()) {
+                Some(&position) => {
+                    if secret.secret_type() == SecretType::Personal {
+                        resolved[position] = secret;
+                    }
+                }
                 None => {
-                    entry.color.eq(tag_color.unwrap_or_default())
-                        || entry.name.eq(tag_name.unwrap_or_default())
-                        || entry.slug.eq(tag_slug.unwrap_or_default())
+                    key_positions.insert(secret.secret_key().to_string(), resolved.len());
+                    resolved.push(secret);
                 }
-            })
-            .collect()
+            }
+        }
+
+        resolved
+    }
+
+    /// is_secret_path_within()
+    ///
+    /// Checks whether `secret_path` is `parent_path` itself, or a folder nested below it. Trailing slashes are ignored,
+    /// and `/payments` isn't considered within `/pay`.
+    pub fn is_secret_path_within(secret_path: &str, parent_path: &str) -> bool {
+        let secret_path = secret_path.trim_end_matches('/');
+        let parent_path = parent_path.trim_end_matches('/');
+
+        parent_path.is_empty()
+            || secret_path == parent_path
+            || secret_path
+                .strip_prefix(parent_path)
+                .is_some_and(|rest| rest.starts_with('/'))
+    }
+
+    pub fn construct_batch_raw_secrets_endpoint_url(host: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/secrets/batch/raw",
+            host_url = host,
+            version = "v3",
+        )
+    }
+
+    pub fn construct_raw_secrets_endpoint_url(host: &str, secret_key: Option<&str>) -> String {
+        let is_secret_key =
+            secret_key.map_or_else(|| "".to_string(), |secret_key| format!("/{secret_key}"));
+
+        format!(
+            "{host_url}/api/{version}/secrets/raw{secret_key}",
+            host_url = host,
+            version = "v3",
+            secret_key = is_secret_key
+        )
     }
 }
 
@@ -138,6 +531,11 @@ pub async fn get_secret(
     secret_path: Option<&str>,
     // ) -> Result<InfisicalSecret, Box<dyn std::error::Error>> {
 ) -> Result<InfisicalSecret, Box<dyn std::error::Error>> {
+    // catch malformed environment slugs here rather than waiting on a 404 from Infisical
+    if let Some(environment_slug) = environment {
+        validate_environment_slug(environment_slug)?;
+    }
+
     let api_request: serde_json::Value = client
           .get(format!(
               "{host}/api/{endpoint_api_version}/{endpoint}/{secret}?workspaceId={workspace_id}&environment={secret_environment}&secretPath={secret_path}",
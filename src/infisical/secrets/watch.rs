@@ -0,0 +1,222 @@
+/// Secret watching:
+///
+/// Polls a scope (project, environment, and secret path) for changes, comparing secret versions between polls,
+/// and yields what changed as a Stream.
+use std::{collections::HashMap, time::Duration};
+
+use futures_util::{Stream, stream};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken,
+    secrets::{
+        error_handling::SecretsError,
+        utils::{InfisicalSecret, ListSecretsOptions, SecretScope, SecretType},
+    },
+};
+
+/// Options for watch_secrets().
+///
+/// Fields:
+/// - interval: how long to wait between polls, including after a failed one. Raised to
+///   `WatchSecretsOptions::MIN_INTERVAL` if shorter (including zero)
+/// - list_options: how the scope is listed on every poll, see list_secrets()
+#[derive(Debug, Clone)]
+pub struct WatchSecretsOptions {
+    pub interval: Duration,
+    pub list_options: ListSecretsOptions,
+}
+
+impl WatchSecretsOptions {
+    pub const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// The interval watch_secrets() actually waits between polls.
+    pub fn poll_interval(&self) -> Duration {
+        self.interval.max(Self::MIN_INTERVAL)
+    }
+}
+
+impl Default for WatchSecretsOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(30),
+            list_options: ListSecretsOptions::default(),
+        }
+    }
+}
+
+/// A secret that disappeared from the watched scope, as it was last seen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemovedSecret {
+    pub secret_key: String,
+    pub secret_type: SecretType,
+    pub version: u64,
+}
+
+/// What changed in a watched scope between two polls.
+///
+/// Fields:
+/// - added: secrets that weren't there before
+/// - updated: secrets whose version changed, e.g. rotated values
+/// - removed: secrets that aren't there anymore
+pub struct SecretChange {
+    pub scope: SecretScope,
+    pub added: Vec<InfisicalSecret>,
+    pub updated: Vec<InfisicalSecret>,
+    pub removed: Vec<RemovedSecret>,
+}
+
+impl SecretChange {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// The versions of a scope's secrets at a given point in time. Secret values aren't kept.
+///
+/// A shared secret and its personal override are tracked separately.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecretVersions {
+    versions: HashMap<(String, SecretType), u64>,
+}
+
+impl SecretVersions {
+    pub fn new(secrets: &[InfisicalSecret]) -> Self {
+        Self {
+            versions: secrets
+                .iter()
+                .map(|secret| {
+                    (
+                        (secret.secret_key().to_string(), secret.secret_type()),
+                        secret.version(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// changes()
+    ///
+    /// Compares `secrets` (a newer listing of `scope`) against these versions.
+    pub fn changes(&self, scope: &SecretScope, secrets: Vec<InfisicalSecret>) -> SecretChange {
+        let current = SecretVersions::new(&secrets);
+
+        let mut change = SecretChange {
+            scope: scope.clone(),
+            added: Vec::new(),
+            updated: Vec::new(),
+            removed: self
+                .versions
+                .iter()
+                .filter(|(secret, _)| !current.versions.contains_key(*secret))
+                .map(|((secret_key, secret_type), version)| RemovedSecret {
+                    secret_key: secret_key.clone(),
+                    secret_type: *secret_type,
+                    version: *version,
+                })
+                .collect(),
+        };
+
+        for secret in secrets {
+            let previous_version = self
+                .versions
+                .get(&(secret.secret_key().to_string(), secret.secret_type()));
+
+            match previous_version {
+                None => change.added.push(secret),
+                Some(&version) if version != secret.version() => change.updated.push(secret),
+                Some(_) => {}
+            }
+        }
+
+        change
+            .removed
+            .sort_by(|a, b| a.secret_key.cmp(&b.secret_key));
+
+        change
+    }
+}
+
+// what a watch carries over from one poll to the next
+#[derive(Default)]
+struct WatchState {
+    versions: Option<SecretVersions>,
+    etag: Option<String>,
+    polled: bool,
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// Secret watching
+impl UniversalAuthAccessToken {
+    /// watch_secrets()
+    ///
+    /// Polls `scope` every `options.interval`, yielding a SecretChange whenever secrets were added, updated
+    /// (their version changed), or removed. The first poll only records the current versions.
+    ///
+    /// Polls are conditional on the ETag of the previous listing where Infisical provides one, so unchanged scopes
+    /// aren't downloaded again. A failed poll is yielded as an error, and watching carries on at the next interval;
+    /// drop the stream to stop watching.
+    ///
+    /// # Example
+    /// ```ignore
+    /// use futures_util::StreamExt;
+    ///
+    /// let scope = SecretScope::new("<workspace id>", "prod", Some("/db"));
+    /// let mut changes =
+    ///     std::pin::pin!(access_token.watch_secrets(host, &client, &scope, WatchSecretsOptions::default()));
+    ///
+    /// while let Some(change) = changes.next().await {
+    ///     match change {
+    ///         Ok(change) => reload_config(&change.updated),
+    ///         Err(error) => eprintln!("failed to poll secrets: {error}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn watch_secrets<'a>(
+        &'a self,
+        host: &'a str,
+        client: &'a reqwest::Client,
+        scope: &'a SecretScope,
+        options: WatchSecretsOptions,
+    ) -> impl Stream<Item = Result<SecretChange, SecretsError>> + 'a {
+        stream::unfold(WatchState::default(), move |mut state| {
+            let options = options.clone();
+
+            async move {
+                loop {
+                    if state.polled {
+                        tokio::time::sleep(options.poll_interval()).await;
+                    }
+                    state.polled = true;
+
+                    let listed = match self
+                        .send_list_secrets_request(
+                            host,
+                            client,
+                            scope,
+                            &options.list_options,
+                            state.etag.as_deref(),
+                        )
+                        .await
+                    {
+                        Ok(Some(listed)) => listed,
+                        // nothing changed since the last poll
+                        Ok(None) => continue,
+                        Err(error) => return Some((Err(error), state)),
+                    };
+
+                    let versions = SecretVersions::new(&listed.secrets);
+                    state.etag = listed.etag;
+
+                    let Some(previous) = state.versions.replace(versions) else {
+                        continue;
+                    };
+
+                    let change = previous.changes(scope, listed.secrets);
+                    if !change.is_empty() {
+                        return Some((Ok(change), state));
+                    }
+                }
+            }
+        })
+    }
+}
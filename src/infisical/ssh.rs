@@ -0,0 +1,515 @@
+/// SSH:
+///
+/// Infisical's SSH certificate authorities and certificate templates, and the short-lived user/host
+/// certificates issued through them.
+use reqwest::StatusCode;
+
+use error_handling::SshError;
+use utils::{
+    ssh_util_functions::{construct_project_ssh_cas_endpoint_url, construct_ssh_endpoint_url},
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, pki::utils::PkiKeyAlgorithm,
+    utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the SSH module, such as SshCertificateTemplate and IssuedSshCertificate.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// SSH certificate authority management
+impl UniversalAuthAccessToken {
+    /// list_ssh_certificate_authorities()
+    ///
+    /// Lists the SSH CAs in a given project.
+    pub async fn list_ssh_certificate_authorities(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+    ) -> Result<Vec<SshCertificateAuthority>, SshError> {
+        let endpoint_url = construct_project_ssh_cas_endpoint_url(host, project_id);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SshError::ProjectRequestError {
+                operation: "list_ssh_certificate_authorities".to_string(),
+                project_id: project_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SshCertificateAuthoritiesResponse>(&bytes)?.cas)
+    }
+
+    /// get_ssh_certificate_authority()
+    ///
+    /// Retrieves a single SSH CA by its id.
+    pub async fn get_ssh_certificate_authority(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ssh_ca_id: &str,
+    ) -> Result<SshCertificateAuthority, SshError> {
+        let endpoint_url = construct_ssh_endpoint_url(host, &format!("ca/{ssh_ca_id}"));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_ca_error(
+                "get_ssh_certificate_authority",
+                ssh_ca_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SshCertificateAuthorityResponse>(&bytes)?.ca)
+    }
+
+    /// create_ssh_certificate_authority()
+    ///
+    /// Creates an SSH CA, generating its key pair or importing an existing one.
+    pub async fn create_ssh_certificate_authority(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateSshCertificateAuthorityOptions,
+    ) -> Result<SshCertificateAuthority, SshError> {
+        let endpoint_url = construct_ssh_endpoint_url(host, "ca");
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(SshError::ProjectRequestError {
+                operation: "create_ssh_certificate_authority".to_string(),
+                project_id: options.project_id.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SshCertificateAuthorityResponse>(&bytes)?.ca)
+    }
+
+    /// delete_ssh_certificate_authority()
+    ///
+    /// Deletes an SSH CA, along with its certificate templates. Certificates it issued stay valid until they
+    /// expire, unless servers stop trusting the CA's public key.
+    pub async fn delete_ssh_certificate_authority(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ssh_ca_id: &str,
+    ) -> Result<SshCertificateAuthority, SshError> {
+        let endpoint_url = construct_ssh_endpoint_url(host, &format!("ca/{ssh_ca_id}"));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_ca_error(
+                "delete_ssh_certificate_authority",
+                ssh_ca_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SshCertificateAuthorityResponse>(&bytes)?.ca)
+    }
+
+    /// get_ssh_certificate_authority_public_key()
+    ///
+    /// Retrieves an SSH CA's public key in OpenSSH format, ready to be written into sshd's `TrustedUserCAKeys`.
+    pub async fn get_ssh_certificate_authority_public_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ssh_ca_id: &str,
+    ) -> Result<String, SshError> {
+        let endpoint_url = construct_ssh_endpoint_url(host, &format!("ca/{ssh_ca_id}/public-key"));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_ca_error(
+                "get_ssh_certificate_authority_public_key",
+                ssh_ca_id,
+                error_response,
+            ));
+        }
+
+        // the public key is returned as plain text rather than JSON
+        Ok(response.text().await?.trim().to_string())
+    }
+
+    /// list_ssh_certificate_templates()
+    ///
+    /// Lists the certificate templates of a given SSH CA.
+    pub async fn list_ssh_certificate_templates(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        ssh_ca_id: &str,
+    ) -> Result<Vec<SshCertificateTemplate>, SshError> {
+        let endpoint_url =
+            construct_ssh_endpoint_url(host, &format!("ca/{ssh_ca_id}/certificate-templates"));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_ca_error(
+                "list_ssh_certificate_templates",
+                ssh_ca_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(
+            serde_json::from_slice::<SshCertificateTemplatesResponse>(&bytes)?
+                .certificate_templates,
+        )
+    }
+
+    /// get_ssh_certificate_template()
+    ///
+    /// Retrieves a single SSH certificate template by its id.
+    pub async fn get_ssh_certificate_template(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        template_id: &str,
+    ) -> Result<SshCertificateTemplate, SshError> {
+        let endpoint_url =
+            construct_ssh_endpoint_url(host, &format!("certificate-templates/{template_id}"));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_template_error(
+                "get_ssh_certificate_template",
+                template_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SshCertificateTemplate>(&bytes)?)
+    }
+
+    /// create_ssh_certificate_template()
+    ///
+    /// Creates a certificate template on an SSH CA.
+    pub async fn create_ssh_certificate_template(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateSshCertificateTemplateOptions,
+    ) -> Result<SshCertificateTemplate, SshError> {
+        let endpoint_url = construct_ssh_endpoint_url(host, "certificate-templates");
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_template_error(
+                "create_ssh_certificate_template",
+                &options.name,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SshCertificateTemplate>(&bytes)?)
+    }
+
+    /// update_ssh_certificate_template()
+    ///
+    /// Updates an SSH certificate template. Any field left as None in `options` is left untouched.
+    pub async fn update_ssh_certificate_template(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        template_id: &str,
+        options: &UpdateSshCertificateTemplateOptions,
+    ) -> Result<SshCertificateTemplate, SshError> {
+        let endpoint_url =
+            construct_ssh_endpoint_url(host, &format!("certificate-templates/{template_id}"));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_template_error(
+                "update_ssh_certificate_template",
+                template_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SshCertificateTemplate>(&bytes)?)
+    }
+
+    /// delete_ssh_certificate_template()
+    ///
+    /// Deletes an SSH certificate template.
+    pub async fn delete_ssh_certificate_template(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        template_id: &str,
+    ) -> Result<SshCertificateTemplate, SshError> {
+        let endpoint_url =
+            construct_ssh_endpoint_url(host, &format!("certificate-templates/{template_id}"));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_template_error(
+                "delete_ssh_certificate_template",
+                template_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SshCertificateTemplate>(&bytes)?)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+/// SSH certificate issuance
+impl UniversalAuthAccessToken {
+    /// issue_ssh_certificate()
+    ///
+    /// Issues an SSH certificate through a certificate template, with Infisical generating a `key_algorithm`
+    /// key pair for it. The private key is only ever returned here.
+    pub async fn issue_ssh_certificate(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &SshCertificateOptions,
+        key_algorithm: PkiKeyAlgorithm,
+    ) -> Result<IssuedSshCertificate, SshError> {
+        let endpoint_url = construct_ssh_endpoint_url(host, "certificates/issue");
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&SshCertificateRequestBody {
+                options,
+                key_algorithm: Some(key_algorithm),
+                public_key: None,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_certificate_error(
+                "issue_ssh_certificate",
+                options,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<IssuedSshCertificate>(&bytes)?)
+    }
+
+    /// sign_ssh_public_key()
+    ///
+    /// Signs an existing public key (OpenSSH format) through a certificate template, for when the private key
+    /// should never leave the requester.
+    pub async fn sign_ssh_public_key(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &SshCertificateOptions,
+        public_key: &str,
+    ) -> Result<SignedSshCertificate, SshError> {
+        let endpoint_url = construct_ssh_endpoint_url(host, "certificates/sign");
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&SshCertificateRequestBody {
+                options,
+                key_algorithm: None,
+                public_key: Some(public_key),
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(ssh_certificate_error(
+                "sign_ssh_public_key",
+                options,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<SignedSshCertificate>(&bytes)?)
+    }
+}
+
+fn ssh_ca_error(operation: &str, ssh_ca_id: &str, error: ApiResponse) -> SshError {
+    SshError::SshCertificateAuthorityError {
+        operation: operation.to_string(),
+        ssh_ca_id: ssh_ca_id.to_string(),
+        error,
+    }
+}
+
+fn ssh_template_error(operation: &str, certificate_template: &str, error: ApiResponse) -> SshError {
+    SshError::SshCertificateTemplateError {
+        operation: operation.to_string(),
+        certificate_template: certificate_template.to_string(),
+        error,
+    }
+}
+
+fn ssh_certificate_error(
+    operation: &str,
+    options: &SshCertificateOptions,
+    error: ApiResponse,
+) -> SshError {
+    SshError::SshCertificateError {
+        operation: operation.to_string(),
+        certificate_template_id: options.certificate_template_id.clone(),
+        principals: options.principals.clone(),
+        error,
+    }
+}
@@ -0,0 +1,57 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum SshError {
+    #[error(
+        "Ssh::{operation}(): 
+        Project ID: {project_id}
+        Err: {error}"
+    )]
+    ProjectRequestError {
+        operation: String,
+        project_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Ssh::{operation}(): 
+        SSH CA ID: {ssh_ca_id}
+        Err: {error}"
+    )]
+    SshCertificateAuthorityError {
+        operation: String,
+        ssh_ca_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Ssh::{operation}(): 
+        Certificate template: {certificate_template}
+        Err: {error}"
+    )]
+    SshCertificateTemplateError {
+        operation: String,
+        certificate_template: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Ssh::{operation}(): 
+        Certificate template ID: {certificate_template_id}
+        Principals: {principals:?}
+        Err: {error}"
+    )]
+    SshCertificateError {
+        operation: String,
+        certificate_template_id: String,
+        principals: Vec<String>,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
@@ -0,0 +1,238 @@
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::infisical::pki::utils::PkiKeyAlgorithm;
+
+/// Whether Infisical generates the SSH CA's key pair (Internal), or it's imported (External).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SshCaKeySource {
+    #[default]
+    Internal,
+    External,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SshCaStatus {
+    #[default]
+    Active,
+    Disabled,
+}
+
+/// An SSH certificate authority.
+///
+/// Fields:
+/// - public_key: the CA's public key, what servers list in `TrustedUserCAKeys` (or clients in `@cert-authority`)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SshCertificateAuthority {
+    pub id: String,
+    #[serde(default)]
+    pub project_id: String,
+    #[serde(default)]
+    pub friendly_name: String,
+    #[serde(default)]
+    pub status: SshCaStatus,
+    pub key_algorithm: PkiKeyAlgorithm,
+    #[serde(default)]
+    pub public_key: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// Request body for create_ssh_certificate_authority(). The key pair only needs to be given for
+/// SshCaKeySource::External.
+///
+/// Note: the private key stays redacted in Debug output, and is only exposed when serialized into the request.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateSshCertificateAuthorityOptions {
+    pub project_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<String>,
+    pub key_algorithm: PkiKeyAlgorithm,
+    pub key_source: SshCaKeySource,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_private_key"
+    )]
+    pub private_key: Option<SecretString>,
+}
+
+fn serialize_private_key<S: Serializer>(
+    private_key: &Option<SecretString>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    private_key
+        .as_ref()
+        .map(|private_key| private_key.expose_secret())
+        .serialize(serializer)
+}
+
+/// An SSH certificate template: what certificates issued through it may look like.
+///
+/// Fields:
+/// - ttl/max_ttl: the default and maximum certificate lifetimes, in Infisical's duration format (e.g. "8h")
+/// - allowed_users/allowed_hosts: the principals certificates may be issued for, supporting `*` wildcards
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SshCertificateTemplate {
+    pub id: String,
+    pub ssh_ca_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub status: SshCaStatus,
+    pub ttl: String,
+    #[serde(rename(serialize = "max_ttl", deserialize = "maxTTL"))]
+    pub max_ttl: String,
+    #[serde(default)]
+    pub allowed_users: Vec<String>,
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+    #[serde(default)]
+    pub allow_user_certificates: bool,
+    #[serde(default)]
+    pub allow_host_certificates: bool,
+    #[serde(default)]
+    pub allow_custom_key_ids: bool,
+}
+
+/// Request body for create_ssh_certificate_template().
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateSshCertificateTemplateOptions {
+    pub ssh_ca_id: String,
+    pub name: String,
+    pub ttl: String,
+    #[serde(rename = "maxTTL")]
+    pub max_ttl: String,
+    pub allowed_users: Vec<String>,
+    pub allowed_hosts: Vec<String>,
+    pub allow_user_certificates: bool,
+    pub allow_host_certificates: bool,
+    pub allow_custom_key_ids: bool,
+}
+
+/// Request body for update_ssh_certificate_template(). Any field left as None is left untouched by Infisical.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct UpdateSshCertificateTemplateOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SshCaStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+    #[serde(rename = "maxTTL", skip_serializing_if = "Option::is_none")]
+    pub max_ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_hosts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_user_certificates: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_host_certificates: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_custom_key_ids: Option<bool>,
+}
+
+/// User certificates log a user into hosts; host certificates prove a host's identity to users.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SshCertificateType {
+    #[default]
+    User,
+    Host,
+}
+
+/// Input for issue_ssh_certificate() and sign_ssh_public_key().
+///
+/// - principals: the usernames (user certificates) or hostnames (host certificates) the certificate is valid for
+/// - ttl: defaults to the template's TTL, can't exceed its max TTL
+/// - key_id: the certificate's key id, as logged by sshd; only honoured if the template allows custom key ids
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct SshCertificateOptions {
+    pub certificate_template_id: String,
+    pub cert_type: SshCertificateType,
+    pub principals: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+}
+
+/// An SSH certificate issued by Infisical, along with the key pair it generated for it.
+///
+/// signed_key is the certificate itself, in OpenSSH format (what goes into e.g. `id_ed25519-cert.pub`).
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuedSshCertificate {
+    pub serial_number: String,
+    pub signed_key: String,
+    pub public_key: String,
+    pub private_key: SecretString,
+    pub key_algorithm: PkiKeyAlgorithm,
+}
+
+/// An SSH certificate signed from an existing public key, in OpenSSH format.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct SignedSshCertificate {
+    pub serial_number: String,
+    pub signed_key: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SshCertificateRequestBody<'a> {
+    #[serde(flatten)]
+    pub options: &'a SshCertificateOptions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_algorithm: Option<PkiKeyAlgorithm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SshCertificateAuthorityResponse {
+    pub ca: SshCertificateAuthority,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SshCertificateAuthoritiesResponse {
+    pub cas: Vec<SshCertificateAuthority>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SshCertificateTemplatesResponse {
+    pub certificate_templates: Vec<SshCertificateTemplate>,
+}
+
+pub mod ssh_util_functions {
+    pub fn construct_ssh_endpoint_url(host: &str, path: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/ssh/{path}",
+            host_url = host,
+            version = "v1",
+        )
+    }
+
+    pub fn construct_project_ssh_cas_endpoint_url(host: &str, project_id: &str) -> String {
+        format!(
+            "{host_url}/api/{version}/workspace/{project_id}/ssh-cas",
+            host_url = host,
+            version = "v1",
+        )
+    }
+}
@@ -0,0 +1,245 @@
+/// Tags:
+///
+/// Management of a project's (workspace's) tags. Attaching tags to secrets, and filtering secrets
+/// by tag, lives in the secrets module.
+use reqwest::StatusCode;
+
+use error_handling::TagError;
+use utils::{tag_util_functions::construct_tag_endpoint_url, *};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// Structs and utility functions used by the tags module, such as the ProjectTag struct.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Tag management for a given project (workspace)
+impl UniversalAuthAccessToken {
+    /// list_tags()
+    ///
+    /// Lists every tag defined in a given project.
+    pub async fn list_tags(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+    ) -> Result<Vec<ProjectTag>, TagError> {
+        let endpoint_url = construct_tag_endpoint_url(host, workspace_id, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(TagError::ListTagsError {
+                workspace_id: workspace_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectTagsResponse>(&bytes)?.workspace_tags)
+    }
+
+    /// get_tag()
+    ///
+    /// Retrieves a single tag by its id.
+    pub async fn get_tag(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        tag_id: &str,
+    ) -> Result<ProjectTag, TagError> {
+        let endpoint_url = construct_tag_endpoint_url(host, workspace_id, Some(tag_id));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(TagError::GetTagError {
+                workspace_id: workspace_id.to_string(),
+                tag: tag_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectTagResponse>(&bytes)?.workspace_tag)
+    }
+
+    /// get_tag_by_slug()
+    ///
+    /// Retrieves a single tag by its slug.
+    pub async fn get_tag_by_slug(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        tag_slug: &str,
+    ) -> Result<ProjectTag, TagError> {
+        let endpoint_url =
+            construct_tag_endpoint_url(host, workspace_id, Some(&format!("slug/{tag_slug}")));
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(TagError::GetTagError {
+                workspace_id: workspace_id.to_string(),
+                tag: tag_slug.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectTagResponse>(&bytes)?.workspace_tag)
+    }
+
+    /// create_tag()
+    ///
+    /// Creates a new tag in a given project.
+    pub async fn create_tag(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        options: &CreateTagOptions,
+    ) -> Result<ProjectTag, TagError> {
+        let endpoint_url = construct_tag_endpoint_url(host, workspace_id, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(TagError::CreateTagError {
+                workspace_id: workspace_id.to_string(),
+                slug: options.slug.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectTagResponse>(&bytes)?.workspace_tag)
+    }
+
+    /// update_tag()
+    ///
+    /// Updates the slug and/or color of a given tag.
+    pub async fn update_tag(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        tag_id: &str,
+        options: &UpdateTagOptions,
+    ) -> Result<ProjectTag, TagError> {
+        let endpoint_url = construct_tag_endpoint_url(host, workspace_id, Some(tag_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(options)
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(TagError::UpdateTagError {
+                workspace_id: workspace_id.to_string(),
+                tag_id: tag_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectTagResponse>(&bytes)?.workspace_tag)
+    }
+
+    /// delete_tag()
+    ///
+    /// Deletes a given tag, detaching it from every secret it was attached to. Returns the deleted tag.
+    pub async fn delete_tag(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        workspace_id: &str,
+        tag_id: &str,
+    ) -> Result<ProjectTag, TagError> {
+        let endpoint_url = construct_tag_endpoint_url(host, workspace_id, Some(tag_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(TagError::DeleteTagError {
+                workspace_id: workspace_id.to_string(),
+                tag_id: tag_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<ProjectTagResponse>(&bytes)?.workspace_tag)
+    }
+}
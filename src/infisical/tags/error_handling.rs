@@ -0,0 +1,69 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum TagError {
+    #[error(
+        "Tags::list_tags(): 
+        Workspace ID: {workspace_id}
+        Err: {error}"
+    )]
+    ListTagsError {
+        workspace_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Tags::get_tag(): 
+        Workspace ID: {workspace_id}
+        Tag: {tag}
+        Err: {error}"
+    )]
+    GetTagError {
+        workspace_id: String,
+        tag: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Tags::create_tag(): 
+        Workspace ID: {workspace_id}
+        Tag slug: {slug}
+        Err: {error}"
+    )]
+    CreateTagError {
+        workspace_id: String,
+        slug: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Tags::update_tag(): 
+        Workspace ID: {workspace_id}
+        Tag ID: {tag_id}
+        Err: {error}"
+    )]
+    UpdateTagError {
+        workspace_id: String,
+        tag_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Tags::delete_tag(): 
+        Workspace ID: {workspace_id}
+        Tag ID: {tag_id}
+        Err: {error}"
+    )]
+    DeleteTagError {
+        workspace_id: String,
+        tag_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
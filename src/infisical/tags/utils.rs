@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+/// A tag defined on a project, which can then be attached to any of the project's secrets.
+///
+/// Fields:
+/// - id: the tag's id, used when attaching tags to secrets or updating/deleting the tag itself
+/// - slug: the tag's slug, used when filtering secrets by tag in list_secrets()
+/// - name: the tag's display name, if any
+/// - color: the tag's color (e.g. "#3b82f6"), if any
+/// - project_id: the id of the project the tag belongs to
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct ProjectTag {
+    pub id: String,
+    pub slug: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub project_id: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// Request body for create_tag().
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct CreateTagOptions {
+    pub slug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// Request body for update_tag(). Any field left as None is left untouched.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct UpdateTagOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+// wrapper structs for the JSON envelopes Infisical returns tags in
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectTagResponse {
+    pub workspace_tag: ProjectTag,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectTagsResponse {
+    pub workspace_tags: Vec<ProjectTag>,
+}
+
+pub mod tag_util_functions {
+    pub fn construct_tag_endpoint_url(host: &str, workspace_id: &str, tag: Option<&str>) -> String {
+        let is_tag = tag.map_or_else(|| "".to_string(), |tag| format!("/{tag}"));
+
+        format!(
+            "{host_url}/api/{version}/workspace/{workspace_id}/tags{tag}",
+            host_url = host,
+            version = "v1",
+            workspace_id = workspace_id,
+            tag = is_tag
+        )
+    }
+}
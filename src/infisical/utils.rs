@@ -1,2 +1,5 @@
 pub mod api_utils;
 pub mod reqwest_utils;
+
+/// Parsing and formatting the RFC 3339 timestamps Infisical uses, without pulling in a date-time crate.
+pub mod time_utils;
@@ -71,6 +71,34 @@ pub enum ApiResponse {
     },
 }
 
+impl ApiResponse {
+    /// The HTTP status code Infisical reported, if any.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            ApiResponse::Ok => None,
+            ApiResponse::BadRequest { status_code, .. }
+            | ApiResponse::Unauthorized { status_code, .. }
+            | ApiResponse::Forbidden { status_code, .. }
+            | ApiResponse::NotFound { status_code, .. }
+            | ApiResponse::UnprocessableContent { status_code, .. }
+            | ApiResponse::InternalServerError { status_code, .. } => Some(*status_code),
+        }
+    }
+
+    /// The human readable error message Infisical reported, if any.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            ApiResponse::Ok => None,
+            ApiResponse::BadRequest { message, .. }
+            | ApiResponse::Unauthorized { message, .. }
+            | ApiResponse::Forbidden { message, .. }
+            | ApiResponse::NotFound { message, .. }
+            | ApiResponse::UnprocessableContent { message, .. }
+            | ApiResponse::InternalServerError { message, .. } => Some(message),
+        }
+    }
+}
+
 impl std::fmt::Display for ApiResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
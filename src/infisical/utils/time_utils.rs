@@ -0,0 +1,97 @@
+use std::time::{Duration, SystemTime};
+
+/// parse_rfc3339_utc()
+///
+/// Parses the UTC timestamps Infisical returns (e.g. "2025-01-01T12:30:00.000Z") into a SystemTime.
+/// Returns None for anything that isn't a UTC ("Z") timestamp.
+pub fn parse_rfc3339_utc(timestamp: &str) -> Option<SystemTime> {
+    let timestamp = timestamp.strip_suffix('Z')?;
+    let (date, time) = timestamp.split_once('T')?;
+
+    let mut date_parts = date.splitn(3, '-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: i64 = date_parts.next()?.parse().ok()?;
+    let day: i64 = date_parts.next()?.parse().ok()?;
+
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut time_parts = time.splitn(3, ':');
+    let hours: u64 = time_parts.next()?.parse().ok()?;
+    let minutes: u64 = time_parts.next()?.parse().ok()?;
+    let seconds: u64 = time_parts.next()?.parse().ok()?;
+    let millis: u64 = format!("{fraction:0<3}").get(..3)?.parse().ok()?;
+
+    // 60 is a leap second, which RFC 3339 allows
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hours > 23
+        || minutes > 59
+        || seconds > 60
+        || !fraction.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        return None;
+    }
+
+    // days since the unix epoch, see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let shifted_year = if month <= 2 { year - 1 } else { year };
+    let era = shifted_year.div_euclid(400);
+    let year_of_era = shifted_year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let seconds_since_epoch =
+        u64::try_from(days).ok()? * 86400 + hours * 3600 + minutes * 60 + seconds;
+
+    Some(
+        SystemTime::UNIX_EPOCH
+            + Duration::from_secs(seconds_since_epoch)
+            + Duration::from_millis(millis),
+    )
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// format_rfc3339_utc()
+///
+/// The inverse of parse_rfc3339_utc(): formats a SystemTime as the UTC timestamps Infisical expects
+/// (e.g. "2025-01-01T12:30:00.000Z"). Times before the unix epoch are clamped to it.
+pub fn format_rfc3339_utc(time: SystemTime) -> String {
+    let since_epoch = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO);
+
+    let seconds = since_epoch.as_secs();
+    let days = (seconds / 86400) as i64;
+    let seconds_of_day = seconds % 86400;
+
+    // civil date from days since the unix epoch, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let shifted_days = days + 719468;
+    let era = shifted_days.div_euclid(146097);
+    let day_of_era = shifted_days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{hours:02}:{minutes:02}:{seconds:02}.{millis:03}Z",
+        hours = seconds_of_day / 3600,
+        minutes = seconds_of_day % 3600 / 60,
+        seconds = seconds_of_day % 60,
+        millis = since_epoch.subsec_millis(),
+    )
+}
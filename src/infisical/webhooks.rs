@@ -0,0 +1,307 @@
+/// Webhooks:
+///
+/// Project webhooks, which Infisical calls whenever secrets change in a given environment and path, and the
+/// verification of the signed deliveries they send.
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use secrecy::{ExposeSecret, SecretString};
+use sha2::Sha256;
+
+use error_handling::{WebhookError, WebhookVerificationError};
+use utils::{
+    webhook_util_functions::{construct_webhook_endpoint_url, parse_webhook_signature_header},
+    *,
+};
+
+use crate::infisical::{
+    auth_methods::universal_auth::utils::UniversalAuthAccessToken, utils::api_utils::ApiResponse,
+};
+
+pub mod error_handling;
+
+/// An embedded HTTP listener for webhook deliveries, which invalidates a SecretCache as changes come in.
+#[cfg(feature = "webhook_receiver")]
+pub mod receiver;
+
+/// Structs and utility functions used by the webhooks module, such as Webhook and WebhookPayload.
+pub mod utils;
+
+// ---------------------------------------------------------------------------------------------------------
+/// Webhook management
+impl UniversalAuthAccessToken {
+    /// list_webhooks()
+    ///
+    /// Lists a project's webhooks, optionally only those of a given environment and/or secret path.
+    pub async fn list_webhooks(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        project_id: &str,
+        options: &ListWebhooksOptions,
+    ) -> Result<Vec<Webhook>, WebhookError> {
+        let endpoint_url = construct_webhook_endpoint_url(host, None);
+
+        let response = client
+            .get(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .query(&ListWebhooksQuery {
+                workspace_id: project_id,
+                options,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(WebhookError::ProjectRequestError {
+                operation: "list_webhooks".to_string(),
+                project_id: project_id.to_string(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<WebhooksResponse>(&bytes)?.webhooks)
+    }
+
+    /// create_webhook()
+    ///
+    /// Creates a webhook fired on every change under the given environment and secret path.
+    pub async fn create_webhook(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        options: &CreateWebhookOptions,
+    ) -> Result<Webhook, WebhookError> {
+        let endpoint_url = construct_webhook_endpoint_url(host, None);
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&CreateWebhookBody {
+                workspace_id: &options.project_id,
+                environment: &options.environment,
+                secret_path: &options.secret_path,
+                webhook_url: &options.webhook_url,
+                webhook_secret_key: options
+                    .secret_key
+                    .as_ref()
+                    .map(|secret_key| secret_key.expose_secret()),
+                webhook_type: options.webhook_type,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(WebhookError::ProjectRequestError {
+                operation: "create_webhook".to_string(),
+                project_id: options.project_id.clone(),
+                error: error_response,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<WebhookResponse>(&bytes)?.webhook)
+    }
+
+    /// set_webhook_enabled()
+    ///
+    /// Enables or disables a webhook. Disabled webhooks are kept, but not fired.
+    pub async fn set_webhook_enabled(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        webhook_id: &str,
+        enabled: bool,
+    ) -> Result<Webhook, WebhookError> {
+        let endpoint_url = construct_webhook_endpoint_url(host, Some(webhook_id));
+
+        let response = client
+            .patch(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .json(&UpdateWebhookBody {
+                is_disabled: !enabled,
+            })
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(webhook_error(
+                "set_webhook_enabled",
+                webhook_id,
+                error_response,
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<WebhookResponse>(&bytes)?.webhook)
+    }
+
+    /// delete_webhook()
+    ///
+    /// Deletes a webhook, returning it as it was before deletion.
+    pub async fn delete_webhook(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        webhook_id: &str,
+    ) -> Result<Webhook, WebhookError> {
+        let endpoint_url = construct_webhook_endpoint_url(host, Some(webhook_id));
+
+        let response = client
+            .delete(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(webhook_error("delete_webhook", webhook_id, error_response));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(serde_json::from_slice::<WebhookResponse>(&bytes)?.webhook)
+    }
+
+    /// test_webhook()
+    ///
+    /// Sends a signed "test" delivery to the webhook's URL. Infisical reports a failed delivery as an error, and
+    /// records the outcome in the webhook's last_status either way.
+    pub async fn test_webhook(
+        &self,
+        host: &str,
+        client: &reqwest::Client,
+        webhook_id: &str,
+    ) -> Result<(), WebhookError> {
+        let endpoint_url =
+            construct_webhook_endpoint_url(host, Some(&format!("{webhook_id}/test")));
+
+        let response = client
+            .post(&endpoint_url)
+            .bearer_auth(self.access_token())
+            .send()
+            .await?;
+
+        // if response doesnt return a 200 OK, short circuit and return a ApiResponse
+        if response.status().ne(&StatusCode::OK) {
+            let error_response = response.json::<ApiResponse>().await?;
+
+            #[cfg(not(feature = "logging_silent"))]
+            println!("error_response: {}", error_response);
+
+            return Err(webhook_error("test_webhook", webhook_id, error_response));
+        }
+
+        Ok(())
+    }
+}
+
+fn webhook_error(operation: &str, webhook_id: &str, error: ApiResponse) -> WebhookError {
+    WebhookError::WebhookRequestError {
+        operation: operation.to_string(),
+        webhook_id: webhook_id.to_string(),
+        error,
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------
+// Incoming webhook deliveries
+
+/// verify_webhook()
+///
+/// Checks an incoming delivery's `x-infisical-signature` header against its raw body, then parses the body.
+/// The body must be passed exactly as received, re-serializing a parsed payload would change its signature.
+///
+/// With a `max_age`, deliveries signed longer ago than that are rejected, which guards against replays. Deliveries
+/// signed more than WEBHOOK_MAX_CLOCK_SKEW in the future are rejected as well, as they'd otherwise never expire.
+pub fn verify_webhook(
+    secret_key: &SecretString,
+    signature_header: &str,
+    body: &[u8],
+    max_age: Option<Duration>,
+) -> Result<WebhookPayload, WebhookVerificationError> {
+    let malformed_header = || WebhookVerificationError::MalformedSignatureHeader {
+        header: signature_header.to_string(),
+    };
+
+    let (timestamp, signature) =
+        parse_webhook_signature_header(signature_header).ok_or_else(malformed_header)?;
+    let signature = hex::decode(signature).map_err(|_| malformed_header())?;
+
+    // verify_slice() compares in constant time
+    webhook_mac(secret_key, body)
+        .verify_slice(&signature)
+        .map_err(|_| WebhookVerificationError::InvalidSignature)?;
+
+    if let Some(max_age) = max_age {
+        let signed_at = UNIX_EPOCH + Duration::from_millis(timestamp);
+
+        // duration_since() fails when signed_at is ahead of now, with how far ahead it is
+        match SystemTime::now().duration_since(signed_at) {
+            Ok(age) if age > max_age => {
+                return Err(WebhookVerificationError::Expired { timestamp });
+            }
+            Err(error) if error.duration() > WEBHOOK_MAX_CLOCK_SKEW => {
+                return Err(WebhookVerificationError::SignedInFuture { timestamp });
+            }
+            _ => {}
+        }
+    }
+
+    let payload = serde_json::from_slice::<WebhookPayload>(body)?;
+
+    if payload.timestamp != timestamp {
+        return Err(WebhookVerificationError::TimestampMismatch {
+            signed: timestamp,
+            payload: payload.timestamp,
+        });
+    }
+
+    Ok(payload)
+}
+
+/// sign_webhook()
+///
+/// Builds the `x-infisical-signature` header Infisical would send with `body`, e.g. to exercise a webhook
+/// receiver locally. `timestamp` must match the body's, in milliseconds since the unix epoch.
+pub fn sign_webhook(secret_key: &SecretString, body: &[u8], timestamp: u64) -> String {
+    let signature = hex::encode(webhook_mac(secret_key, body).finalize().into_bytes());
+
+    format!("t={timestamp};{signature}")
+}
+
+fn webhook_mac(secret_key: &SecretString, body: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret_key.expose_secret().as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(body);
+
+    mac
+}
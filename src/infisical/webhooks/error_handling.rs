@@ -0,0 +1,61 @@
+use crate::infisical::utils::api_utils::ApiResponse;
+
+// ---------------------------------------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum WebhookError {
+    #[error(
+        "Webhooks::{operation}(): 
+        Project ID: {project_id}
+        Err: {error}"
+    )]
+    ProjectRequestError {
+        operation: String,
+        project_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(
+        "Webhooks::{operation}(): 
+        Webhook ID: {webhook_id}
+        Err: {error}"
+    )]
+    WebhookRequestError {
+        operation: String,
+        webhook_id: String,
+        error: ApiResponse,
+    },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
+
+// ---------------------------------------------------------------------------------------------------------
+
+/// Why an incoming webhook delivery was rejected by verify_webhook().
+#[derive(thiserror::Error, Debug)]
+pub enum WebhookVerificationError {
+    #[error("Webhooks::verify_webhook(): malformed x-infisical-signature header: {header}")]
+    MalformedSignatureHeader { header: String },
+
+    #[error("Webhooks::verify_webhook(): signature doesn't match the payload")]
+    InvalidSignature,
+
+    #[error("Webhooks::verify_webhook(): delivery is too old, signed at {timestamp} (unix millis)")]
+    Expired { timestamp: u64 },
+
+    #[error(
+        "Webhooks::verify_webhook(): delivery is signed in the future, at {timestamp} (unix millis)"
+    )]
+    SignedInFuture { timestamp: u64 },
+
+    #[error(
+        "Webhooks::verify_webhook(): signed timestamp {signed} doesn't match the payload's {payload}"
+    )]
+    TimestampMismatch { signed: u64, payload: u64 },
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
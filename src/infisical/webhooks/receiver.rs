@@ -0,0 +1,168 @@
+/// Webhook receiver:
+///
+/// A small HTTP listener for Infisical's webhook deliveries. Verified `secrets.modified` deliveries invalidate the
+/// affected scope of a SecretCache, which in turn notifies the cache's subscribers.
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use axum::{Router, body::Bytes, extract::State, http::HeaderMap, http::StatusCode, routing::post};
+use secrecy::SecretString;
+use tokio::{net::TcpListener, task::JoinHandle};
+
+use crate::infisical::{
+    secrets::{cache::SecretCache, utils::SecretScope},
+    webhooks::{
+        error_handling::WebhookVerificationError,
+        utils::{WEBHOOK_SIGNATURE_HEADER, WebhookEventType},
+        verify_webhook,
+    },
+};
+
+/// Input for webhook_router() and WebhookReceiver::bind().
+///
+/// - secret_key: the key the webhook was created with, see CreateWebhookOptions
+/// - max_age: deliveries signed longer ago than this (or too far in the future) are rejected, None accepts any
+/// - path: the route deliveries are accepted on, i.e. the path of the webhook's URL
+#[derive(Clone, Debug)]
+pub struct WebhookReceiverOptions {
+    pub secret_key: SecretString,
+    pub max_age: Option<Duration>,
+    pub path: String,
+}
+
+impl WebhookReceiverOptions {
+    /// Accepts deliveries on `/infisical/webhook` signed within the last five minutes.
+    pub fn new(secret_key: SecretString) -> Self {
+        Self {
+            secret_key,
+            max_age: Some(Duration::from_secs(5 * 60)),
+            path: "/infisical/webhook".to_string(),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ReceiverState {
+    cache: Arc<SecretCache>,
+    secret_key: SecretString,
+    max_age: Option<Duration>,
+}
+
+/// webhook_router()
+///
+/// The receiver's routes, for mounting into an existing axum application instead of running a WebhookReceiver.
+///
+/// Responds with 401 to deliveries that fail verification, 400 to malformed payloads, and 200 otherwise.
+pub fn webhook_router(cache: Arc<SecretCache>, options: WebhookReceiverOptions) -> Router {
+    Router::new()
+        .route(&options.path, post(receive_webhook))
+        .with_state(ReceiverState {
+            cache,
+            secret_key: options.secret_key,
+            max_age: options.max_age,
+        })
+}
+
+async fn receive_webhook(
+    State(state): State<ReceiverState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    let Some(signature_header) = headers
+        .get(WEBHOOK_SIGNATURE_HEADER)
+        .and_then(|header| header.to_str().ok())
+    else {
+        return StatusCode::UNAUTHORIZED;
+    };
+
+    let payload = match verify_webhook(&state.secret_key, signature_header, &body, state.max_age) {
+        Ok(payload) => payload,
+        Err(error) => return rejection_status(error),
+    };
+
+    if payload.event == WebhookEventType::SecretsModified {
+        state.cache.invalidate(&SecretScope::new(
+            &payload.project.workspace_id,
+            &payload.project.environment,
+            Some(&payload.project.secret_path),
+        ));
+    }
+
+    StatusCode::OK
+}
+
+// malformed payloads are a bad request, everything else failed verification
+fn rejection_status(error: WebhookVerificationError) -> StatusCode {
+    #[cfg(not(feature = "logging_silent"))]
+    println!("WebhookReceiver: rejected delivery: {error}");
+
+    match error {
+        WebhookVerificationError::SerdeJsonError(_) => StatusCode::BAD_REQUEST,
+        _ => StatusCode::UNAUTHORIZED,
+    }
+}
+
+/// WebhookReceiver
+///
+/// Serves webhook_router() on its own listener in the background, until dropped or stopped.
+///
+/// # Example
+/// ```ignore
+/// let receiver = WebhookReceiver::bind(
+///     "0.0.0.0:8080".parse()?,
+///     cache.clone(), // Arc<SecretCache>
+///     WebhookReceiverOptions::new(webhook_secret_key),
+/// )
+/// .await?;
+///
+/// let mut invalidations = cache.subscribe();
+/// while let Ok(invalidation) = invalidations.recv().await {
+///     println!("{} changed", invalidation.changed.secret_path);
+/// }
+/// ```
+pub struct WebhookReceiver {
+    local_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl WebhookReceiver {
+    /// bind()
+    ///
+    /// Starts listening on `address` on the current tokio runtime. Bind to port 0 to pick any free port.
+    pub async fn bind(
+        address: SocketAddr,
+        cache: Arc<SecretCache>,
+        options: WebhookReceiverOptions,
+    ) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(address).await?;
+        let local_addr = listener.local_addr()?;
+        let router = webhook_router(cache, options);
+
+        // axum::serve() keeps accepting through connection errors, it only returns once aborted
+        let task = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+
+        Ok(Self { local_addr, task })
+    }
+
+    /// The address the receiver is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Whether the receiver is still serving.
+    pub fn is_running(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    /// Stops serving, closing the listener.
+    pub fn stop(self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for WebhookReceiver {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+/// The header Infisical signs webhook deliveries with, formatted as `t=<unix millis>;<hex hmac-sha256>`.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-infisical-signature";
+
+/// How far ahead of the local clock a delivery's signed timestamp may be before verify_webhook() rejects it
+/// (when given a max_age), so that small clock differences between Infisical and the receiver don't matter.
+pub const WEBHOOK_MAX_CLOCK_SKEW: Duration = Duration::from_secs(60);
+
+/// Where a webhook delivers to: a generic HTTP endpoint, or a Slack incoming webhook.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum WebhookType {
+    #[default]
+    General,
+    Slack,
+}
+
+/// Outcome of a webhook's most recent delivery.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookStatus {
+    Success,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct WebhookEnvironment {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+}
+
+/// A project webhook, fired whenever a secret under its environment and secret path changes.
+///
+/// Fields:
+/// - url: the delivery endpoint
+/// - last_status: None until the webhook has been delivered at least once
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all(serialize = "snake_case", deserialize = "camelCase"))]
+pub struct Webhook {
+    pub id: String,
+    pub project_id: String,
+    pub environment: WebhookEnvironment,
+    pub secret_path: String,
+    pub url: String,
+    #[serde(rename(serialize = "webhook_type", deserialize = "type"), default)]
+    pub webhook_type: WebhookType,
+    pub is_disabled: bool,
+    #[serde(default)]
+    pub last_status: Option<WebhookStatus>,
+    #[serde(default)]
+    pub last_run_error_message: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Input for create_webhook().
+///
+/// - environment: slug of the environment to watch
+/// - secret_path: the folder to watch, changes in nested folders fire the webhook too
+/// - secret_key: the key deliveries are signed with, see verify_webhook(). Without one deliveries are unsigned
+#[derive(Clone, Debug)]
+pub struct CreateWebhookOptions {
+    pub project_id: String,
+    pub environment: String,
+    pub secret_path: String,
+    pub webhook_url: String,
+    pub secret_key: Option<SecretString>,
+    pub webhook_type: WebhookType,
+}
+
+/// Filters for list_webhooks(), None lists the webhooks of every environment/path.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ListWebhooksOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_path: Option<String>,
+}
+
+/// The event a webhook delivery was sent for. Events this crate doesn't model yet deserialize as Unknown.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebhookEventType {
+    #[serde(rename = "secrets.modified")]
+    SecretsModified,
+    #[serde(rename = "test")]
+    Test,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The environment and path a webhook delivery's change happened in.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookProject {
+    pub workspace_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    pub environment: String,
+    pub secret_path: String,
+}
+
+/// The body of a webhook delivery.
+///
+/// Fields:
+/// - timestamp: when the delivery was sent, in milliseconds since the unix epoch
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    pub event: WebhookEventType,
+    pub project: WebhookProject,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateWebhookBody<'a> {
+    pub workspace_id: &'a str,
+    pub environment: &'a str,
+    pub secret_path: &'a str,
+    pub webhook_url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_secret_key: Option<&'a str>,
+    #[serde(rename = "type")]
+    pub webhook_type: WebhookType,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UpdateWebhookBody {
+    pub is_disabled: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ListWebhooksQuery<'a> {
+    pub workspace_id: &'a str,
+    #[serde(flatten)]
+    pub options: &'a ListWebhooksOptions,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WebhookResponse {
+    pub webhook: Webhook,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WebhooksResponse {
+    pub webhooks: Vec<Webhook>,
+}
+
+pub mod webhook_util_functions {
+    pub fn construct_webhook_endpoint_url(host: &str, path: Option<&str>) -> String {
+        let is_path = path.map_or_else(|| "".to_string(), |path| format!("/{path}"));
+
+        format!(
+            "{host_url}/api/{version}/webhooks{path}",
+            host_url = host,
+            version = "v1",
+            path = is_path
+        )
+    }
+
+    /// parse_webhook_signature_header()
+    ///
+    /// Splits an `x-infisical-signature` header into its timestamp (unix millis) and hex encoded signature.
+    pub fn parse_webhook_signature_header(header: &str) -> Option<(u64, &str)> {
+        let (timestamp, signature) = header.trim().split_once(';')?;
+        let timestamp = timestamp.trim().strip_prefix("t=")?.parse().ok()?;
+
+        Some((timestamp, signature.trim()))
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+pub mod access_requests {
+    use infisical_rs::infisical::access_approvals::utils::{
+        AccessRequest, AccessRequestAction, RequestAccessOptions,
+        access_approval_util_functions::access_request_permissions,
+    };
+    use serde_json::json;
+
+    fn mock_request(extra: serde_json::Value) -> AccessRequest {
+        let mut request = json!({
+            "id": "request-id",
+            "policyId": "policy-id",
+            "isTemporary": true,
+            "temporaryRange": "1h",
+            "createdAt": "2025-01-06T00:00:00.000Z",
+            "updatedAt": "2025-01-06T00:00:00.000Z"
+        });
+        request
+            .as_object_mut()
+            .expect("object")
+            .extend(extra.as_object().expect("object").clone());
+
+        serde_json::from_value(request).expect("valid access request")
+    }
+
+    #[test]
+    fn test_access_request_states() {
+        let pending = mock_request(json!({}));
+        assert!(pending.is_pending());
+
+        let granted = mock_request(json!({ "privilegeId": "privilege-id" }));
+        assert!(granted.is_granted());
+        assert!(!granted.is_pending());
+
+        let denied = mock_request(json!({
+            "reviewers": [{ "member": "membership-id", "status": "rejected" }]
+        }));
+        assert!(denied.is_denied());
+        assert!(!denied.is_granted());
+    }
+
+    #[test]
+    fn test_access_request_permissions() {
+        let permissions = access_request_permissions(&RequestAccessOptions {
+            environment: "prod".to_string(),
+            secret_path: "/payments/**".to_string(),
+            actions: vec![AccessRequestAction::Read, AccessRequestAction::Edit],
+            duration: "30m".to_string(),
+            justification: Some("incident #4821".to_string()),
+        });
+
+        assert_eq!(
+            permissions,
+            vec![
+                json!({
+                    "action": "read",
+                    "subject": "secrets",
+                    "conditions": { "environment": "prod", "secretPath": { "$glob": "/payments/**" } }
+                }),
+                json!({
+                    "action": "edit",
+                    "subject": "secrets",
+                    "conditions": { "environment": "prod", "secretPath": { "$glob": "/payments/**" } }
+                }),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod access_polling {
+    use std::time::Duration;
+
+    use infisical_rs::infisical::access_approvals::utils::AccessPollOptions;
+
+    #[test]
+    fn test_zero_interval_is_raised_to_the_minimum() {
+        let options = AccessPollOptions {
+            interval: Duration::ZERO,
+            ..Default::default()
+        };
+
+        assert_eq!(options.poll_interval(), AccessPollOptions::MIN_INTERVAL);
+    }
+
+    #[test]
+    fn test_longer_intervals_are_kept() {
+        assert_eq!(
+            AccessPollOptions::default().poll_interval(),
+            Duration::from_secs(10)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(test)]
+pub mod audit_log_events {
+    use infisical_rs::infisical::audit_logs::utils::{AuditLog, AuditLogActor, AuditLogEvent};
+    use serde_json::json;
+
+    #[test]
+    fn test_identity_secret_read_deserialization() {
+        let audit_log: AuditLog = serde_json::from_value(json!({
+            "id": "audit-log-id",
+            "actor": {
+                "type": "identity",
+                "metadata": { "identityId": "identity-id", "name": "ci-runner" }
+            },
+            "event": {
+                "type": "get-secret",
+                "metadata": {
+                    "environment": "prod",
+                    "secretPath": "/db",
+                    "secretId": "secret-id",
+                    "secretKey": "DB_PASSWORD",
+                    "secretVersion": 3
+                }
+            },
+            "ipAddress": "10.0.0.1",
+            "userAgent": "infisical-rs",
+            "userAgentType": "other",
+            "projectId": "project-id",
+            "projectName": "backend",
+            "createdAt": "2025-03-01T12:30:15.250Z"
+        }))
+        .expect("valid audit log");
+
+        assert_eq!(audit_log.actor.actor_id(), Some("identity-id"));
+        assert_eq!(audit_log.event.event_type(), "get-secret");
+
+        let secrets = audit_log.event.secrets();
+        assert_eq!(secrets.len(), 1);
+        assert_eq!(secrets[0].0, "prod");
+        assert_eq!(secrets[0].1, "/db");
+        assert_eq!(secrets[0].2.secret_key, "DB_PASSWORD");
+        assert_eq!(secrets[0].2.secret_version, 3);
+    }
+
+    #[test]
+    fn test_batch_event_deserialization() {
+        let event: AuditLogEvent = serde_json::from_value(json!({
+            "type": "update-secrets",
+            "metadata": {
+                "environment": "dev",
+                "secretPath": "/",
+                "secrets": [
+                    { "secretId": "a", "secretKey": "A", "secretVersion": 2 },
+                    { "secretId": "b", "secretKey": "B", "secretVersion": 5 }
+                ]
+            }
+        }))
+        .expect("valid event");
+
+        let keys = event
+            .secrets()
+            .into_iter()
+            .map(|(_, _, secret)| secret.secret_key.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_unknown_and_malformed_events_fall_back_to_other() {
+        let unknown: AuditLogEvent = serde_json::from_value(json!({
+            "type": "create-webhook",
+            "metadata": { "webhookId": "webhook-id" }
+        }))
+        .expect("valid event");
+
+        assert_eq!(
+            unknown,
+            AuditLogEvent::Other {
+                event_type: "create-webhook".to_string(),
+                metadata: json!({ "webhookId": "webhook-id" }),
+            }
+        );
+
+        // a known event type whose metadata has drifted is kept rather than failing the page
+        let malformed: AuditLogEvent = serde_json::from_value(json!({
+            "type": "get-secret",
+            "metadata": { "environment": "prod" }
+        }))
+        .expect("valid event");
+
+        assert_eq!(malformed.event_type(), "get-secret");
+        assert!(matches!(malformed, AuditLogEvent::Other { .. }));
+    }
+
+    #[test]
+    fn test_event_round_trip() {
+        let event_json = json!({
+            "type": "login-identity-universal-auth",
+            "metadata": { "identityId": "identity-id", "clientSecretId": "client-secret-id" }
+        });
+
+        let event: AuditLogEvent = serde_json::from_value(event_json.clone()).expect("valid event");
+        assert_eq!(
+            serde_json::to_value(&event).expect("serializable"),
+            event_json
+        );
+    }
+
+    #[test]
+    fn test_unknown_actor_deserialization() {
+        let actor: AuditLogActor = serde_json::from_value(json!({
+            "type": "platform",
+            "metadata": {}
+        }))
+        .expect("valid actor");
+
+        assert_eq!(actor.actor_type(), "platform");
+        assert!(matches!(actor, AuditLogActor::Other { .. }));
+        assert_eq!(actor.actor_id(), None);
+    }
+}
+
+#[cfg(test)]
+pub mod audit_log_queries {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use infisical_rs::infisical::audit_logs::utils::{
+        AuditLogActorType, AuditLogQuery, audit_log_util_functions::audit_log_query_parameters,
+    };
+
+    #[test]
+    fn test_query_parameters() {
+        let query = AuditLogQuery {
+            project_id: Some("project-id".to_string()),
+            actor_type: Some(AuditLogActorType::Identity),
+            actor_id: Some("identity-id".to_string()),
+            event_types: vec!["get-secret".to_string(), "get-secrets".to_string()],
+            start: Some(UNIX_EPOCH + Duration::from_secs(1_740_832_215)),
+            page_size: 50,
+            ..Default::default()
+        };
+
+        let parameters = audit_log_query_parameters(&query, 100);
+
+        assert_eq!(
+            parameters,
+            vec![
+                ("offset", "100".to_string()),
+                ("limit", "50".to_string()),
+                ("projectId", "project-id".to_string()),
+                ("actorType", "identity".to_string()),
+                ("actorId", "identity-id".to_string()),
+                ("startDate", "2025-03-01T12:30:15.000Z".to_string()),
+                ("eventType", "get-secret".to_string()),
+                ("eventType", "get-secrets".to_string()),
+            ]
+        );
+    }
+}
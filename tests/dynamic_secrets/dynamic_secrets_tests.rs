@@ -0,0 +1,109 @@
+#[cfg(test)]
+pub mod lease_expiry {
+    use std::time::{Duration, SystemTime};
+
+    use infisical_rs::infisical::utils::time_utils::parse_rfc3339_utc;
+
+    #[test]
+    fn test_parse_timestamps() {
+        assert_eq!(
+            parse_rfc3339_utc("1970-01-01T00:00:00Z"),
+            Some(SystemTime::UNIX_EPOCH)
+        );
+        assert_eq!(
+            parse_rfc3339_utc("2025-03-01T12:30:15.250Z"),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_millis(1_740_832_215_250))
+        );
+        // leap day
+        assert_eq!(
+            parse_rfc3339_utc("2024-02-29T00:00:00.000Z"),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_709_164_800))
+        );
+        // leap second
+        assert_eq!(
+            parse_rfc3339_utc("2016-12-31T23:59:60Z"),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_483_228_800))
+        );
+    }
+
+    #[test]
+    fn test_reject_malformed_timestamps() {
+        for timestamp in [
+            "",
+            "2025-03-01",
+            "2025-03-01T12:30:15",
+            "2025-03-01T12:30:15+02:00",
+            "2025-13-01T12:30:15Z",
+            "2025-03-01T24:00:00Z",
+            "2025-03-01T12:75:00Z",
+            "2025-03-01T12:30:61Z",
+            "2025-02-30T12:30:15Z",
+            "2023-02-29T12:30:15Z",
+            "2025-04-31T12:30:15Z",
+            "2025-03-01T12:30:15.2x0Z",
+            "not-a-dateTtimeZ",
+        ] {
+            assert!(parse_rfc3339_utc(timestamp).is_none(), "{timestamp}");
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod lease_credentials {
+    use infisical_rs::infisical::dynamic_secrets::utils::DynamicSecretCredentials;
+    use secrecy::zeroize::Zeroize;
+    use serde_json::json;
+
+    #[test]
+    fn test_credentials_from_lease_data() {
+        let data = json!({
+            "DB_USERNAME": "infisical-user-abc123",
+            "DB_PASSWORD": "hunter2",
+            "PORT": 5432,
+        });
+
+        let credentials = DynamicSecretCredentials::from(data.as_object().expect("object").clone());
+
+        assert_eq!(credentials.values["DB_USERNAME"], "infisical-user-abc123");
+        assert_eq!(credentials.values["DB_PASSWORD"], "hunter2");
+        assert_eq!(credentials.values["PORT"], "5432");
+    }
+
+    #[test]
+    fn test_zeroize_clears_credentials() {
+        let mut credentials = DynamicSecretCredentials::from(
+            json!({ "DB_PASSWORD": "hunter2" })
+                .as_object()
+                .expect("object")
+                .clone(),
+        );
+
+        credentials.zeroize();
+
+        assert!(credentials.values.is_empty());
+    }
+}
+
+#[cfg(test)]
+pub mod lease_keeper {
+    use std::time::Duration;
+
+    use infisical_rs::infisical::dynamic_secrets::lease_keeper::LeaseKeeperOptions;
+
+    #[test]
+    fn test_zero_retry_interval_is_raised_to_the_minimum() {
+        let options = LeaseKeeperOptions {
+            retry_interval: Duration::ZERO,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            options.retry_interval(),
+            LeaseKeeperOptions::MIN_RETRY_INTERVAL
+        );
+        assert_eq!(
+            LeaseKeeperOptions::default().retry_interval(),
+            Duration::from_secs(30)
+        );
+    }
+}
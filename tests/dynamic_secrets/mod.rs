@@ -0,0 +1 @@
+pub mod dynamic_secrets_tests;
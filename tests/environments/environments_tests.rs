@@ -0,0 +1,84 @@
+#[cfg(test)]
+pub mod slug_validation {
+    use infisical_rs::infisical::environments::utils::environment_util_functions::validate_environment_slug;
+
+    #[test]
+    fn test_valid_slugs() {
+        for slug in ["dev", "staging", "prod", "qa-2", "us-east-1"] {
+            assert!(validate_environment_slug(slug).is_ok(), "{slug}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_slugs() {
+        let too_long = "a".repeat(65);
+
+        for slug in [
+            "",
+            "Prod",
+            "pro d",
+            "prod/",
+            "-prod",
+            "prod-",
+            "pr--od",
+            too_long.as_str(),
+        ] {
+            assert!(validate_environment_slug(slug).is_err(), "{slug}");
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod environment_ordering {
+    use infisical_rs::infisical::environments::{
+        error_handling::EnvironmentOrderingError,
+        utils::{Environment, environment_util_functions::order_environments},
+    };
+
+    fn environments(slugs: &[&str]) -> Vec<Environment> {
+        slugs
+            .iter()
+            .enumerate()
+            .map(|(index, slug)| Environment {
+                id: format!("{slug}-id"),
+                name: slug.to_string(),
+                slug: slug.to_string(),
+                position: index as u64 + 1,
+            })
+            .collect()
+    }
+
+    fn slugs(environments: &[Environment]) -> Vec<&str> {
+        environments
+            .iter()
+            .map(|environment| environment.slug.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_unmentioned_environments_come_last() {
+        let ordered = order_environments(
+            environments(&["dev", "qa", "staging", "prod"]),
+            &["prod", "dev"],
+        )
+        .expect("every slug exists");
+
+        assert_eq!(slugs(&ordered), ["prod", "dev", "qa", "staging"]);
+    }
+
+    #[test]
+    fn test_unknown_slug() {
+        assert_eq!(
+            order_environments(environments(&["dev", "prod"]), &["dev", "staging"]),
+            Err(EnvironmentOrderingError::UnknownSlug("staging".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_duplicate_slug() {
+        assert_eq!(
+            order_environments(environments(&["dev", "prod"]), &["prod", "dev", "prod"]),
+            Err(EnvironmentOrderingError::DuplicateSlug("prod".to_string()))
+        );
+    }
+}
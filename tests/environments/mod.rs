@@ -0,0 +1 @@
+pub mod environments_tests;
@@ -0,0 +1,138 @@
+#[cfg(test)]
+pub mod groups {
+    use infisical_rs::infisical::groups::utils::{
+        CreateGroupOptions, Group, GroupUser, GroupUsersFilter, ListGroupUsersOptions,
+        group_util_functions::{
+            construct_group_endpoint_url, construct_project_groups_endpoint_url,
+        },
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_group_deserialization() {
+        let group: Group = serde_json::from_value(json!({
+            "id": "group-id",
+            "orgId": "org-id",
+            "name": "Payments team",
+            "slug": "payments-team",
+            "role": "member",
+            "roleId": null,
+            "createdAt": "2025-03-01T12:00:00.000Z",
+            "updatedAt": "2025-03-01T12:00:00.000Z"
+        }))
+        .expect("valid group");
+
+        assert_eq!(group.slug, "payments-team");
+        assert_eq!(group.role_id, None);
+    }
+
+    #[test]
+    fn test_options_serialization() {
+        assert_eq!(
+            serde_json::to_value(CreateGroupOptions {
+                name: "Payments team".to_string(),
+                slug: None,
+                role: "no-access".to_string(),
+            })
+            .expect("serializable"),
+            json!({ "name": "Payments team", "role": "no-access" })
+        );
+
+        assert_eq!(
+            serde_json::to_value(ListGroupUsersOptions::default()).expect("serializable"),
+            json!({ "offset": 0, "limit": 100, "filter": "existingMembers" })
+        );
+
+        assert_eq!(
+            serde_json::to_value(GroupUsersFilter::NonMembers).expect("serializable"),
+            json!("nonMembers")
+        );
+    }
+
+    #[test]
+    fn test_group_user_deserialization() {
+        let user: GroupUser = serde_json::from_value(json!({
+            "id": "user-id",
+            "email": "jane@example.com",
+            "username": "jane@example.com",
+            "firstName": "Jane",
+            "lastName": "Doe",
+            "isPartOfGroup": true,
+            "joinedGroupAt": "2025-03-01T12:00:00.000Z"
+        }))
+        .expect("valid user");
+
+        assert!(user.is_part_of_group);
+
+        // adding and removing users returns them without their group status
+        let user: GroupUser = serde_json::from_value(json!({
+            "id": "user-id",
+            "username": "jane@example.com"
+        }))
+        .expect("valid user");
+
+        assert!(!user.is_part_of_group);
+        assert_eq!(user.joined_group_at, None);
+    }
+
+    #[test]
+    fn test_endpoint_urls() {
+        assert_eq!(
+            construct_group_endpoint_url(
+                "https://app.infisical.com",
+                Some("group-id/users/jane@example.com")
+            ),
+            "https://app.infisical.com/api/v1/groups/group-id/users/jane@example.com"
+        );
+        assert_eq!(
+            construct_project_groups_endpoint_url(
+                "https://app.infisical.com",
+                "project-id",
+                Some("group-id")
+            ),
+            "https://app.infisical.com/api/v2/workspace/project-id/groups/group-id"
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod project_group_memberships {
+    use infisical_rs::infisical::groups::utils::ProjectGroupMembership;
+    use serde_json::json;
+
+    #[test]
+    fn test_listed_membership_deserialization() {
+        let membership: ProjectGroupMembership = serde_json::from_value(json!({
+            "id": "membership-id",
+            "groupId": "group-id",
+            "createdAt": "2025-03-01T12:00:00.000Z",
+            "updatedAt": "2025-03-01T12:00:00.000Z",
+            "roles": [
+                { "id": "role-id", "role": "custom", "customRoleSlug": "prod-app-reader", "isTemporary": false }
+            ],
+            "group": { "id": "group-id", "name": "Payments team", "slug": "payments-team" }
+        }))
+        .expect("valid membership");
+
+        assert_eq!(membership.roles[0].slug(), "prod-app-reader");
+        assert_eq!(
+            membership.group.map(|group| group.slug).as_deref(),
+            Some("payments-team")
+        );
+    }
+
+    #[test]
+    fn test_created_membership_deserialization() {
+        let membership: ProjectGroupMembership = serde_json::from_value(json!({
+            "id": "membership-id",
+            "projectId": "project-id",
+            "groupId": "group-id",
+            "createdAt": "2025-03-01T12:00:00.000Z",
+            "updatedAt": "2025-03-01T12:00:00.000Z"
+        }))
+        .expect("valid membership");
+
+        assert_eq!(membership.group, None);
+        assert!(membership.roles.is_empty());
+    }
+}
@@ -0,0 +1 @@
+pub mod groups_tests;
@@ -0,0 +1,98 @@
+#[cfg(test)]
+pub mod identity_management {
+    use infisical_rs::infisical::identities::utils::{
+        CreateIdentityOptions, IdentityMetadata, ListIdentitiesOptions, MachineIdentity,
+        OrganizationIdentity, UpdateIdentityOptions,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_create_options_serialization() {
+        let options = CreateIdentityOptions {
+            name: "payments-ci".to_string(),
+            organization_id: "org-id".to_string(),
+            role: "no-access".to_string(),
+            has_delete_protection: true,
+            metadata: vec![IdentityMetadata {
+                key: "team".to_string(),
+                value: "payments".to_string(),
+            }],
+        };
+
+        assert_eq!(
+            serde_json::to_value(&options).expect("serializable"),
+            json!({
+                "name": "payments-ci",
+                "organizationId": "org-id",
+                "role": "no-access",
+                "hasDeleteProtection": true,
+                "metadata": [{ "key": "team", "value": "payments" }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_update_options_only_send_set_fields() {
+        let options = UpdateIdentityOptions {
+            role: Some("member".to_string()),
+            metadata: Some(Vec::new()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&options).expect("serializable"),
+            json!({ "role": "member", "metadata": [] })
+        );
+        assert_eq!(
+            serde_json::to_value(ListIdentitiesOptions::default()).expect("serializable"),
+            json!({ "offset": 0, "limit": 100 })
+        );
+    }
+
+    #[test]
+    fn test_created_identity_deserialization() {
+        let identity: MachineIdentity = serde_json::from_value(json!({
+            "id": "identity-id",
+            "name": "payments-ci",
+            "authMethods": [],
+            "hasDeleteProtection": false,
+            "orgId": "org-id",
+            "createdAt": "2025-03-01T12:30:15.250Z",
+            "updatedAt": "2025-03-01T12:30:15.250Z"
+        }))
+        .expect("valid identity");
+
+        assert_eq!(identity.id, "identity-id");
+        assert!(!identity.has_auth_method("universal-auth"));
+    }
+
+    #[test]
+    fn test_organization_identity_deserialization() {
+        let identity: OrganizationIdentity = serde_json::from_value(json!({
+            "id": "membership-id",
+            "role": "custom",
+            "customRoleId": "custom-role-id",
+            "orgId": "org-id",
+            "identity": {
+                "id": "identity-id",
+                "name": "payments-ci",
+                "authMethods": ["universal-auth"]
+            },
+            "metadata": [
+                { "id": "metadata-id", "key": "team", "value": "payments" },
+                { "id": "metadata-id-2", "key": "team", "value": "billing" }
+            ],
+            "createdAt": "2025-03-01T12:30:15.250Z",
+            "updatedAt": "2025-03-01T12:30:15.250Z"
+        }))
+        .expect("valid organization identity");
+
+        assert_eq!(identity.custom_role_id.as_deref(), Some("custom-role-id"));
+        assert!(identity.identity.has_auth_method("universal-auth"));
+        assert!(!identity.identity.has_delete_protection);
+        assert_eq!(
+            identity.metadata_values("team"),
+            vec!["payments", "billing"]
+        );
+    }
+}
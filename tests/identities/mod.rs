@@ -0,0 +1 @@
+pub mod identities_tests;
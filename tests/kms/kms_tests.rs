@@ -0,0 +1,143 @@
+#[cfg(test)]
+pub mod kms_keys {
+    use infisical_rs::infisical::kms::utils::{
+        KmsKey, KmsKeyAlgorithm, KmsKeyUsage, KmsSigningAlgorithm,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_kms_key_deserialization() {
+        let key: KmsKey = serde_json::from_value(json!({
+            "id": "key-id",
+            "name": "payments-envelope",
+            "description": null,
+            "isDisabled": false,
+            "projectId": "project-id",
+            "keyUsage": "sign-verify",
+            "encryptionAlgorithm": "ECC_NIST_P256",
+            "version": 1,
+            "createdAt": "2025-01-06T00:00:00.000Z",
+            "updatedAt": "2025-01-06T00:00:00.000Z"
+        }))
+        .expect("valid kms key");
+
+        assert_eq!(key.key_usage, KmsKeyUsage::SignVerify);
+        assert_eq!(key.encryption_algorithm, KmsKeyAlgorithm::EccNistP256);
+    }
+
+    #[test]
+    fn test_algorithm_names() {
+        assert_eq!(
+            serde_json::to_value(KmsKeyAlgorithm::Aes256Gcm).expect("serializable"),
+            json!("aes-256-gcm")
+        );
+        assert_eq!(
+            serde_json::to_value(KmsSigningAlgorithm::RsassaPkcs1V15Sha256).expect("serializable"),
+            json!("RSASSA_PKCS1_V1_5_SHA_256")
+        );
+        assert_eq!(
+            KmsSigningAlgorithm::EcdsaSha384.to_string(),
+            "ECDSA_SHA_384"
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod kms_data_operations {
+    use infisical_rs::infisical::kms::error_handling::KmsError;
+    use secrecy::{ExposeSecret, SecretBox};
+    use serde_json::json;
+
+    use crate::test_utils::{
+        mock_server_test_utils::{MockServer, mock_api_error},
+        universal_auth_test_utils::mock_access_token,
+    };
+
+    // binary data keys aren't valid UTF-8, so they have to survive the round trip byte for byte
+    const PLAINTEXT: [u8; 7] = [0x00, 0xff, 0x10, 0x80, b'k', b'e', b'y'];
+    const ENCODED_PLAINTEXT: &str = "AP8QgGtleQ==";
+
+    #[tokio::test]
+    async fn test_encrypt_sends_base64_plaintext() {
+        let server =
+            MockServer::start(vec![(200, json!({ "ciphertext": "ciphertext-blob" }))]).await;
+
+        let ciphertext = mock_access_token()
+            .encrypt_with_kms_key(
+                &server.host,
+                &reqwest::Client::new(),
+                "key-id",
+                &SecretBox::new(Box::new(PLAINTEXT.to_vec())),
+            )
+            .await
+            .expect("mocked response");
+
+        assert_eq!(ciphertext, "ciphertext-blob");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].target, "/api/v1/kms/keys/key-id/encrypt");
+        assert_eq!(requests[0].body, json!({ "plaintext": ENCODED_PLAINTEXT }));
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_decodes_base64_plaintext() {
+        let server =
+            MockServer::start(vec![(200, json!({ "plaintext": ENCODED_PLAINTEXT }))]).await;
+
+        let plaintext = mock_access_token()
+            .decrypt_with_kms_key(
+                &server.host,
+                &reqwest::Client::new(),
+                "key-id",
+                "ciphertext-blob",
+            )
+            .await
+            .expect("mocked response");
+
+        assert_eq!(plaintext.expose_secret().as_slice(), PLAINTEXT);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].target, "/api/v1/kms/keys/key-id/decrypt");
+        assert_eq!(requests[0].body, json!({ "ciphertext": "ciphertext-blob" }));
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_rejects_invalid_base64() {
+        let server = MockServer::start(vec![(200, json!({ "plaintext": "not base64!" }))]).await;
+
+        let error = mock_access_token()
+            .decrypt_with_kms_key(
+                &server.host,
+                &reqwest::Client::new(),
+                "key-id",
+                "ciphertext-blob",
+            )
+            .await
+            .expect_err("invalid base64");
+
+        assert!(matches!(error, KmsError::InvalidBase64 { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_error_names_the_key() {
+        let server = MockServer::start(vec![mock_api_error(400, "Key is disabled")]).await;
+
+        let error = mock_access_token()
+            .encrypt_with_kms_key(
+                &server.host,
+                &reqwest::Client::new(),
+                "key-id",
+                &SecretBox::new(Box::new(PLAINTEXT.to_vec())),
+            )
+            .await
+            .expect_err("mocked error");
+
+        assert!(matches!(
+            error,
+            KmsError::KmsKeyError { operation, key, .. }
+                if operation == "encrypt_with_kms_key" && key == "key-id"
+        ));
+    }
+}
@@ -0,0 +1 @@
+pub mod kms_tests;
@@ -0,0 +1,165 @@
+#[cfg(test)]
+pub mod organization_memberships {
+    use infisical_rs::infisical::organizations::utils::{
+        OrganizationMembership, OrganizationMembershipStatus, ProjectInvite,
+        UpdateOrganizationMembershipOptions,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_membership_deserialization() {
+        let membership: OrganizationMembership = serde_json::from_value(json!({
+            "id": "membership-id",
+            "orgId": "org-id",
+            "role": "custom",
+            "roleId": "custom-role-id",
+            "status": "accepted",
+            "isActive": false,
+            "user": {
+                "id": "user-id",
+                "username": "jane@example.com",
+                "email": "jane@example.com",
+                "firstName": "Jane",
+                "lastName": null,
+                "isEmailVerified": true
+            },
+            "createdAt": "2025-03-01T12:00:00.000Z",
+            "updatedAt": "2025-03-01T12:00:00.000Z"
+        }))
+        .expect("valid membership");
+
+        assert_eq!(membership.role_id.as_deref(), Some("custom-role-id"));
+        assert_eq!(membership.user.first_name.as_deref(), Some("Jane"));
+        assert!(!membership.is_active);
+        assert!(membership.has_joined());
+    }
+
+    #[test]
+    fn test_pending_invite() {
+        let membership: OrganizationMembership = serde_json::from_value(json!({
+            "id": "membership-id",
+            "orgId": "org-id",
+            "role": "member",
+            "status": "invited",
+            "inviteEmail": "john@example.com",
+            "user": { "id": "user-id", "username": "john@example.com" },
+            "createdAt": "2025-03-01T12:00:00.000Z",
+            "updatedAt": "2025-03-01T12:00:00.000Z"
+        }))
+        .expect("valid membership");
+
+        assert_eq!(membership.status, OrganizationMembershipStatus::Invited);
+        assert!(membership.is_active);
+        assert!(!membership.has_joined());
+    }
+
+    #[test]
+    fn test_unknown_status() {
+        let status: OrganizationMembershipStatus =
+            serde_json::from_value(json!("suspended")).expect("valid status");
+
+        assert_eq!(status, OrganizationMembershipStatus::Unknown);
+    }
+
+    #[test]
+    fn test_options_serialization() {
+        assert_eq!(
+            serde_json::to_value(UpdateOrganizationMembershipOptions {
+                is_active: Some(false),
+                ..Default::default()
+            })
+            .expect("serializable"),
+            json!({ "isActive": false })
+        );
+
+        assert_eq!(
+            serde_json::to_value(ProjectInvite {
+                id: "project-id".to_string(),
+                role_slugs: vec!["viewer".to_string()],
+            })
+            .expect("serializable"),
+            json!({ "id": "project-id", "projectRoleSlug": ["viewer"] })
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod organization_roles {
+    use infisical_rs::infisical::{
+        organizations::utils::{
+            OrganizationRole,
+            organization_util_functions::{
+                construct_organization_memberships_endpoint_url,
+                construct_organization_roles_endpoint_url,
+            },
+        },
+        project_roles::permissions::{PermissionAction, PermissionRule, PermissionSubject},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_role_deserialization() {
+        let role: OrganizationRole = serde_json::from_value(json!({
+            "id": "role-id",
+            "name": "Directory sync",
+            "slug": "directory-sync",
+            "description": null,
+            "permissions": [
+                { "subject": "member", "action": ["read", "create", "delete"] },
+                { "subject": "groups", "action": "edit" }
+            ],
+            "orgId": "org-id",
+            "createdAt": "2025-03-01T12:00:00.000Z",
+            "updatedAt": "2025-03-01T12:00:00.000Z"
+        }))
+        .expect("valid role");
+
+        assert_eq!(
+            role.permissions,
+            vec![
+                PermissionRule::allow(
+                    PermissionSubject::Members,
+                    &[
+                        PermissionAction::Read,
+                        PermissionAction::Create,
+                        PermissionAction::Delete
+                    ]
+                ),
+                PermissionRule::allow(
+                    PermissionSubject::Other("groups".to_string()),
+                    &[PermissionAction::Edit]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_built_in_role_deserialization() {
+        let role: OrganizationRole = serde_json::from_value(json!({
+            "id": "b11b49a9-09a9-4443-916a-4246f9ff2c69",
+            "name": "Admin",
+            "slug": "admin",
+            "description": "Complete administration access over the organization"
+        }))
+        .expect("valid role");
+
+        assert!(role.permissions.is_empty());
+        assert_eq!(role.org_id, None);
+    }
+
+    #[test]
+    fn test_endpoint_urls() {
+        assert_eq!(
+            construct_organization_memberships_endpoint_url(
+                "https://app.infisical.com",
+                "org-id",
+                Some("membership-id")
+            ),
+            "https://app.infisical.com/api/v2/organizations/org-id/memberships/membership-id"
+        );
+        assert_eq!(
+            construct_organization_roles_endpoint_url("https://app.infisical.com", "org-id", None),
+            "https://app.infisical.com/api/v1/organization/org-id/roles"
+        );
+    }
+}
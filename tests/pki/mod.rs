@@ -0,0 +1 @@
+pub mod pki_tests;
@@ -0,0 +1,107 @@
+#[cfg(test)]
+pub mod membership_roles {
+    use std::time::Duration;
+
+    use infisical_rs::infisical::{
+        project_memberships::utils::{
+            ProjectIdentityMembership, ProjectMembershipRole, ProjectRoleAssignment,
+            ProjectUserMembership, TemporaryRoleAccess,
+        },
+        utils::time_utils::parse_rfc3339_utc,
+    };
+    use serde_json::json;
+
+    fn temporary_role() -> ProjectMembershipRole {
+        serde_json::from_value(json!({
+            "id": "role-id",
+            "role": "custom",
+            "customRoleId": "custom-role-id",
+            "customRoleSlug": "secret-rotator",
+            "customRoleName": "Secret rotator",
+            "isTemporary": true,
+            "temporaryMode": "relative",
+            "temporaryRange": "2h",
+            "temporaryAccessStartTime": "2025-03-01T12:00:00.000Z",
+            "temporaryAccessEndTime": "2025-03-01T14:00:00.000Z",
+            "createdAt": "2025-03-01T12:00:00.000Z",
+            "updatedAt": "2025-03-01T12:00:00.000Z"
+        }))
+        .expect("valid role")
+    }
+
+    #[test]
+    fn test_role_assignment_constructors() {
+        assert_eq!(
+            ProjectRoleAssignment::permanent("viewer"),
+            ProjectRoleAssignment {
+                role: "viewer".to_string(),
+                temporary: None,
+            }
+        );
+        assert_eq!(
+            ProjectRoleAssignment::temporary("admin", "2h", None).temporary,
+            Some(TemporaryRoleAccess {
+                range: "2h".to_string(),
+                starts_at: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_custom_role_slug() {
+        assert_eq!(temporary_role().slug(), "secret-rotator");
+    }
+
+    #[test]
+    fn test_temporary_role_window() {
+        let role = temporary_role();
+        let starts_at = parse_rfc3339_utc("2025-03-01T12:00:00.000Z").expect("valid timestamp");
+
+        assert!(!role.is_active_at(starts_at - Duration::from_secs(1)));
+        assert!(role.is_active_at(starts_at));
+        assert!(role.is_active_at(starts_at + Duration::from_secs(60 * 60)));
+        assert!(!role.is_active_at(starts_at + Duration::from_secs(2 * 60 * 60)));
+    }
+
+    #[test]
+    fn test_identity_membership_deserialization() {
+        let membership: ProjectIdentityMembership = serde_json::from_value(json!({
+            "id": "membership-id",
+            "identity": {
+                "id": "identity-id",
+                "name": "payments-ci",
+                "authMethods": ["universal-auth"]
+            },
+            "roles": [{ "id": "role-id", "role": "viewer", "isTemporary": false }],
+            "createdAt": "2025-03-01T12:00:00.000Z",
+            "updatedAt": "2025-03-01T12:00:00.000Z"
+        }))
+        .expect("valid membership");
+
+        assert_eq!(membership.identity.name, "payments-ci");
+        assert_eq!(membership.roles[0].slug(), "viewer");
+        assert!(membership.roles[0].is_active_at(std::time::SystemTime::now()));
+    }
+
+    #[test]
+    fn test_user_membership_deserialization() {
+        let membership: ProjectUserMembership = serde_json::from_value(json!({
+            "id": "membership-id",
+            "userId": "user-id",
+            "projectId": "project-id",
+            "user": {
+                "id": "user-id",
+                "username": "jane@example.com",
+                "email": "jane@example.com",
+                "firstName": "Jane",
+                "lastName": null
+            },
+            "roles": [{ "id": "role-id", "role": "member" }]
+        }))
+        .expect("valid membership");
+
+        assert_eq!(membership.user.username, "jane@example.com");
+        assert_eq!(membership.user.last_name, None);
+        assert_eq!(membership.roles[0].slug(), "member");
+    }
+}
@@ -0,0 +1,345 @@
+#[cfg(test)]
+pub mod permission_rules {
+    use infisical_rs::infisical::project_roles::{
+        permissions::{PermissionAction, PermissionCondition, PermissionRule, PermissionSubject},
+        utils::{CreateProjectRoleOptions, ProjectRole},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_rule_serialization() {
+        let rule = PermissionRule::allow(
+            PermissionSubject::Secrets,
+            &[PermissionAction::Read, PermissionAction::Edit],
+        )
+        .in_environment("prod")
+        .at_secret_path_glob("/apps/**");
+
+        assert_eq!(
+            serde_json::to_value(&rule).expect("serializable"),
+            json!({
+                "subject": "secrets",
+                "action": ["read", "edit"],
+                "conditions": {
+                    "environment": { "$eq": "prod" },
+                    "secretPath": { "$glob": "/apps/**" }
+                },
+                "inverted": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_unconditional_deny_serialization() {
+        let rule = PermissionRule::deny(
+            PermissionSubject::SecretFolders,
+            &[PermissionAction::Delete],
+        );
+
+        assert_eq!(
+            serde_json::to_value(&rule).expect("serializable"),
+            json!({ "subject": "secret-folders", "action": ["delete"], "inverted": true })
+        );
+    }
+
+    #[test]
+    fn test_rule_deserialization() {
+        let rule: PermissionRule = serde_json::from_value(json!({
+            "subject": "secrets",
+            "action": "read",
+            "conditions": {
+                "environment": "staging",
+                "secretPath": { "$glob": "/apps/*", "$ne": "/apps/legacy" },
+                "secretTags": { "$in": ["pci"] }
+            }
+        }))
+        .expect("valid rule");
+
+        assert_eq!(rule.actions, vec![PermissionAction::Read]);
+        assert!(!rule.inverted);
+        assert_eq!(
+            rule.conditions.environment,
+            vec![PermissionCondition::Eq("staging".to_string())]
+        );
+        assert_eq!(
+            rule.conditions.secret_path,
+            vec![
+                PermissionCondition::Glob("/apps/*".to_string()),
+                PermissionCondition::Ne("/apps/legacy".to_string()),
+            ]
+        );
+        assert_eq!(
+            rule.conditions.other.get("secretTags"),
+            Some(&json!({ "$in": ["pci"] }))
+        );
+    }
+
+    #[test]
+    fn test_unknown_subjects_and_actions_round_trip() {
+        let rule_json = json!({
+            "subject": "pki-alerts",
+            "action": ["lease"],
+            "inverted": false
+        });
+
+        let rule: PermissionRule = serde_json::from_value(rule_json.clone()).expect("valid rule");
+        assert_eq!(
+            rule.subject,
+            PermissionSubject::Other("pki-alerts".to_string())
+        );
+        assert_eq!(
+            rule.actions,
+            vec![PermissionAction::Other("lease".to_string())]
+        );
+        assert_eq!(
+            serde_json::to_value(&rule).expect("serializable"),
+            rule_json
+        );
+    }
+
+    #[test]
+    fn test_unsupported_condition_operator() {
+        let rule = serde_json::from_value::<PermissionRule>(json!({
+            "subject": "secrets",
+            "action": ["read"],
+            "conditions": { "secretPath": { "$regex": "^/apps" } }
+        }));
+
+        assert!(rule.is_err());
+    }
+
+    #[test]
+    fn test_repeated_condition_operators() {
+        let rule = PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read])
+            .in_environment("dev")
+            .in_environment("prod");
+        assert!(serde_json::to_value(&rule).is_err());
+
+        let rule = PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read])
+            .at_secret_path_glob("/apps/**")
+            .at_secret_path_glob("/services/**");
+        assert!(serde_json::to_value(&rule).is_err());
+
+        // different operators on the same field are kept, and round trip
+        let mut rule = PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read])
+            .at_secret_path_glob("/apps/**");
+        rule.conditions
+            .secret_path
+            .push(PermissionCondition::Ne("/apps/payments".to_string()));
+
+        let rule_json = serde_json::to_value(&rule).expect("serializable");
+        assert_eq!(
+            rule_json["conditions"],
+            json!({ "secretPath": { "$glob": "/apps/**", "$ne": "/apps/payments" } })
+        );
+
+        let round_tripped: PermissionRule = serde_json::from_value(rule_json).expect("valid rule");
+        assert_eq!(round_tripped, rule);
+    }
+
+    #[test]
+    fn test_role_round_trip() {
+        let options = CreateProjectRoleOptions {
+            slug: "prod-app-reader".to_string(),
+            name: "Production app reader".to_string(),
+            description: None,
+            permissions: vec![
+                PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read])
+                    .in_environment("prod"),
+            ],
+        };
+
+        let role: ProjectRole = serde_json::from_value(json!({
+            "id": "role-id",
+            "name": options.name,
+            "slug": options.slug,
+            "description": null,
+            "permissions": serde_json::to_value(&options.permissions).expect("serializable"),
+            "projectId": "project-id",
+            "createdAt": "2025-03-01T12:00:00.000Z",
+            "updatedAt": "2025-03-01T12:00:00.000Z"
+        }))
+        .expect("valid role");
+
+        assert_eq!(role.permissions, options.permissions);
+    }
+}
+
+#[cfg(test)]
+pub mod permission_evaluation {
+    use infisical_rs::infisical::project_roles::{
+        evaluation::{PermissionResource, ProjectPermissions},
+        permissions::{PermissionAction, PermissionRule, PermissionSubject},
+        utils::project_role_util_functions::{matches_glob, unpack_permission_rules},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_glob_matching() {
+        assert!(matches_glob("/apps/*", "/apps/payments"));
+        assert!(!matches_glob("/apps/*", "/apps/payments/api"));
+        assert!(matches_glob("/apps/**", "/apps/payments/api"));
+        assert!(matches_glob("/apps/**", "/apps"));
+        assert!(matches_glob("/apps/**", "/apps/"));
+        assert!(!matches_glob("/apps/**", "/applications"));
+        assert!(matches_glob("/**", "/"));
+        assert!(matches_glob("/{apps,services}/*", "/services/auth"));
+        assert!(matches_glob("/apps/v?", "/apps/v2"));
+        assert!(matches_glob("/apps/v[0-9]", "/apps/v7"));
+        assert!(!matches_glob("/apps/v[!0-9]", "/apps/v7"));
+        assert!(matches_glob("DB_*", "DB_PASSWORD"));
+        assert!(!matches_glob("DB_*", "API_KEY"));
+    }
+
+    #[test]
+    fn test_later_rules_take_precedence() {
+        let permissions = ProjectPermissions::new(vec![
+            PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read])
+                .at_secret_path_glob("/**"),
+            PermissionRule::deny(PermissionSubject::Secrets, &[PermissionAction::Read])
+                .in_environment("prod")
+                .at_secret_path_glob("/apps/payments/**"),
+        ]);
+
+        assert!(permissions.can_read_secret("dev", "/apps/payments", None));
+        assert!(permissions.can_read_secret("prod", "/apps/auth", Some("API_KEY")));
+        assert!(!permissions.can_read_secret("prod", "/apps/payments/api", Some("API_KEY")));
+
+        let denied = permissions
+            .check_secret_access(&PermissionAction::Read, "prod", "/apps/payments", None)
+            .expect_err("denied");
+        assert_eq!(denied.denied_by.as_deref(), permissions.rules.get(1));
+        assert!(denied.to_string().contains("a rule forbids it"));
+    }
+
+    #[test]
+    fn test_nothing_is_allowed_by_default() {
+        let permissions = ProjectPermissions::new(vec![
+            PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read])
+                .in_environment("dev"),
+        ]);
+
+        let denied = permissions
+            .check_secret_access(&PermissionAction::Edit, "dev", "/", Some("API_KEY"))
+            .expect_err("denied");
+        assert_eq!(denied.denied_by, None);
+        assert!(denied.to_string().contains("no rule allows it"));
+
+        assert!(!permissions.can_read_secret("prod", "/", None));
+    }
+
+    #[test]
+    fn test_manage_and_all_wildcards() {
+        let permissions = ProjectPermissions::new(vec![PermissionRule::allow(
+            PermissionSubject::from("all"),
+            &[PermissionAction::from("manage")],
+        )]);
+
+        assert!(
+            permissions
+                .check_secret_access(&PermissionAction::Delete, "prod", "/", Some("API_KEY"))
+                .is_ok()
+        );
+        assert!(permissions.can(
+            &PermissionAction::Create,
+            &PermissionSubject::Members,
+            &PermissionResource::default(),
+        ));
+    }
+
+    #[test]
+    fn test_split_read_actions() {
+        let describe = PermissionRule::allow(
+            PermissionSubject::Secrets,
+            &[PermissionAction::DescribeSecret],
+        );
+        let read_value =
+            PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::ReadValue]);
+
+        let permissions = ProjectPermissions::new(vec![describe.clone(), read_value.clone()]);
+        assert!(permissions.can_read_secret("prod", "/", Some("API_KEY")));
+
+        // seeing a secret exists isn't enough to read it
+        let permissions = ProjectPermissions::new(vec![describe]);
+        let denied = permissions
+            .check_secret_access(&PermissionAction::Read, "prod", "/", Some("API_KEY"))
+            .expect_err("denied");
+        assert_eq!(denied.action, PermissionAction::ReadValue);
+
+        // the legacy read action grants both halves, and forbidding either half forbids reading
+        let permissions = ProjectPermissions::new(vec![
+            PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read]),
+            PermissionRule::deny(PermissionSubject::Secrets, &[PermissionAction::ReadValue])
+                .in_environment("prod"),
+        ]);
+        assert!(permissions.can_read_secret("dev", "/", Some("API_KEY")));
+        assert!(permissions.can(
+            &PermissionAction::DescribeSecret,
+            &PermissionSubject::Secrets,
+            &PermissionResource::secret("prod", "/", Some("API_KEY")),
+        ));
+        assert!(!permissions.can_read_secret("prod", "/", Some("API_KEY")));
+
+        assert_eq!(
+            serde_json::to_value(&read_value).expect("serializable")["action"],
+            json!(["readValue"])
+        );
+    }
+
+    #[test]
+    fn test_secret_name_conditions() {
+        let permissions = ProjectPermissions::new(vec![
+            PermissionRule::allow(PermissionSubject::Secrets, &[PermissionAction::Read])
+                .with_secret_name_glob("PUBLIC_*"),
+        ]);
+
+        assert!(permissions.can_read_secret("dev", "/", Some("PUBLIC_URL")));
+        assert!(!permissions.can_read_secret("dev", "/", Some("PRIVATE_KEY")));
+        // without a secret name, a condition on it can't be satisfied
+        assert!(!permissions.can_read_secret("dev", "/", None));
+    }
+
+    #[test]
+    fn test_unknown_conditions_are_strict() {
+        let rules = unpack_permission_rules(&[
+            json!(["read", "secrets", { "secretTags": { "$in": ["public"] } }]),
+            json!(["read", "secrets"]),
+            json!(["read", "secrets", { "secretTags": { "$in": ["internal"] } }, 1]),
+        ])
+        .expect("valid rules");
+
+        let permissions = ProjectPermissions::new(rules[..1].to_vec());
+        assert!(!permissions.can_read_secret("dev", "/", Some("API_KEY")));
+
+        let permissions = ProjectPermissions::new(rules[1..].to_vec());
+        assert!(!permissions.can_read_secret("dev", "/", Some("API_KEY")));
+    }
+
+    #[test]
+    fn test_unpack_permission_rules() {
+        let rules = unpack_permission_rules(&[
+            json!(["read,edit", "secrets,secret-folders", { "environment": "prod" }]),
+            json!(["delete", "secrets", 0, 1]),
+        ])
+        .expect("valid rules");
+
+        assert_eq!(
+            rules,
+            vec![
+                PermissionRule::allow(
+                    PermissionSubject::Secrets,
+                    &[PermissionAction::Read, PermissionAction::Edit]
+                )
+                .in_environment("prod"),
+                PermissionRule::allow(
+                    PermissionSubject::SecretFolders,
+                    &[PermissionAction::Read, PermissionAction::Edit]
+                )
+                .in_environment("prod"),
+                PermissionRule::deny(PermissionSubject::Secrets, &[PermissionAction::Delete]),
+            ]
+        );
+
+        assert!(unpack_permission_rules(&[json!({ "action": "read" })]).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+pub mod project_deserialization {
+    use infisical_rs::infisical::projects::utils::Project;
+
+    #[test]
+    fn test_v1_workspace_payload() -> Result<(), serde_json::Error> {
+        let project = serde_json::from_value::<Project>(serde_json::json!({
+            "_id": "6f1b2b4c-0000-0000-0000-000000000000",
+            "id": "6f1b2b4c-0000-0000-0000-000000000000",
+            "name": "payments",
+            "slug": "payments-x1y2",
+            "autoCapitalization": true,
+            "organization": "org-id",
+            "version": 3,
+            "environments": [
+                { "id": "env-2", "name": "Production", "slug": "prod", "position": 2 },
+                { "id": "env-1", "name": "Development", "slug": "dev", "position": 1 }
+            ],
+            "createdAt": "2025-01-01T00:00:00.000Z",
+            "updatedAt": "2025-01-01T00:00:00.000Z"
+        }))?;
+
+        assert_eq!(project.org_id, "org-id");
+        assert!(project.auto_capitalization);
+        assert_eq!(project.audit_logs_retention_days, None);
+        assert_eq!(project.environments.len(), 2);
+        assert_eq!(project.environments[1].slug, "dev");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_v2_project_payload() -> Result<(), serde_json::Error> {
+        let project = serde_json::from_value::<Project>(serde_json::json!({
+            "id": "project-id",
+            "name": "payments",
+            "slug": "payments-x1y2",
+            "description": "card processing",
+            "orgId": "org-id",
+            "auditLogsRetentionDays": 30
+        }))?;
+
+        assert_eq!(project.org_id, "org-id");
+        assert_eq!(project.description.as_deref(), Some("card processing"));
+        assert_eq!(project.audit_logs_retention_days, Some(30));
+        assert!(!project.auto_capitalization);
+        assert!(project.environments.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_payload_with_both_organization_fields() -> Result<(), serde_json::Error> {
+        let project = serde_json::from_value::<Project>(serde_json::json!({
+            "id": "project-id",
+            "name": "payments",
+            "slug": "payments-x1y2",
+            "orgId": "org-id",
+            "organization": "legacy-org-id"
+        }))?;
+
+        // orgId wins over the v1 organization field
+        assert_eq!(project.org_id, "org-id");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_payload_without_an_organization() -> Result<(), serde_json::Error> {
+        let project = serde_json::from_value::<Project>(serde_json::json!({
+            "id": "project-id",
+            "name": "payments",
+            "slug": "payments-x1y2"
+        }))?;
+
+        assert_eq!(project.org_id, "");
+        assert_eq!(project.version, 0);
+
+        Ok(())
+    }
+}
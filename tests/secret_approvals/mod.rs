@@ -0,0 +1 @@
+pub mod secret_approvals_tests;
@@ -0,0 +1,65 @@
+#[cfg(test)]
+pub mod approval_detection {
+    use infisical_rs::infisical::{
+        secret_approvals::utils::secret_approval_util_functions::is_approval_required,
+        utils::api_utils::ApiResponse,
+    };
+    use serde_json::json;
+
+    fn mock_error(status_code: u16, message: &str) -> ApiResponse {
+        serde_json::from_value(json!({
+            "reqId": "req-id",
+            "statusCode": status_code,
+            "message": message,
+            "error": "PermissionDenied",
+        }))
+        .expect("valid error response")
+    }
+
+    #[test]
+    fn test_detect_approval_required() {
+        assert!(is_approval_required(&mock_error(
+            403,
+            "Secret approval required for environment prod"
+        )));
+        assert!(!is_approval_required(&mock_error(
+            403,
+            "You are not allowed to create on secrets"
+        )));
+        assert!(!is_approval_required(&mock_error(
+            400,
+            "Invalid approval policy"
+        )));
+    }
+}
+
+#[cfg(test)]
+pub mod change_requests {
+    use infisical_rs::infisical::secret_approvals::utils::{
+        SecretChangeRequest, SecretChangeRequestStatus,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_change_request_reviews() {
+        let change_request: SecretChangeRequest = serde_json::from_value(json!({
+            "id": "change-request-id",
+            "slug": "violet-otter",
+            "status": "open",
+            "hasMerged": false,
+            "policyId": "policy-id",
+            "environment": "prod",
+            "reviewers": [
+                { "userId": "user-1", "status": "approved" },
+                { "userId": "user-2", "status": "approved" }
+            ],
+            "createdAt": "2025-01-06T00:00:00.000Z",
+            "updatedAt": "2025-01-06T00:00:00.000Z"
+        }))
+        .expect("valid change request");
+
+        assert_eq!(change_request.status, SecretChangeRequestStatus::Open);
+        assert_eq!(change_request.approvals(), 2);
+        assert!(!change_request.is_rejected());
+    }
+}
@@ -0,0 +1 @@
+pub mod secret_rotations_tests;
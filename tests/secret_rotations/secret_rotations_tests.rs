@@ -0,0 +1,56 @@
+#[cfg(test)]
+pub mod rotation_status {
+    use infisical_rs::infisical::secret_rotations::utils::{SecretRotation, SecretRotationState};
+    use serde_json::json;
+
+    fn mock_rotation(status: Option<&str>, status_message: Option<&str>) -> SecretRotation {
+        serde_json::from_value(json!({
+            "id": "rotation-id",
+            "interval": 7,
+            "provider": "postgres",
+            "environment": { "id": "env-id", "name": "Production", "slug": "prod" },
+            "secretPath": "/db",
+            "outputs": [{
+                "key": "db_password",
+                "secret": { "id": "secret-id", "secretKey": "DB_PASSWORD", "version": 3 }
+            }],
+            "lastRotatedAt": "2025-01-06T00:00:00.000Z",
+            "status": status,
+            "statusMessage": status_message,
+            "createdAt": "2024-12-30T00:00:00.000Z",
+            "updatedAt": "2025-01-06T00:00:00.000Z"
+        }))
+        .expect("valid secret rotation")
+    }
+
+    #[test]
+    fn test_failed_rotation_reports_last_error() {
+        let rotation = mock_rotation(Some("failed"), Some("password authentication failed"));
+        let status = rotation.status();
+
+        assert!(rotation.has_failed());
+        assert_eq!(status.state, Some(SecretRotationState::Failed));
+        assert_eq!(
+            status.last_error.as_deref(),
+            Some("password authentication failed")
+        );
+        assert_eq!(rotation.outputs[0].secret.secret_key, "DB_PASSWORD");
+    }
+
+    #[test]
+    fn test_successful_rotation_has_no_error() {
+        let rotation = mock_rotation(Some("success"), Some("rotated"));
+
+        assert!(!rotation.has_failed());
+        assert_eq!(rotation.status().last_error, None);
+    }
+
+    #[test]
+    fn test_unknown_and_missing_states() {
+        assert_eq!(
+            mock_rotation(Some("pending"), None).status().state,
+            Some(SecretRotationState::Unknown)
+        );
+        assert_eq!(mock_rotation(None, None).status().state, None);
+    }
+}
@@ -0,0 +1 @@
+pub mod secret_sharing_tests;
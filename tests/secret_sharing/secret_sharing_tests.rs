@@ -0,0 +1,66 @@
+#[cfg(test)]
+pub mod timestamps {
+    use std::time::{Duration, SystemTime};
+
+    use infisical_rs::infisical::utils::time_utils::{format_rfc3339_utc, parse_rfc3339_utc};
+
+    #[test]
+    fn test_format_timestamps() {
+        assert_eq!(
+            format_rfc3339_utc(SystemTime::UNIX_EPOCH),
+            "1970-01-01T00:00:00.000Z"
+        );
+        assert_eq!(
+            format_rfc3339_utc(SystemTime::UNIX_EPOCH + Duration::from_millis(1_740_832_215_250)),
+            "2025-03-01T12:30:15.250Z"
+        );
+        assert_eq!(
+            format_rfc3339_utc(SystemTime::UNIX_EPOCH + Duration::from_secs(1_709_164_800)),
+            "2024-02-29T00:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn test_format_parse_round_trip() {
+        // every ~37 days across a few centuries, to go through leap years and century boundaries
+        for days in (0..100_000u64).step_by(37) {
+            let time = SystemTime::UNIX_EPOCH + Duration::from_millis(days * 86_400_123);
+
+            assert_eq!(parse_rfc3339_utc(&format_rfc3339_utc(time)), Some(time));
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod shared_secrets {
+    use infisical_rs::infisical::secret_sharing::utils::{
+        SharedSecret, SharedSecretAccessType,
+        secret_sharing_util_functions::construct_shared_secret_url,
+    };
+    use secrecy::ExposeSecret;
+    use serde_json::json;
+
+    #[test]
+    fn test_shared_secret_url() {
+        assert_eq!(
+            construct_shared_secret_url("https://app.infisical.com/", "shared-id"),
+            "https://app.infisical.com/shared/secret/shared-id"
+        );
+    }
+
+    #[test]
+    fn test_shared_secret_deserialization() {
+        let shared_secret: SharedSecret = serde_json::from_value(json!({
+            "secretValue": "vendor-api-key",
+            "expiresAt": "2025-01-07T00:00:00.000Z",
+            "expiresAfterViews": 1,
+            "accessType": "anyone",
+            "orgName": "Acme"
+        }))
+        .expect("valid shared secret");
+
+        assert_eq!(shared_secret.secret_value.expose_secret(), "vendor-api-key");
+        assert_eq!(shared_secret.access_type, SharedSecretAccessType::Anyone);
+        assert_eq!(shared_secret.expires_after_views, Some(1));
+    }
+}
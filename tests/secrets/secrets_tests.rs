@@ -0,0 +1,1033 @@
+#[cfg(test)]
+pub mod tag_queries {
+    use infisical_rs::infisical::secrets::utils::{InfisicalSecretTag, TagQuery};
+    use serde_json::json;
+
+    fn tag(id: &str, slug: &str, color: Option<&str>) -> InfisicalSecretTag {
+        InfisicalSecretTag {
+            color: color.map(str::to_string),
+            id: id.to_string(),
+            name: None,
+            slug: slug.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_tag_without_color_deserialization() {
+        let tag: InfisicalSecretTag = serde_json::from_value(json!({
+            "id": "tag-id",
+            "slug": "payments",
+            "name": "Payments",
+            "color": null
+        }))
+        .expect("valid tag");
+
+        assert_eq!(tag.color, None);
+        assert_eq!(tag.name.as_deref(), Some("Payments"));
+
+        let query = TagQuery {
+            color: Some("#ff0000".to_string()),
+            ..Default::default()
+        };
+        assert!(!query.matches(&tag));
+    }
+
+    #[test]
+    fn test_unset_filters_are_ignored() {
+        // a tag without a color/name should still match a query that only asks for a slug
+        let query = TagQuery {
+            slug: Some("payments".to_string()),
+            ..Default::default()
+        };
+
+        assert!(query.matches(&tag("1", "payments", None)));
+        assert!(!query.matches(&tag("2", "billing", None)));
+    }
+
+    #[test]
+    fn test_every_set_filter_has_to_match() {
+        let query = TagQuery {
+            slug: Some("payments".to_string()),
+            color: Some("#ff0000".to_string()),
+            ..Default::default()
+        };
+
+        assert!(query.matches(&tag("1", "payments", Some("#ff0000"))));
+        assert!(!query.matches(&tag("1", "payments", Some("#00ff00"))));
+    }
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        assert!(TagQuery::default().matches(&tag("1", "payments", None)));
+    }
+}
+
+#[cfg(test)]
+pub mod secret_overrides {
+    use infisical_rs::infisical::secrets::{
+        error_handling::SecretsError,
+        utils::{SecretScope, SecretType, secret_util_functions::resolve_secret_overrides},
+    };
+    use serde_json::json;
+
+    use crate::test_utils::{
+        mock_server_test_utils::{MockServer, mock_api_error},
+        secrets_test_utils::{mock_secret, mock_secret_json},
+        universal_auth_test_utils::mock_access_token,
+    };
+
+    #[test]
+    fn test_secret_type_deserialization() {
+        assert_eq!(
+            mock_secret("API_KEY", "shared", "shared").secret_type(),
+            SecretType::Shared
+        );
+        assert_eq!(
+            mock_secret("API_KEY", "mine", "personal").secret_type(),
+            SecretType::Personal
+        );
+    }
+
+    #[test]
+    fn test_personal_overrides_win() {
+        let resolved = resolve_secret_overrides(vec![
+            mock_secret("API_KEY", "shared", "shared"),
+            mock_secret("DB_URL", "shared-db", "shared"),
+            mock_secret("API_KEY", "mine", "personal"),
+        ]);
+
+        assert_eq!(resolved.len(), 2);
+        assert_eq!(resolved[0].secret_key(), "API_KEY");
+        assert_eq!(resolved[0].secret_value(), "mine");
+        assert_eq!(resolved[1].secret_value(), "shared-db");
+    }
+
+    #[test]
+    fn test_shared_value_used_without_override() {
+        // personal override listed first shouldn't be replaced by the shared value that follows
+        let resolved = resolve_secret_overrides(vec![
+            mock_secret("API_KEY", "mine", "personal"),
+            mock_secret("API_KEY", "shared", "shared"),
+        ]);
+
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].secret_value(), "mine");
+    }
+
+    #[tokio::test]
+    async fn test_get_personal_secret_requests_the_personal_type() {
+        let server = MockServer::start(vec![(
+            200,
+            json!({ "secret": mock_secret_json("API_KEY", "mine", "personal") }),
+        )])
+        .await;
+        let scope = SecretScope::new("workspace-id", "dev", Some("/api"));
+
+        let secret = mock_access_token()
+            .get_personal_secret(&server.host, &reqwest::Client::new(), &scope, "API_KEY")
+            .await
+            .expect("mocked response");
+
+        assert_eq!(secret.secret_type(), SecretType::Personal);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert!(
+            requests[0]
+                .target
+                .starts_with("/api/v3/secrets/raw/API_KEY?")
+        );
+        assert_eq!(
+            requests[0].query_pairs(),
+            vec![
+                ("workspaceId".to_string(), "workspace-id".to_string()),
+                ("environment".to_string(), "dev".to_string()),
+                ("secretPath".to_string(), "/api".to_string()),
+                ("type".to_string(), "personal".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_secret_falls_back_to_shared() {
+        let server = MockServer::start(vec![
+            mock_api_error(404, "Secret not found"),
+            (
+                200,
+                json!({ "secret": mock_secret_json("API_KEY", "shared", "shared") }),
+            ),
+        ])
+        .await;
+        let scope = SecretScope::new("workspace-id", "dev", None);
+
+        let secret = mock_access_token()
+            .resolve_secret(&server.host, &reqwest::Client::new(), &scope, "API_KEY")
+            .await
+            .expect("mocked response");
+
+        assert_eq!(secret.secret_value(), "shared");
+
+        let secret_types: Vec<String> = server
+            .requests()
+            .iter()
+            .filter_map(|request| {
+                request
+                    .query_pairs()
+                    .into_iter()
+                    .find(|(name, _)| name == "type")
+                    .map(|(_, value)| value)
+            })
+            .collect();
+        assert_eq!(secret_types, vec!["personal", "shared"]);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_secret_surfaces_other_errors() {
+        // e.g. Infisical refusing personal secrets to a machine identity, which mustn't fall back silently
+        let server = MockServer::start(vec![mock_api_error(
+            403,
+            "Only users can have personal secrets",
+        )])
+        .await;
+        let scope = SecretScope::new("workspace-id", "dev", None);
+
+        let result = mock_access_token()
+            .resolve_secret(&server.host, &reqwest::Client::new(), &scope, "API_KEY")
+            .await;
+
+        assert!(matches!(result, Err(SecretsError::GetSecretError { .. })));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_personal_secret_body() {
+        let server = MockServer::start(vec![(
+            200,
+            json!({ "secret": mock_secret_json("API_KEY", "mine", "personal") }),
+        )])
+        .await;
+        let scope = SecretScope::new("workspace-id", "dev", None);
+
+        mock_access_token()
+            .create_personal_secret(
+                &server.host,
+                &reqwest::Client::new(),
+                &scope,
+                "API_KEY",
+                "mine",
+            )
+            .await
+            .expect("mocked response");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].target, "/api/v3/secrets/raw/API_KEY");
+        assert_eq!(
+            requests[0].body,
+            json!({
+                "workspaceId": "workspace-id",
+                "environment": "dev",
+                "secretPath": "/",
+                "secretValue": "mine",
+                "type": "personal"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_personal_secret_body() {
+        let server = MockServer::start(vec![(
+            200,
+            json!({ "secret": mock_secret_json("API_KEY", "mine", "personal") }),
+        )])
+        .await;
+        let scope = SecretScope::new("workspace-id", "dev", None);
+
+        mock_access_token()
+            .delete_secret(
+                &server.host,
+                &reqwest::Client::new(),
+                &scope,
+                "API_KEY",
+                SecretType::Personal,
+            )
+            .await
+            .expect("mocked response");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "DELETE");
+        assert_eq!(requests[0].body["type"], "personal");
+    }
+}
+
+#[cfg(test)]
+pub mod secret_metadata {
+    use infisical_rs::infisical::secrets::utils::{
+        UpdateSecretOptions, secret_util_functions::filter_secrets_by_metadata,
+    };
+
+    use crate::test_utils::secrets_test_utils::mock_secret_from_value;
+
+    fn mock_secret_with_metadata(
+        secret_key: &str,
+        metadata: serde_json::Value,
+    ) -> infisical_rs::infisical::secrets::utils::InfisicalSecret {
+        mock_secret_from_value(serde_json::json!({
+            "_id": secret_key,
+            "id": secret_key,
+            "createdAt": "2025-01-01T00:00:00.000Z",
+            "updatedAt": "2025-01-01T00:00:00.000Z",
+            "environment": "dev",
+            "workspace": "workspace-id",
+            "secretKey": secret_key,
+            "secretValue": "value",
+            "secretComment": "",
+            "secretMetadata": metadata,
+            "secretReminderNote": null,
+            "secretReminderRepeatDays": null,
+            "tags": [],
+            "type": "shared",
+            "version": 1
+        }))
+    }
+
+    #[test]
+    fn test_filter_secrets_by_metadata() {
+        let secrets = vec![
+            mock_secret_with_metadata(
+                "DB_URL",
+                serde_json::json!([{ "key": "owner", "value": "payments" }]),
+            ),
+            mock_secret_with_metadata(
+                "API_KEY",
+                serde_json::json!([{ "key": "owner", "value": "search" }]),
+            ),
+            mock_secret_with_metadata("FLAG", serde_json::json!([])),
+        ];
+
+        let payments = filter_secrets_by_metadata(&secrets, "owner", Some("payments"));
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].secret_key(), "DB_URL");
+
+        assert_eq!(filter_secrets_by_metadata(&secrets, "owner", None).len(), 2);
+        assert_eq!(secrets[0].secret_reminder_repeat_days(), None);
+    }
+
+    #[test]
+    fn test_clearing_a_reminder_sends_nulls() -> Result<(), serde_json::Error> {
+        let body = serde_json::to_value(UpdateSecretOptions {
+            secret_reminder_note: Some(None),
+            secret_reminder_repeat_days: Some(None),
+            ..Default::default()
+        })?;
+
+        assert_eq!(
+            body,
+            serde_json::json!({ "secretReminderNote": null, "secretReminderRepeatDays": null })
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod secret_batches {
+    use infisical_rs::infisical::secrets::utils::{
+        BatchCreateSecret, CreateSecretOptions, InfisicalSecretMetadata,
+    };
+
+    #[test]
+    fn test_batch_create_entry_is_flattened() -> Result<(), serde_json::Error> {
+        let entry = serde_json::to_value(BatchCreateSecret {
+            secret_key: "DB_URL".to_string(),
+            options: CreateSecretOptions {
+                secret_value: "postgres://".to_string(),
+                secret_metadata: vec![InfisicalSecretMetadata {
+                    key: "owner".to_string(),
+                    value: "payments".to_string(),
+                }],
+                ..Default::default()
+            },
+        })?;
+
+        assert_eq!(
+            entry,
+            serde_json::json!({
+                "secretKey": "DB_URL",
+                "secretValue": "postgres://",
+                "secretMetadata": [{ "key": "owner", "value": "payments" }],
+                "type": "shared"
+            })
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod secret_diffs {
+    use infisical_rs::infisical::secrets::diff::diff_util_functions::diff_secret_lists;
+    use secrecy::ExposeSecret;
+
+    use crate::test_utils::secrets_test_utils::mock_secret;
+
+    #[test]
+    fn test_diff_secret_lists() {
+        let staging = vec![
+            mock_secret("API_KEY", "staging-key", "shared"),
+            mock_secret("DB_URL", "postgres://db", "shared"),
+            mock_secret("NEW_FLAG", "true", "shared"),
+            mock_secret("API_KEY", "my-key", "personal"),
+        ];
+        let prod = vec![
+            mock_secret("API_KEY", "prod-key", "shared"),
+            mock_secret("DB_URL", "postgres://db", "shared"),
+            mock_secret("OLD_FLAG", "false", "shared"),
+        ];
+
+        let diff = diff_secret_lists(&staging, &prod, false);
+
+        assert_eq!(diff.only_in_source, vec!["NEW_FLAG".to_string()]);
+        assert_eq!(diff.only_in_target, vec!["OLD_FLAG".to_string()]);
+        assert_eq!(diff.unchanged, vec!["DB_URL".to_string()]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].secret_key, "API_KEY");
+        assert!(diff.changed[0].value_changed);
+        assert!(diff.changed[0].values.is_none());
+    }
+
+    #[test]
+    fn test_values_only_included_when_asked() {
+        let staging = vec![mock_secret("API_KEY", "staging-key", "shared")];
+        let prod = vec![mock_secret("API_KEY", "prod-key", "shared")];
+
+        let diff = diff_secret_lists(&staging, &prod, true);
+        let values = diff.changed[0].values.as_ref().unwrap();
+
+        assert_eq!(values.source.expose_secret(), "staging-key");
+        assert_eq!(values.target.expose_secret(), "prod-key");
+        assert!(diff_secret_lists(&staging, &staging, true).is_empty());
+    }
+}
+
+#[cfg(test)]
+pub mod secret_promotion {
+    use infisical_rs::infisical::secrets::{
+        error_handling::SecretsError,
+        promotion::{
+            PromotionAction, PromotionOptions, SecretKeySelector,
+            promotion_util_functions::plan_promotion,
+        },
+        utils::SecretScope,
+    };
+    use serde_json::json;
+
+    use crate::test_utils::{
+        mock_server_test_utils::{MockServer, mock_api_error},
+        secrets_test_utils::{mock_secret, mock_secret_json},
+        universal_auth_test_utils::mock_access_token,
+    };
+
+    fn commented_secret(secret_key: &str, secret_value: &str, comment: &str) -> serde_json::Value {
+        let mut secret = mock_secret_json(secret_key, secret_value, "shared");
+        secret["secretComment"] = json!(comment);
+        secret
+    }
+
+    // staging has an updated API_KEY and a brand new NEW_FLAG, prod has the old API_KEY
+    fn listed_scopes() -> Vec<(u16, serde_json::Value)> {
+        vec![
+            (
+                200,
+                json!({ "secrets": [
+                    commented_secret("API_KEY", "new-key", "staging comment"),
+                    commented_secret("NEW_FLAG", "true", "flag comment"),
+                ] }),
+            ),
+            (
+                200,
+                json!({ "secrets": [commented_secret("API_KEY", "old-key", "prod comment")] }),
+            ),
+        ]
+    }
+
+    async fn promote(server: &MockServer) -> Result<(), SecretsError> {
+        mock_access_token()
+            .promote_secrets(
+                &server.host,
+                &reqwest::Client::new(),
+                &SecretScope::new("workspace-id", "staging", None),
+                &SecretScope::new("workspace-id", "prod", None),
+                &PromotionOptions::default(),
+            )
+            .await
+            .map(|_| ())
+    }
+
+    #[test]
+    fn test_plan_promotion() {
+        let staging = SecretScope::new("workspace-id", "staging", None);
+        let prod = SecretScope::new("workspace-id", "prod", None);
+
+        let staging_secrets = vec![
+            mock_secret("APP_API_KEY", "new-key", "shared"),
+            mock_secret("APP_DB_URL", "postgres://db", "shared"),
+            mock_secret("APP_NEW_FLAG", "true", "shared"),
+            mock_secret("OTHER_SECRET", "left alone", "shared"),
+        ];
+        let prod_secrets = vec![
+            mock_secret("APP_API_KEY", "old-key", "shared"),
+            mock_secret("APP_DB_URL", "postgres://db", "shared"),
+            mock_secret("APP_PROD_ONLY", "kept", "shared"),
+        ];
+
+        let plan = plan_promotion(
+            &staging,
+            &prod,
+            &staging_secrets,
+            &prod_secrets,
+            &SecretKeySelector::Prefix("APP_".to_string()),
+        );
+
+        assert!(!plan.applied);
+        assert_eq!(plan.unchanged, vec!["APP_DB_URL".to_string()]);
+        assert_eq!(plan.changes.len(), 2);
+        assert_eq!(plan.changes[0].secret_key, "APP_API_KEY");
+        assert_eq!(plan.changes[0].action, PromotionAction::Update);
+        assert!(plan.changes[0].value_changed);
+        assert_eq!(plan.changes[1].secret_key, "APP_NEW_FLAG");
+        assert_eq!(plan.changes[1].action, PromotionAction::Create);
+
+        let dry_run_output = plan.to_string();
+        assert!(dry_run_output.contains("+ APP_NEW_FLAG"));
+        assert!(dry_run_output.contains("~ APP_API_KEY (value)"));
+    }
+
+    #[tokio::test]
+    async fn test_promotion_keeps_target_comments() {
+        let mut responses = listed_scopes();
+        responses.push((200, json!({ "secrets": [] })));
+        responses.push((200, json!({ "secrets": [] })));
+        let server = MockServer::start(responses).await;
+
+        promote(&server).await.expect("mocked responses");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 4);
+
+        // created secrets carry the source comment over, updated ones keep their own
+        assert_eq!(requests[2].method, "POST");
+        assert_eq!(requests[2].body["secrets"][0]["secretKey"], "NEW_FLAG");
+        assert_eq!(
+            requests[2].body["secrets"][0]["secretComment"],
+            "flag comment"
+        );
+        assert_eq!(requests[3].method, "PATCH");
+        assert_eq!(requests[3].body["secrets"][0]["secretKey"], "API_KEY");
+        assert!(
+            requests[3].body["secrets"][0]
+                .get("secretComment")
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_update_rolls_back_created_secrets() {
+        let mut responses = listed_scopes();
+        responses.push((200, json!({ "secrets": [] })));
+        responses.push(mock_api_error(400, "Invalid secret value"));
+        responses.push((200, json!({ "secrets": [] })));
+        let server = MockServer::start(responses).await;
+
+        let Err(SecretsError::PartiallyAppliedError {
+            rolled_back_secret_keys,
+            applied_secret_keys,
+            rollback_error,
+            ..
+        }) = promote(&server).await
+        else {
+            panic!("expected a PartiallyAppliedError");
+        };
+
+        assert_eq!(rolled_back_secret_keys, vec!["NEW_FLAG".to_string()]);
+        assert!(applied_secret_keys.is_empty());
+        assert!(rollback_error.is_none());
+
+        let requests = server.requests();
+        assert_eq!(requests[4].method, "DELETE");
+        assert_eq!(
+            requests[4].body["secrets"],
+            json!([{ "secretKey": "NEW_FLAG", "type": "shared" }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_rollback_reports_applied_secrets() {
+        let mut responses = listed_scopes();
+        responses.push((200, json!({ "secrets": [] })));
+        responses.push(mock_api_error(400, "Invalid secret value"));
+        responses.push(mock_api_error(500, "Database unavailable"));
+        let server = MockServer::start(responses).await;
+
+        let Err(SecretsError::PartiallyAppliedError {
+            rolled_back_secret_keys,
+            applied_secret_keys,
+            rollback_error,
+            ..
+        }) = promote(&server).await
+        else {
+            panic!("expected a PartiallyAppliedError");
+        };
+
+        assert!(rolled_back_secret_keys.is_empty());
+        assert_eq!(applied_secret_keys, vec!["NEW_FLAG".to_string()]);
+        assert!(rollback_error.is_some());
+    }
+}
+
+#[cfg(test)]
+pub mod secret_transfers {
+    use infisical_rs::infisical::secrets::{
+        error_handling::SecretsError,
+        utils::{CopySecretsResult, SecretConflictBehavior, SecretScope, TransferSecretsOptions},
+    };
+    use serde_json::json;
+
+    use crate::test_utils::{
+        mock_server_test_utils::{MockServer, mock_api_error},
+        secrets_test_utils::mock_secret_json,
+        universal_auth_test_utils::mock_access_token,
+    };
+
+    // dev has API_KEY and NEW_FLAG, staging already has API_KEY
+    fn listed_scopes() -> Vec<(u16, serde_json::Value)> {
+        vec![
+            (
+                200,
+                json!({ "secrets": [
+                    mock_secret_json("API_KEY", "dev-key", "shared"),
+                    mock_secret_json("NEW_FLAG", "true", "shared"),
+                ] }),
+            ),
+            (
+                200,
+                json!({ "secrets": [mock_secret_json("API_KEY", "staging-key", "shared")] }),
+            ),
+        ]
+    }
+
+    async fn copy(
+        server: &MockServer,
+        on_conflict: SecretConflictBehavior,
+    ) -> Result<CopySecretsResult, SecretsError> {
+        mock_access_token()
+            .copy_secrets(
+                &server.host,
+                &reqwest::Client::new(),
+                &SecretScope::new("workspace-id", "dev", None),
+                &SecretScope::new("workspace-id", "staging", None),
+                &TransferSecretsOptions {
+                    secret_keys: None,
+                    on_conflict,
+                },
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_copy_creates_new_and_overwrites_existing_secrets() {
+        let mut responses = listed_scopes();
+        responses.push((200, json!({ "secrets": [] })));
+        responses.push((200, json!({ "secrets": [] })));
+        let server = MockServer::start(responses).await;
+
+        let result = copy(&server, SecretConflictBehavior::Overwrite)
+            .await
+            .expect("mocked responses");
+
+        assert_eq!(result.created, vec!["NEW_FLAG".to_string()]);
+        assert_eq!(result.overwritten, vec!["API_KEY".to_string()]);
+        assert!(result.skipped.is_empty());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[2].method, "POST");
+        assert_eq!(requests[2].body["environment"], "staging");
+        assert_eq!(requests[2].body["secrets"].as_array().unwrap().len(), 1);
+        assert_eq!(requests[2].body["secrets"][0]["secretKey"], "NEW_FLAG");
+        assert_eq!(requests[2].body["secrets"][0]["secretValue"], "true");
+        assert_eq!(requests[3].method, "PATCH");
+        assert_eq!(requests[3].body["secrets"].as_array().unwrap().len(), 1);
+        assert_eq!(requests[3].body["secrets"][0]["secretKey"], "API_KEY");
+        assert_eq!(requests[3].body["secrets"][0]["secretValue"], "dev-key");
+    }
+
+    #[tokio::test]
+    async fn test_copy_skips_existing_secrets() {
+        let mut responses = listed_scopes();
+        responses.push((200, json!({ "secrets": [] })));
+        let server = MockServer::start(responses).await;
+
+        let result = copy(&server, SecretConflictBehavior::Skip)
+            .await
+            .expect("mocked responses");
+
+        assert_eq!(result.created, vec!["NEW_FLAG".to_string()]);
+        assert!(result.overwritten.is_empty());
+        assert_eq!(result.skipped, vec!["API_KEY".to_string()]);
+
+        // no update batch is sent when nothing is overwritten
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].method, "POST");
+    }
+
+    #[tokio::test]
+    async fn test_failed_overwrite_rolls_back_created_copies() {
+        let mut responses = listed_scopes();
+        responses.push((200, json!({ "secrets": [] })));
+        responses.push(mock_api_error(400, "Invalid secret value"));
+        responses.push((200, json!({ "secrets": [] })));
+        let server = MockServer::start(responses).await;
+
+        let Err(SecretsError::PartiallyAppliedError {
+            operation,
+            rolled_back_secret_keys,
+            applied_secret_keys,
+            rollback_error,
+            ..
+        }) = copy(&server, SecretConflictBehavior::Overwrite).await
+        else {
+            panic!("expected a PartiallyAppliedError");
+        };
+
+        assert_eq!(operation, "copy_secrets");
+        assert_eq!(rolled_back_secret_keys, vec!["NEW_FLAG".to_string()]);
+        assert!(applied_secret_keys.is_empty());
+        assert!(rollback_error.is_none());
+
+        // only the created copy is deleted, the secret that failed to update is left alone
+        let requests = server.requests();
+        assert_eq!(requests.len(), 5);
+        assert_eq!(requests[4].method, "DELETE");
+        assert_eq!(requests[4].body["environment"], "staging");
+        assert_eq!(
+            requests[4].body["secrets"],
+            json!([{ "secretKey": "NEW_FLAG", "type": "shared" }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_overwrite_without_created_copies_is_not_rolled_back() {
+        let server = MockServer::start(vec![
+            (
+                200,
+                json!({ "secrets": [mock_secret_json("API_KEY", "dev-key", "shared")] }),
+            ),
+            (
+                200,
+                json!({ "secrets": [mock_secret_json("API_KEY", "staging-key", "shared")] }),
+            ),
+            mock_api_error(400, "Invalid secret value"),
+        ])
+        .await;
+
+        let error = copy(&server, SecretConflictBehavior::Overwrite)
+            .await
+            .expect_err("mocked error");
+
+        assert!(!matches!(error, SecretsError::PartiallyAppliedError { .. }));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_move_sends_secret_ids_and_project_slug() {
+        let server = MockServer::start(vec![
+            (
+                200,
+                json!({ "secrets": [
+                    mock_secret_json("API_KEY", "dev-key", "shared"),
+                    mock_secret_json("NEW_FLAG", "true", "shared"),
+                ] }),
+            ),
+            (
+                200,
+                json!({ "workspace": {
+                    "id": "workspace-id",
+                    "name": "Project",
+                    "slug": "project-slug",
+                    "orgId": "org-id",
+                } }),
+            ),
+            (
+                200,
+                json!({ "isSourceUpdated": true, "isDestinationUpdated": true }),
+            ),
+        ])
+        .await;
+
+        let result = mock_access_token()
+            .move_secrets(
+                &server.host,
+                &reqwest::Client::new(),
+                &SecretScope::new("workspace-id", "dev", None),
+                &SecretScope::new("workspace-id", "staging", None),
+                &TransferSecretsOptions {
+                    secret_keys: Some(vec!["NEW_FLAG".to_string()]),
+                    on_conflict: SecretConflictBehavior::Overwrite,
+                },
+            )
+            .await
+            .expect("mocked responses");
+
+        assert!(result.is_source_updated && result.is_destination_updated);
+
+        let requests = server.requests();
+        assert_eq!(requests[2].method, "POST");
+        assert_eq!(requests[2].body["projectSlug"], "project-slug");
+        assert_eq!(requests[2].body["destinationEnvironment"], "staging");
+        assert_eq!(requests[2].body["secretIds"], json!(["NEW_FLAG-shared"]));
+        assert_eq!(requests[2].body["shouldOverwrite"], true);
+    }
+
+    #[tokio::test]
+    async fn test_move_rejects_other_projects() {
+        let error = mock_access_token()
+            .move_secrets(
+                "http://127.0.0.1:1",
+                &reqwest::Client::new(),
+                &SecretScope::new("workspace-id", "dev", None),
+                &SecretScope::new("other-workspace-id", "dev", None),
+                &TransferSecretsOptions::default(),
+            )
+            .await
+            .expect_err("different projects");
+
+        assert!(matches!(
+            error,
+            SecretsError::CrossProjectMoveUnsupported { .. }
+        ));
+    }
+}
+
+#[cfg(test)]
+pub mod secret_cache {
+    use std::sync::Arc;
+
+    use infisical_rs::infisical::secrets::{
+        cache::SecretCache,
+        utils::{ListSecretsOptions, SecretScope, secret_util_functions::is_secret_path_within},
+    };
+    use secrecy::ExposeSecret;
+
+    use crate::test_utils::{
+        secrets_test_utils::mock_secret, universal_auth_test_utils::mock_access_token,
+    };
+
+    fn scope(environment: &str, secret_path: &str) -> SecretScope {
+        SecretScope::new("workspace-id", environment, Some(secret_path))
+    }
+
+    fn primed_cache(options: ListSecretsOptions) -> SecretCache {
+        let cache = SecretCache::new(
+            Arc::new(mock_access_token()),
+            "http://127.0.0.1:1",
+            reqwest::Client::new(),
+            options,
+        );
+
+        for (environment, secret_path) in [
+            ("prod", "/"),
+            ("prod", "/db"),
+            ("prod", "/db/replica"),
+            ("prod", "/dbx"),
+            ("dev", "/db"),
+        ] {
+            cache.insert(
+                scope(environment, secret_path),
+                vec![mock_secret("DATABASE_URL", secret_path, "shared")],
+            );
+        }
+
+        cache
+    }
+
+    fn sorted(mut scopes: Vec<SecretScope>) -> Vec<SecretScope> {
+        scopes.sort_by(|a, b| {
+            (&a.environment, &a.secret_path).cmp(&(&b.environment, &b.secret_path))
+        });
+        scopes
+    }
+
+    #[test]
+    fn test_secret_path_within() {
+        assert!(is_secret_path_within("/db", "/db"));
+        assert!(is_secret_path_within("/db/replica", "/db/"));
+        assert!(is_secret_path_within("/db", "/"));
+        assert!(!is_secret_path_within("/dbx", "/db"));
+        assert!(!is_secret_path_within("/", "/db"));
+    }
+
+    #[tokio::test]
+    async fn test_cached_scopes_are_served_from_memory() {
+        let cache = primed_cache(ListSecretsOptions::default());
+
+        // the mock host is unreachable, so this only succeeds without a request
+        let value = cache
+            .secret_value(&scope("prod", "/db"), "DATABASE_URL")
+            .await
+            .expect("cached scope");
+        assert_eq!(
+            value.map(|value| value.expose_secret().to_string()),
+            Some("/db".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalidation_evicts_only_affected_scopes() {
+        let cache = primed_cache(ListSecretsOptions::default());
+        let mut invalidations = cache.subscribe();
+
+        let evicted = cache.invalidate(&scope("prod", "/db"));
+
+        assert_eq!(
+            sorted(evicted.clone()),
+            vec![scope("prod", "/db"), scope("prod", "/db/replica")]
+        );
+        assert_eq!(
+            sorted(cache.cached_scopes()),
+            vec![
+                scope("dev", "/db"),
+                scope("prod", "/"),
+                scope("prod", "/dbx")
+            ]
+        );
+
+        let invalidation = invalidations.recv().await.expect("published invalidation");
+        assert_eq!(invalidation.changed, scope("prod", "/db"));
+        assert_eq!(sorted(invalidation.evicted), sorted(evicted));
+    }
+
+    #[test]
+    fn test_recursive_listings_evict_parent_scopes() {
+        let cache = primed_cache(ListSecretsOptions {
+            recursive: true,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            sorted(cache.invalidate(&scope("prod", "/db"))),
+            vec![
+                scope("prod", "/"),
+                scope("prod", "/db"),
+                scope("prod", "/db/replica")
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod secret_watching {
+    use std::time::Duration;
+
+    use infisical_rs::infisical::secrets::{
+        utils::{InfisicalSecret, SecretScope, SecretType},
+        watch::{RemovedSecret, SecretVersions, WatchSecretsOptions},
+    };
+
+    use crate::test_utils::secrets_test_utils::mock_secret_from_value;
+
+    fn versioned_secret(secret_key: &str, secret_type: &str, version: u64) -> InfisicalSecret {
+        mock_secret_from_value(serde_json::json!({
+            "_id": format!("{secret_key}-{secret_type}"),
+            "id": format!("{secret_key}-{secret_type}"),
+            "createdAt": "2025-01-01T00:00:00.000Z",
+            "updatedAt": "2025-01-01T00:00:00.000Z",
+            "environment": "prod",
+            "workspace": "workspace-id",
+            "secretKey": secret_key,
+            "secretValue": format!("{secret_key}-v{version}"),
+            "secretComment": "",
+            "tags": [],
+            "type": secret_type,
+            "version": version
+        }))
+    }
+
+    #[test]
+    fn test_changes_between_polls() {
+        let scope = SecretScope::new("workspace-id", "prod", None);
+
+        let versions = SecretVersions::new(&[
+            versioned_secret("DB_PASSWORD", "shared", 3),
+            versioned_secret("API_KEY", "shared", 1),
+            versioned_secret("LEGACY_TOKEN", "shared", 7),
+        ]);
+
+        let change = versions.changes(
+            &scope,
+            vec![
+                versioned_secret("DB_PASSWORD", "shared", 4),
+                versioned_secret("API_KEY", "shared", 1),
+                versioned_secret("SMTP_PASSWORD", "shared", 1),
+            ],
+        );
+
+        assert_eq!(change.scope, scope);
+        assert_eq!(change.added.len(), 1);
+        assert_eq!(change.added[0].secret_key(), "SMTP_PASSWORD");
+        assert_eq!(change.updated.len(), 1);
+        assert_eq!(change.updated[0].secret_value(), "DB_PASSWORD-v4");
+        assert_eq!(
+            change.removed,
+            vec![RemovedSecret {
+                secret_key: "LEGACY_TOKEN".to_string(),
+                secret_type: SecretType::Shared,
+                version: 7,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_personal_overrides_tracked_separately() {
+        let scope = SecretScope::new("workspace-id", "prod", None);
+
+        let versions = SecretVersions::new(&[
+            versioned_secret("API_KEY", "shared", 1),
+            versioned_secret("API_KEY", "personal", 1),
+        ]);
+
+        let change = versions.changes(&scope, vec![versioned_secret("API_KEY", "shared", 1)]);
+
+        assert!(change.added.is_empty());
+        assert!(change.updated.is_empty());
+        assert_eq!(change.removed.len(), 1);
+        assert_eq!(change.removed[0].secret_type, SecretType::Personal);
+    }
+
+    #[test]
+    fn test_unchanged_scope() {
+        let scope = SecretScope::new("workspace-id", "prod", None);
+        let versions = SecretVersions::new(&[versioned_secret("API_KEY", "shared", 2)]);
+
+        assert!(
+            versions
+                .changes(&scope, vec![versioned_secret("API_KEY", "shared", 2)])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_zero_interval_is_raised_to_the_minimum() {
+        let options = WatchSecretsOptions {
+            interval: Duration::ZERO,
+            ..Default::default()
+        };
+
+        assert_eq!(options.poll_interval(), WatchSecretsOptions::MIN_INTERVAL);
+        assert_eq!(
+            WatchSecretsOptions::default().poll_interval(),
+            Duration::from_secs(30)
+        );
+    }
+}
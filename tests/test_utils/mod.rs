@@ -23,8 +23,9 @@ pub mod universal_auth_test_utils {
 
     use infisical_rs::infisical::auth_methods::universal_auth::{
         error_handling::UniversalAuthError,
-        utils::{UniversalAuthAccessToken, UniversalAuthCredentials},
+        utils::{UniversalAuthAccessToken, UniversalAuthAccessTokenData, UniversalAuthCredentials},
     };
+    use secrecy::SecretBox;
 
     use super::_env::{
         TEST_ATTACH_IDENTITY_ID, TEST_CLIENT_ID, TEST_CLIENT_SECRET, UniversalAuthTestStruct,
@@ -56,6 +57,19 @@ pub mod universal_auth_test_utils {
 
     pub fn universal_auth_test_setup() {}
 
+    /// an access token that was never issued by Infisical, for tests that never reach it
+    pub fn mock_access_token() -> UniversalAuthAccessToken {
+        UniversalAuthAccessToken {
+            data: SecretBox::new(Box::new(UniversalAuthAccessTokenData {
+                access_token: "mock-access-token".to_string(),
+                access_token_max_ttl: 7200,
+                expires_in: 7200,
+                token_type: "Bearer".to_string(),
+            })),
+            version: "v1".to_string(),
+        }
+    }
+
     pub async fn mock_access_token_login(
         config: &UniversalAuthTestStruct,
     ) -> Result<UniversalAuthAccessToken, UniversalAuthError> {
@@ -65,3 +79,176 @@ pub mod universal_auth_test_utils {
             .await?)
     }
 }
+
+pub mod secrets_test_utils {
+    use infisical_rs::infisical::secrets::utils::{InfisicalSecret, InfisicalSecretData};
+    use secrecy::SecretBox;
+
+    /// builds a secret the same way it'd come back from the raw secrets endpoints
+    pub fn mock_secret(secret_key: &str, secret_value: &str, secret_type: &str) -> InfisicalSecret {
+        mock_secret_from_value(mock_secret_json(secret_key, secret_value, secret_type))
+    }
+
+    /// the raw secrets endpoints' JSON for a single secret
+    pub fn mock_secret_json(
+        secret_key: &str,
+        secret_value: &str,
+        secret_type: &str,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "_id": format!("{secret_key}-{secret_type}"),
+            "id": format!("{secret_key}-{secret_type}"),
+            "createdAt": "2025-01-01T00:00:00.000Z",
+            "updatedAt": "2025-01-01T00:00:00.000Z",
+            "environment": "dev",
+            "workspace": "workspace-id",
+            "secretKey": secret_key,
+            "secretValue": secret_value,
+            "secretComment": "",
+            "secretMetadata": [],
+            "secretReminderNote": "",
+            "secretReminderRepeatDays": 0,
+            "tags": [],
+            "type": secret_type,
+            "version": 1
+        })
+    }
+
+    pub fn mock_secret_from_value(value: serde_json::Value) -> InfisicalSecret {
+        InfisicalSecret {
+            data: SecretBox::new(Box::new(
+                serde_json::from_value::<InfisicalSecretData>(value).unwrap(),
+            )),
+        }
+    }
+}
+
+pub mod mock_server_test_utils {
+    use std::sync::{Arc, Mutex};
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    /// a request as the mock server received it
+    #[derive(Debug, Clone)]
+    pub struct RecordedRequest {
+        pub method: String,
+        /// the request target, query string included
+        pub target: String,
+        pub body: serde_json::Value,
+    }
+
+    impl RecordedRequest {
+        pub fn query_pairs(&self) -> Vec<(String, String)> {
+            reqwest::Url::parse(&format!("http://mock{}", self.target))
+                .expect("valid request target")
+                .query_pairs()
+                .into_owned()
+                .collect()
+        }
+    }
+
+    /// a local HTTP server answering each request with the next canned (status, JSON body) response,
+    /// and a 500 once they run out
+    pub struct MockServer {
+        pub host: String,
+        requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    }
+
+    impl MockServer {
+        pub async fn start(responses: Vec<(u16, serde_json::Value)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.expect("bindable");
+            let host = format!("http://{}", listener.local_addr().expect("bound"));
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let recorded = requests.clone();
+            tokio::spawn(async move {
+                let mut responses = responses.into_iter();
+
+                while let Ok((mut stream, _)) = listener.accept().await {
+                    let Some(request) = read_request(&mut stream).await else {
+                        continue;
+                    };
+                    recorded.lock().expect("unpoisoned").push(request);
+
+                    let (status, body) = responses
+                        .next()
+                        .unwrap_or_else(|| mock_api_error(500, "no mock response left"));
+                    let body = body.to_string();
+                    let response = format!(
+                        "HTTP/1.1 {status} MOCK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+
+                    let _ = stream.write_all(response.as_bytes()).await;
+                }
+            });
+
+            Self { host, requests }
+        }
+
+        pub fn requests(&self) -> Vec<RecordedRequest> {
+            self.requests.lock().expect("unpoisoned").clone()
+        }
+    }
+
+    /// an error response shaped the way Infisical returns them
+    pub fn mock_api_error(status: u16, message: &str) -> (u16, serde_json::Value) {
+        (
+            status,
+            serde_json::json!({
+                "reqId": "mock-req-id",
+                "statusCode": status,
+                "message": message,
+                "error": "Mock Error"
+            }),
+        )
+    }
+
+    async fn read_request(stream: &mut TcpStream) -> Option<RecordedRequest> {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 4096];
+
+        let header_end = loop {
+            let read = stream.read(&mut chunk).await.ok()?;
+            if read == 0 {
+                return None;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+
+            if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break position + 4;
+            }
+        };
+
+        let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+        let mut request_line = head.lines().next()?.split_whitespace();
+        let method = request_line.next()?.to_string();
+        let target = request_line.next()?.to_string();
+
+        let content_length = head
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+
+        while buffer.len() < header_end + content_length {
+            let read = stream.read(&mut chunk).await.ok()?;
+            if read == 0 {
+                break;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+
+        let body = serde_json::from_slice(&buffer[header_end..]).unwrap_or(serde_json::Value::Null);
+
+        Some(RecordedRequest {
+            method,
+            target,
+            body,
+        })
+    }
+}
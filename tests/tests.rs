@@ -1,2 +1,20 @@
+pub mod access_approvals;
+pub mod audit_logs;
 pub mod auth_methods;
+pub mod dynamic_secrets;
+pub mod environments;
+pub mod groups;
+pub mod identities;
+pub mod kms;
+pub mod organizations;
+pub mod pki;
+pub mod project_memberships;
+pub mod project_roles;
+pub mod projects;
+pub mod secret_approvals;
+pub mod secret_rotations;
+pub mod secret_sharing;
+pub mod secrets;
+pub mod ssh;
 pub mod test_utils;
+pub mod webhooks;
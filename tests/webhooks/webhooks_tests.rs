@@ -0,0 +1,364 @@
+#[cfg(test)]
+pub mod webhook_management {
+    use infisical_rs::infisical::webhooks::utils::{Webhook, WebhookStatus, WebhookType};
+    use serde_json::json;
+
+    #[test]
+    fn test_webhook_deserialization() {
+        let webhook: Webhook = serde_json::from_value(json!({
+            "id": "webhook-id",
+            "projectId": "project-id",
+            "environment": { "id": "env-id", "name": "Production", "slug": "prod" },
+            "envId": "env-id",
+            "secretPath": "/db",
+            "url": "https://hooks.example.com/infisical",
+            "type": "general",
+            "isDisabled": false,
+            "lastStatus": "failed",
+            "lastRunErrorMessage": "connect ECONNREFUSED",
+            "createdAt": "2025-03-01T12:30:15.250Z",
+            "updatedAt": "2025-03-01T12:30:15.250Z"
+        }))
+        .expect("valid webhook");
+
+        assert_eq!(webhook.environment.slug, "prod");
+        assert_eq!(webhook.webhook_type, WebhookType::General);
+        assert_eq!(webhook.last_status, Some(WebhookStatus::Failed));
+        assert_eq!(
+            webhook.last_run_error_message.as_deref(),
+            Some("connect ECONNREFUSED")
+        );
+    }
+
+    #[test]
+    fn test_never_delivered_webhook_deserialization() {
+        let webhook: Webhook = serde_json::from_value(json!({
+            "id": "webhook-id",
+            "projectId": "project-id",
+            "environment": { "id": "env-id", "name": "Development", "slug": "dev" },
+            "secretPath": "/",
+            "url": "https://hooks.slack.com/services/T000/B000/XXXX",
+            "type": "slack",
+            "isDisabled": true,
+            "lastStatus": null,
+            "lastRunErrorMessage": null,
+            "createdAt": "2025-03-01T12:30:15.250Z",
+            "updatedAt": "2025-03-01T12:30:15.250Z"
+        }))
+        .expect("valid webhook");
+
+        assert_eq!(webhook.webhook_type, WebhookType::Slack);
+        assert!(webhook.is_disabled);
+        assert_eq!(webhook.last_status, None);
+    }
+}
+
+#[cfg(test)]
+pub mod webhook_verification {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use infisical_rs::infisical::webhooks::{
+        error_handling::WebhookVerificationError,
+        sign_webhook,
+        utils::{WebhookEventType, webhook_util_functions::parse_webhook_signature_header},
+        verify_webhook,
+    };
+    use secrecy::SecretString;
+    use serde_json::json;
+
+    fn secret_key() -> SecretString {
+        SecretString::from("webhook-secret-key")
+    }
+
+    fn payload(event: &str, timestamp: u64) -> Vec<u8> {
+        serde_json::to_vec(&json!({
+            "event": event,
+            "project": {
+                "workspaceId": "project-id",
+                "projectName": "backend",
+                "environment": "prod",
+                "secretPath": "/db"
+            },
+            "timestamp": timestamp
+        }))
+        .expect("serializable")
+    }
+
+    fn now_millis() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("after the epoch")
+            .as_millis() as u64
+    }
+
+    #[test]
+    fn test_known_signature() {
+        // hmac-sha256 of the raw body, hex encoded, as Infisical computes it
+        assert_eq!(
+            sign_webhook(&SecretString::from("key"), b"{}", 1),
+            "t=1;a777724d943eb48dc69bca8a4a6d57a04db3f9ec7e1de4e581e860265bdf3032"
+        );
+        assert_eq!(
+            parse_webhook_signature_header("t=1;abcd"),
+            Some((1, "abcd"))
+        );
+    }
+
+    #[test]
+    fn test_valid_delivery() {
+        let timestamp = now_millis();
+        let body = payload("secrets.modified", timestamp);
+        let header = sign_webhook(&secret_key(), &body, timestamp);
+
+        let payload = verify_webhook(
+            &secret_key(),
+            &header,
+            &body,
+            Some(Duration::from_secs(300)),
+        )
+        .expect("valid delivery");
+
+        assert_eq!(payload.event, WebhookEventType::SecretsModified);
+        assert_eq!(payload.project.environment, "prod");
+        assert_eq!(payload.project.secret_path, "/db");
+    }
+
+    #[test]
+    fn test_tampered_delivery() {
+        let timestamp = now_millis();
+        let body = payload("secrets.modified", timestamp);
+        let header = sign_webhook(&secret_key(), &body, timestamp);
+
+        let tampered = payload("test", timestamp);
+        assert!(matches!(
+            verify_webhook(&secret_key(), &header, &tampered, None),
+            Err(WebhookVerificationError::InvalidSignature)
+        ));
+
+        let wrong_key = SecretString::from("another-key");
+        assert!(matches!(
+            verify_webhook(&wrong_key, &header, &body, None),
+            Err(WebhookVerificationError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn test_expired_delivery() {
+        let timestamp = now_millis() - 10 * 60 * 1000;
+        let body = payload("secrets.modified", timestamp);
+        let header = sign_webhook(&secret_key(), &body, timestamp);
+
+        assert!(matches!(
+            verify_webhook(
+                &secret_key(),
+                &header,
+                &body,
+                Some(Duration::from_secs(300))
+            ),
+            Err(WebhookVerificationError::Expired { .. })
+        ));
+        assert!(verify_webhook(&secret_key(), &header, &body, None).is_ok());
+    }
+
+    #[test]
+    fn test_future_delivery() {
+        let max_age = Some(Duration::from_secs(300));
+
+        let timestamp = now_millis() + 10 * 60 * 1000;
+        let body = payload("secrets.modified", timestamp);
+        let header = sign_webhook(&secret_key(), &body, timestamp);
+
+        assert!(matches!(
+            verify_webhook(&secret_key(), &header, &body, max_age),
+            Err(WebhookVerificationError::SignedInFuture { .. })
+        ));
+        assert!(verify_webhook(&secret_key(), &header, &body, None).is_ok());
+
+        // a clock that's merely a little behind Infisical's is fine
+        let timestamp = now_millis() + 5 * 1000;
+        let body = payload("secrets.modified", timestamp);
+        let header = sign_webhook(&secret_key(), &body, timestamp);
+
+        assert!(verify_webhook(&secret_key(), &header, &body, max_age).is_ok());
+    }
+
+    #[test]
+    fn test_malformed_header() {
+        let body = payload("test", 1);
+
+        for header in ["", "1;abcd", "t=1", "t=abc;abcd", "t=1;not-hex"] {
+            assert!(matches!(
+                verify_webhook(&secret_key(), header, &body, None),
+                Err(WebhookVerificationError::MalformedSignatureHeader { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_timestamp_mismatch() {
+        let body = payload("test", 1);
+        let header = sign_webhook(&secret_key(), &body, 2);
+
+        assert!(matches!(
+            verify_webhook(&secret_key(), &header, &body, None),
+            Err(WebhookVerificationError::TimestampMismatch {
+                signed: 2,
+                payload: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_unknown_event() {
+        let body = payload("secrets.reminder-expired", 1);
+        let header = sign_webhook(&secret_key(), &body, 1);
+
+        let payload = verify_webhook(&secret_key(), &header, &body, None).expect("valid delivery");
+        assert_eq!(payload.event, WebhookEventType::Unknown);
+    }
+}
+
+#[cfg(all(test, feature = "webhook_receiver"))]
+pub mod webhook_receiver {
+    use std::{
+        sync::Arc,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use infisical_rs::infisical::{
+        secrets::{
+            cache::SecretCache,
+            utils::{ListSecretsOptions, SecretScope},
+        },
+        webhooks::{
+            receiver::{WebhookReceiver, WebhookReceiverOptions},
+            sign_webhook,
+            utils::WEBHOOK_SIGNATURE_HEADER,
+        },
+    };
+    use secrecy::SecretString;
+    use serde_json::json;
+
+    use crate::test_utils::{
+        secrets_test_utils::mock_secret, universal_auth_test_utils::mock_access_token,
+    };
+
+    fn secret_key() -> SecretString {
+        SecretString::from("webhook-secret-key")
+    }
+
+    async fn receiver_with_cache() -> (WebhookReceiver, Arc<SecretCache>) {
+        let cache = Arc::new(SecretCache::new(
+            Arc::new(mock_access_token()),
+            "http://127.0.0.1:1",
+            reqwest::Client::new(),
+            ListSecretsOptions::default(),
+        ));
+
+        for secret_path in ["/db", "/api"] {
+            cache.insert(
+                SecretScope::new("project-id", "prod", Some(secret_path)),
+                vec![mock_secret("KEY", "value", "shared")],
+            );
+        }
+
+        let receiver = WebhookReceiver::bind(
+            "127.0.0.1:0".parse().expect("valid address"),
+            cache.clone(),
+            WebhookReceiverOptions::new(secret_key()),
+        )
+        .await
+        .expect("bound receiver");
+
+        (receiver, cache)
+    }
+
+    fn signed_delivery(event: &str, secret_key: &SecretString) -> (Vec<u8>, String) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("after the epoch")
+            .as_millis() as u64;
+
+        let body = serde_json::to_vec(&json!({
+            "event": event,
+            "project": {
+                "workspaceId": "project-id",
+                "environment": "prod",
+                "secretPath": "/db"
+            },
+            "timestamp": timestamp
+        }))
+        .expect("serializable");
+        let signature = sign_webhook(secret_key, &body, timestamp);
+
+        (body, signature)
+    }
+
+    async fn post(receiver: &WebhookReceiver, body: Vec<u8>, signature: Option<String>) -> u16 {
+        let mut request = reqwest::Client::new()
+            .post(format!(
+                "http://{}/infisical/webhook",
+                receiver.local_addr()
+            ))
+            .body(body);
+
+        if let Some(signature) = signature {
+            request = request.header(WEBHOOK_SIGNATURE_HEADER, signature);
+        }
+
+        request.send().await.expect("delivered").status().as_u16()
+    }
+
+    #[tokio::test]
+    async fn test_signed_delivery_invalidates_scope() {
+        let (receiver, cache) = receiver_with_cache().await;
+        let mut invalidations = cache.subscribe();
+
+        let (body, signature) = signed_delivery("secrets.modified", &secret_key());
+        assert_eq!(post(&receiver, body, Some(signature)).await, 200);
+
+        let invalidation = invalidations.recv().await.expect("published invalidation");
+        assert_eq!(
+            invalidation.changed,
+            SecretScope::new("project-id", "prod", Some("/db"))
+        );
+        assert_eq!(
+            cache.cached_scopes(),
+            vec![SecretScope::new("project-id", "prod", Some("/api"))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_test_delivery_is_acknowledged() {
+        let (receiver, cache) = receiver_with_cache().await;
+
+        let (body, signature) = signed_delivery("test", &secret_key());
+        assert_eq!(post(&receiver, body, Some(signature)).await, 200);
+        assert_eq!(cache.cached_scopes().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unverified_deliveries_are_rejected() {
+        let (receiver, cache) = receiver_with_cache().await;
+
+        let (body, _) = signed_delivery("secrets.modified", &secret_key());
+        assert_eq!(post(&receiver, body.clone(), None).await, 401);
+
+        let (_, wrong_signature) =
+            signed_delivery("secrets.modified", &SecretString::from("another-key"));
+        assert_eq!(post(&receiver, body, Some(wrong_signature)).await, 401);
+
+        // a fresh, correctly signed body that isn't a webhook payload
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("after the epoch")
+            .as_millis() as u64;
+        let signature = sign_webhook(&secret_key(), b"not json", timestamp);
+        assert_eq!(
+            post(&receiver, b"not json".to_vec(), Some(signature)).await,
+            400
+        );
+
+        assert_eq!(cache.cached_scopes().len(), 2);
+    }
+}